@@ -1,10 +1,17 @@
 //! Python bindings for Redis Cloud API client
 
 use crate::error::IntoPyResult;
+use crate::records;
 use crate::runtime::{block_on, future_into_py};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
-use redis_cloud::{AccountHandler, CloudClient, DatabaseHandler, SubscriptionHandler};
+use redis_cloud::types::Section;
+use redis_cloud::{
+    AccountHandler, CloudClient, CostReportHandler, DatabaseHandler, FixedDatabaseHandler,
+    FixedSubscriptionHandler, SubscriptionHandler,
+};
+use serde::Serialize;
+use serde_json::{Value, json};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -356,6 +363,181 @@ impl PyCloudClient {
             .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
         Ok(json_to_py(py, json))
     }
+
+    // Account snapshot
+
+    /// Get a snapshot of the whole account as a nested dict (async)
+    ///
+    /// See `snapshot_json` for the layout.
+    fn snapshot<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let json = snapshot_json(&client).await?;
+            Python::with_gil(|py| Ok(json_to_py(py, json)))
+        })
+    }
+
+    /// Get a snapshot of the whole account as a nested dict (sync)
+    fn snapshot_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let client = self.client.clone();
+        let json = block_on(py, async move { snapshot_json(&client).await })?;
+        Ok(json_to_py(py, json))
+    }
+
+    // Tabular exports
+
+    /// List every database in the account as flat records (async)
+    ///
+    /// Suitable for `pandas.DataFrame(records)`; see the README for columns.
+    fn database_records<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let snapshot = snapshot_json(&client).await?;
+            let json = Value::Array(records::database_records(&snapshot));
+            Python::with_gil(|py| Ok(json_to_py(py, json)))
+        })
+    }
+
+    /// List every database in the account as flat records (sync)
+    fn database_records_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let client = self.client.clone();
+        let snapshot = block_on(py, async move { snapshot_json(&client).await })?;
+        Ok(json_to_py(
+            py,
+            Value::Array(records::database_records(&snapshot)),
+        ))
+    }
+
+    /// Download a generated cost report as flat records (async)
+    ///
+    /// Works for both CSV and JSON reports; see the README for columns.
+    fn cost_report_records<'py>(
+        &self,
+        py: Python<'py>,
+        cost_report_id: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let json = cost_report_json(&client, &cost_report_id).await?;
+            Python::with_gil(|py| Ok(json_to_py(py, json)))
+        })
+    }
+
+    /// Download a generated cost report as flat records (sync)
+    fn cost_report_records_sync(
+        &self,
+        py: Python<'_>,
+        cost_report_id: String,
+    ) -> PyResult<Py<PyAny>> {
+        let client = self.client.clone();
+        let json = block_on(py, async move {
+            cost_report_json(&client, &cost_report_id).await
+        })?;
+        Ok(json_to_py(py, json))
+    }
+}
+
+/// Download a cost report and flatten it into records
+async fn cost_report_json(client: &CloudClient, cost_report_id: &str) -> PyResult<Value> {
+    let report = CostReportHandler::new(client.clone())
+        .download_cost_report(cost_report_id)
+        .await
+        .into_py_result()?;
+    records::cost_report_records(&report)
+        .map(Value::Array)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Fetch the account, its subscriptions and their databases as one document
+///
+/// ```text
+/// {
+///   "account": {...},
+///   "subscriptions": [{...subscription, "databases": [...], "error": null}],
+///   "essentialsSubscriptions": [{...subscription, "databases": [...], "error": null}],
+///   "recentTasks": [...],
+///   "errors": ["..."]
+/// }
+/// ```
+///
+/// Pro subscriptions come from `CloudClient::dashboard`; Essentials databases
+/// are paged through in full. A subscription whose
+/// databases cannot be fetched keeps `"databases": null` and the reason in
+/// `"error"`; the same applies to the task list, and every such failure is
+/// also listed in the top-level `"errors"`. Only the account and the
+/// subscription lists are required.
+async fn snapshot_json(client: &CloudClient) -> PyResult<Value> {
+    let account = AccountHandler::new(client.clone())
+        .get_current_account()
+        .await
+        .into_py_result()?;
+    let dashboard = client.dashboard().await.into_py_result()?;
+    let mut errors = Vec::new();
+
+    let mut subscriptions = Vec::new();
+    for overview in &dashboard.subscriptions {
+        let (databases, error) = section_json(&overview.databases)?;
+        errors.extend(error.clone());
+        subscriptions.push(with_databases(&overview.subscription, databases, error)?);
+    }
+
+    let fixed_databases = FixedDatabaseHandler::new(client.clone());
+    let fixed = FixedSubscriptionHandler::new(client.clone())
+        .list()
+        .await
+        .into_py_result()?;
+    let mut essentials_subscriptions = Vec::new();
+    for subscription in fixed.subscriptions.unwrap_or_default() {
+        let databases = match subscription.id {
+            Some(id) => Section::from_result(fixed_databases.get_all_databases(id).await),
+            None => Section::NotConfigured { message: None },
+        };
+        let (databases, error) = section_json(&databases)?;
+        errors.extend(error.clone());
+        essentials_subscriptions.push(with_databases(&subscription, databases, error)?);
+    }
+
+    let (recent_tasks, error) = section_json(&dashboard.recent_tasks)?;
+    errors.extend(error);
+
+    Ok(json!({
+        "account": to_json(&account)?,
+        "subscriptions": subscriptions,
+        "essentialsSubscriptions": essentials_subscriptions,
+        "recentTasks": recent_tasks,
+        "errors": errors,
+    }))
+}
+
+/// Serialize a subscription and attach its databases and fetch error
+fn with_databases<T: Serialize>(
+    subscription: &T,
+    databases: Value,
+    error: Option<String>,
+) -> PyResult<Value> {
+    let mut value = to_json(subscription)?;
+    if let Value::Object(fields) = &mut value {
+        fields.insert("databases".to_string(), databases);
+        fields.insert(
+            "error".to_string(),
+            error.map_or(Value::Null, Value::String),
+        );
+    }
+    Ok(value)
+}
+
+/// The section's value (or `null`) and the error that prevented fetching it
+fn section_json<T: Serialize>(section: &Section<T>) -> PyResult<(Value, Option<String>)> {
+    Ok(match section {
+        Section::Available(value) => (to_json(value)?, None),
+        Section::NotConfigured { .. } => (Value::Null, None),
+        Section::Failed(err) => (Value::Null, Some(err.to_string())),
+    })
+}
+
+fn to_json<T: Serialize>(value: &T) -> PyResult<Value> {
+    serde_json::to_value(value)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
 }
 
 /// Convert serde_json::Value to Python object
@@ -424,3 +606,68 @@ pub fn py_to_json(py: Python<'_>, obj: Py<PyAny>) -> PyResult<serde_json::Value>
 // Note: Rust-side unit tests for json_to_py and py_to_json require linking
 // against Python which is complex in pure Rust test context. These functions
 // are tested via Python-side integration tests in tests/test_client.py instead.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis_cloud::testing::{MockCloudServer, sample};
+    use wiremock::ResponseTemplate;
+
+    #[tokio::test]
+    async fn test_snapshot_json() {
+        let server = MockCloudServer::with_sample_account().await;
+        server
+            .mock_path(
+                "GET",
+                "/fixed/subscriptions",
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "subscriptions": [
+                        {"id": 2001, "name": "essentials-cache"},
+                        {"id": 2002, "name": "essentials-broken"}
+                    ]
+                })),
+            )
+            .await;
+        server
+            .mock_path(
+                "GET",
+                "/fixed/subscriptions/2001/databases",
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "subscription": {
+                        "subscriptionId": 2001,
+                        "databases": [{"databaseId": 11, "name": "cache"}]
+                    }
+                })),
+            )
+            .await;
+        server
+            .mock_server_error("/fixed/subscriptions/2002/databases")
+            .await;
+
+        let snapshot = snapshot_json(&server.client()).await.unwrap();
+
+        let subscriptions = snapshot["subscriptions"].as_array().unwrap();
+        assert_eq!(subscriptions.len(), 2);
+        assert!(subscriptions.iter().all(|s| s["error"].is_null()));
+        assert_eq!(
+            snapshot["recentTasks"].as_array().unwrap().len(),
+            4,
+            "{snapshot:#}"
+        );
+
+        let essentials = snapshot["essentialsSubscriptions"].as_array().unwrap();
+        assert_eq!(essentials.len(), 2);
+        assert_eq!(essentials[0]["databases"][0]["name"], "cache");
+        assert!(essentials[1]["databases"].is_null());
+        assert!(essentials[1]["error"].is_string());
+        assert_eq!(snapshot["errors"].as_array().unwrap().len(), 1);
+
+        let records = records::database_records(&snapshot);
+        let production = records
+            .iter()
+            .filter(|r| r["subscription_id"] == sample::PRODUCTION_SUBSCRIPTION_ID)
+            .count();
+        assert_eq!(production, sample::PRODUCTION_DATABASES.len());
+        assert_eq!(records.len(), 6);
+    }
+}
@@ -17,6 +17,7 @@ pub fn cloud_error_to_py(err: redis_cloud::CloudError) -> PyErr {
             PyValueError::new_err(format!("Resource not found: {}", err))
         }
         redis_cloud::CloudError::BadRequest { .. } => PyValueError::new_err(err.to_string()),
+        redis_cloud::CloudError::Configuration { .. } => PyValueError::new_err(err.to_string()),
         redis_cloud::CloudError::Forbidden { .. } => {
             RedisCloudError::new_err(format!("Access forbidden: {}", err))
         }
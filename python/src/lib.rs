@@ -6,6 +6,7 @@ use pyo3::prelude::*;
 
 mod client;
 mod error;
+mod records;
 mod runtime;
 
 use client::PyCloudClient;
@@ -0,0 +1,254 @@
+//! Flat records for tabular analysis
+//!
+//! `pandas.DataFrame(records)` expects a list of flat dicts sharing the same
+//! keys. The helpers here flatten the account snapshot and downloaded cost
+//! reports into that shape, with snake_case column names that don't change
+//! between API versions.
+//!
+//! # Database inventory columns
+//!
+//! One record per database, Pro and Essentials alike. Every column is always
+//! present; values the API did not report are `None`.
+//!
+//! | Column | Type | Description |
+//! |--------|------|-------------|
+//! | `plan` | str | `"pro"` or `"essentials"` |
+//! | `subscription_id` | int | Subscription ID |
+//! | `subscription_name` | str | Subscription name |
+//! | `database_id` | int | Database ID |
+//! | `database_name` | str | Database name |
+//! | `status` | str | Database status, e.g. `"active"` |
+//! | `provider` | str | Cloud provider, e.g. `"AWS"` |
+//! | `region` | str | Cloud region |
+//! | `redis_version` | str | Redis version |
+//! | `memory_limit_gb` | float | Memory limit in GB |
+//! | `dataset_size_gb` | float | Dataset size in GB |
+//! | `memory_used_mb` | float | Memory used in MB |
+//! | `replication` | bool | Replication enabled |
+//! | `data_persistence` | str | Persistence policy |
+//! | `tls` | bool | TLS enabled |
+//! | `public_endpoint` | str | Public endpoint |
+//! | `private_endpoint` | str | Private endpoint |
+//!
+//! # Cost report columns
+//!
+//! One record per report line. Column names are the report's FOCUS headers
+//! converted to snake_case (`BilledCost` becomes `billed_cost`,
+//! `x_SubscriptionId` becomes `x_subscription_id`). Columns ending in `_cost`,
+//! `_price` or `_quantity` are floats; every other column is a string. Empty
+//! cells are `None`.
+
+use redis_cloud::{CostReportFormat, parse_cost_report_rows};
+use serde_json::{Map, Value};
+
+/// Database inventory columns, in output order
+pub(crate) const DATABASE_COLUMNS: &[&str] = &[
+    "plan",
+    "subscription_id",
+    "subscription_name",
+    "database_id",
+    "database_name",
+    "status",
+    "provider",
+    "region",
+    "redis_version",
+    "memory_limit_gb",
+    "dataset_size_gb",
+    "memory_used_mb",
+    "replication",
+    "data_persistence",
+    "tls",
+    "public_endpoint",
+    "private_endpoint",
+];
+
+/// Flatten an account snapshot into one record per database
+///
+/// Subscriptions whose databases could not be fetched contribute no rows;
+/// the snapshot's `errors` list says why.
+pub(crate) fn database_records(snapshot: &Value) -> Vec<Value> {
+    let mut records = Vec::new();
+    for (plan, key) in [
+        ("pro", "subscriptions"),
+        ("essentials", "essentialsSubscriptions"),
+    ] {
+        for subscription in array(&snapshot[key]) {
+            for database in array(&subscription["databases"]) {
+                records.push(database_record(plan, subscription, database));
+            }
+        }
+    }
+    records
+}
+
+fn database_record(plan: &str, subscription: &Value, database: &Value) -> Value {
+    let values = [
+        Value::from(plan),
+        subscription["id"].clone(),
+        subscription["name"].clone(),
+        database["databaseId"].clone(),
+        database["name"].clone(),
+        database["status"].clone(),
+        database["provider"].clone(),
+        database["region"].clone(),
+        database["redisVersion"].clone(),
+        database["memoryLimitInGb"].clone(),
+        database["datasetSizeInGb"].clone(),
+        database["memoryUsedInMb"].clone(),
+        database["replication"].clone(),
+        database["dataPersistence"].clone(),
+        database["enableTls"].clone(),
+        database["publicEndpoint"].clone(),
+        database["privateEndpoint"].clone(),
+    ];
+    let record: Map<String, Value> = DATABASE_COLUMNS
+        .iter()
+        .map(|column| (*column).to_string())
+        .zip(values)
+        .collect();
+    Value::Object(record)
+}
+
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map_or(&[], Vec::as_slice)
+}
+
+/// Parse a downloaded cost report (CSV or JSON) into one record per line
+///
+/// Parsing is done by `redis_cloud::parse_cost_report_rows`; this only
+/// renames the columns and normalizes the cells.
+pub(crate) fn cost_report_records(report: &[u8]) -> redis_cloud::Result<Vec<Value>> {
+    let format = CostReportFormat::detect(report);
+    let rows = parse_cost_report_rows(report, &format)?;
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            Value::Object(
+                row.into_iter()
+                    .map(|(column, value)| {
+                        let column = snake_case(&column);
+                        let value = cell(&column, value);
+                        (column, value)
+                    })
+                    .collect(),
+            )
+        })
+        .collect())
+}
+
+/// Normalize one cell: empty strings become null, numeric columns become floats
+fn cell(column: &str, value: Value) -> Value {
+    let numeric = ["_cost", "_price", "_quantity"]
+        .iter()
+        .any(|suffix| column.ends_with(suffix));
+    match value {
+        Value::String(s) if s.trim().is_empty() => Value::Null,
+        Value::String(s) if numeric => s
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(Value::String(s), Value::Number),
+        Value::Number(n) if numeric => n
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(Value::Null, Value::Number),
+        Value::Number(n) => Value::String(n.to_string()),
+        Value::Bool(b) => Value::String(b.to_string()),
+        other => other,
+    }
+}
+
+/// `BilledCost` -> `billed_cost`, `ResourceID` -> `resource_id`
+fn snake_case(header: &str) -> String {
+    let chars: Vec<char> = header.trim().chars().collect();
+    let mut out = String::with_capacity(chars.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let boundary = prev.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit())
+                || (prev.is_some_and(char::is_uppercase) && next.is_some_and(|n| n.is_lowercase()));
+            if boundary && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else if c == ' ' || c == '-' {
+            if !out.ends_with('_') {
+                out.push('_');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("BilledCost"), "billed_cost");
+        assert_eq!(snake_case("ResourceID"), "resource_id");
+        assert_eq!(snake_case("x_SubscriptionId"), "x_subscription_id");
+        assert_eq!(snake_case("SKUPriceId"), "sku_price_id");
+        assert_eq!(snake_case(" Billing Period-Start "), "billing_period_start");
+    }
+
+    #[test]
+    fn test_csv_records() {
+        // BOM, CRLF line endings, quoted commas, escaped quotes, an embedded
+        // newline and a blank line
+        let report = "\u{feff}BilledCost,ChargeDescription,Tags,ConsumedQuantity\r\n\
+                      1.5,\"Shard \"\"large\"\"\",\"env=prod\r\nteam=cache\",250\r\n\
+                      \r\n\
+                      2,\"Network, cross-AZ\",,\r\n";
+        let records = cost_report_records(report.as_bytes()).unwrap();
+
+        assert_eq!(
+            records,
+            vec![
+                json!({
+                    "billed_cost": 1.5,
+                    "charge_description": "Shard \"large\"",
+                    "tags": "env=prod\r\nteam=cache",
+                    "consumed_quantity": 250.0
+                }),
+                json!({
+                    "billed_cost": 2.0,
+                    "charge_description": "Network, cross-AZ",
+                    "tags": null,
+                    "consumed_quantity": null
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_records() {
+        let report = br#"{"rows": [
+            {"BilledCost": "3.25", "RegionId": "us-east-1", "x_DatabaseId": 456},
+            "not a row"
+        ]}"#;
+        let records = cost_report_records(report).unwrap();
+
+        assert_eq!(
+            records,
+            vec![json!({
+                "billed_cost": 3.25,
+                "region_id": "us-east-1",
+                "x_database_id": "456"
+            })]
+        );
+    }
+
+    #[test]
+    fn test_empty_and_malformed_reports() {
+        assert!(cost_report_records(b"").unwrap().is_empty());
+        assert!(cost_report_records(b"BilledCost\n").unwrap().is_empty());
+        assert!(cost_report_records(b"[1, 2").is_err());
+    }
+}
@@ -131,6 +131,18 @@ class TestClientMethods:
         assert hasattr(client, "account")
         assert hasattr(client, "account_sync")
 
+    def test_client_has_snapshot_method(self, client):
+        """Test that client has account snapshot method."""
+        assert hasattr(client, "snapshot")
+        assert hasattr(client, "snapshot_sync")
+
+    def test_client_has_record_methods(self, client):
+        """Test that client has tabular export methods."""
+        assert hasattr(client, "database_records")
+        assert hasattr(client, "database_records_sync")
+        assert hasattr(client, "cost_report_records")
+        assert hasattr(client, "cost_report_records_sync")
+
     def test_client_has_raw_methods(self, client):
         """Test that client has raw HTTP methods."""
         assert hasattr(client, "get")
@@ -2,13 +2,13 @@
 //!
 //! This module provides comprehensive account management functionality for Redis Cloud,
 //! including account information retrieval, settings management, API keys, owners,
-//! payment methods, SSO/SAML configuration, and billing address management.
+//! payment methods, and billing address management.
 //!
 //! # Overview
 //!
 //! The account module is the central point for managing organization-wide settings and
 //! configurations in Redis Cloud. It handles everything from basic account information
-//! to advanced features like SSO integration and API key management.
+//! to API key management.
 //!
 //! # Key Features
 //!
@@ -16,7 +16,6 @@
 //! - **API Key Management**: Create, list, and manage API keys for programmatic access
 //! - **Owner Management**: Manage account owners and their permissions
 //! - **Payment Methods**: Handle payment methods and billing configuration
-//! - **SSO/SAML**: Configure single sign-on and SAML integration
 //! - **Billing Address**: Manage billing address information
 //!
 //! # Example Usage
@@ -43,16 +42,25 @@
 //! # }
 //! ```
 
-use crate::types::Link;
+use crate::query::QueryParams;
+use crate::types::{Link, Timestamp};
 use crate::{CloudClient, Result};
+use async_stream::try_stream;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+#[cfg(feature = "gzip")]
+use flate2::{Compression, write::GzEncoder};
+use futures_core::Stream;
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::pin::pin;
 
 // ============================================================================
 // Models
 // ============================================================================
 
 /// Database modules/capabilities response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ModulesData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modules: Option<Vec<Module>>,
@@ -63,7 +71,7 @@ pub struct ModulesData {
 }
 
 /// Root account response from GET /
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RootAccount {
     /// Account information
@@ -76,7 +84,7 @@ pub struct RootAccount {
 }
 
 /// Account information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Account {
     /// Account ID
@@ -89,11 +97,11 @@ pub struct Account {
 
     /// Timestamp when the account was created
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<String>,
+    pub created_timestamp: Option<Timestamp>,
 
     /// Timestamp when the account was last updated
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<String>,
+    pub updated_timestamp: Option<Timestamp>,
 
     /// Marketplace status (e.g., "active", "deleted")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -105,7 +113,7 @@ pub struct Account {
 }
 
 /// API key information returned in account response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountApiKeyInfo {
     /// API key name
@@ -139,10 +147,14 @@ pub struct AccountApiKeyInfo {
     /// Account marketplace ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub account_marketplace_id: Option<String>,
+
+    /// Timestamp when the API key was created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_timestamp: Option<Timestamp>,
 }
 
 /// API key owner information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountApiKeyOwner {
     /// Owner's name
@@ -155,21 +167,26 @@ pub struct AccountApiKeyOwner {
 }
 
 /// Account system log entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountSystemLogEntry {
+    /// Log entry ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i32>,
 
+    /// Timestamp of the event (ISO 8601)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time: Option<String>,
 
+    /// User or API key that triggered the event
     #[serde(skip_serializing_if = "Option::is_none")]
     pub originator: Option<String>,
 
+    /// Name of the API key used, for API-originated events
     #[serde(skip_serializing_if = "Option::is_none")]
     pub api_key_name: Option<String>,
 
+    /// Name of the affected resource
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<String>,
 
@@ -177,15 +194,25 @@ pub struct AccountSystemLogEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_id: Option<i32>,
 
+    /// Event category (e.g., "Account", "Subscription", "Database")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#type: Option<String>,
 
+    /// What happened
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
+impl AccountSystemLogEntry {
+    /// Parsed event timestamp, if present and valid RFC 3339
+    #[must_use]
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        parse_log_time(self.time.as_deref())
+    }
+}
+
 /// Available regions response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Regions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
@@ -196,7 +223,7 @@ pub struct Regions {
 }
 
 /// Region information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Region {
     /// Region ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -211,8 +238,37 @@ pub struct Region {
     pub provider: Option<String>,
 }
 
+impl Region {
+    /// Returns true if this is `name` on `provider` (provider compared case-insensitively)
+    #[must_use]
+    pub fn matches(&self, provider: &str, name: &str) -> bool {
+        self.name.as_deref() == Some(name)
+            && self
+                .provider
+                .as_deref()
+                .is_some_and(|p| p.eq_ignore_ascii_case(provider))
+    }
+}
+
+impl Regions {
+    /// Find a region by provider and name
+    #[must_use]
+    pub fn find(&self, provider: &str, name: &str) -> Option<&Region> {
+        self.regions
+            .iter()
+            .flatten()
+            .find(|region| region.matches(provider, name))
+    }
+
+    /// Returns true if the region is offered for the provider
+    #[must_use]
+    pub fn contains(&self, provider: &str, name: &str) -> bool {
+        self.find(provider, name).is_some()
+    }
+}
+
 /// Account payment methods response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethods {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -228,7 +284,7 @@ pub struct PaymentMethods {
 }
 
 /// Payment method information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethod {
     /// Payment method ID
@@ -260,8 +316,65 @@ pub struct PaymentMethod {
     pub links: Option<Vec<Link>>,
 }
 
+impl PaymentMethod {
+    /// Returns true if the card expires within `months` months of `today`.
+    ///
+    /// Cards are valid through the end of their expiration month, so a card
+    /// expiring this month is within 0 months. Cards that have already expired
+    /// also match. Payment methods without an expiration date never match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use redis_cloud::account::PaymentMethod;
+    ///
+    /// let card: PaymentMethod = serde_json::from_value(serde_json::json!({
+    ///     "id": 1,
+    ///     "expirationMonth": 3,
+    ///     "expirationYear": 2025
+    /// })).unwrap();
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    /// assert!(card.expires_within(2, today));
+    /// assert!(!card.expires_within(1, today));
+    /// ```
+    #[must_use]
+    pub fn expires_within(&self, months: u32, today: NaiveDate) -> bool {
+        match self.months_until_expiry(today) {
+            Some(remaining) => remaining <= i64::from(months),
+            None => false,
+        }
+    }
+
+    /// Number of whole months between `today` and the card's expiration month.
+    ///
+    /// Returns 0 for a card expiring this month, a negative value for an
+    /// expired card, and `None` when the expiration date is unknown.
+    #[must_use]
+    pub fn months_until_expiry(&self, today: NaiveDate) -> Option<i64> {
+        let year = i64::from(self.expiration_year?);
+        let month = i64::from(self.expiration_month?);
+        let current = i64::from(today.year()) * 12 + i64::from(today.month());
+        Some(year * 12 + month - current)
+    }
+}
+
+impl PaymentMethods {
+    /// Payment methods that expire within `months` months of `today`,
+    /// including those that have already expired.
+    #[must_use]
+    pub fn expiring_within(&self, months: u32, today: NaiveDate) -> Vec<&PaymentMethod> {
+        self.payment_methods
+            .iter()
+            .flatten()
+            .filter(|pm| pm.expires_within(months, today))
+            .collect()
+    }
+}
+
 /// Database module/capability information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Module {
     /// Module name (e.g., "`RedisJSON`", "`RediSearch`")
@@ -276,13 +389,45 @@ pub struct Module {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// Module version, when reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
     /// Module parameters configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Vec<ModuleParameter>>,
 }
 
+impl Module {
+    /// Returns true if `name` matches the module name or capability name (case-insensitive)
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        [&self.name, &self.capability_name]
+            .into_iter()
+            .flatten()
+            .any(|n| n.eq_ignore_ascii_case(name))
+    }
+
+    /// Find a supported parameter by name
+    #[must_use]
+    pub fn parameter(&self, name: &str) -> Option<&ModuleParameter> {
+        self.parameters
+            .iter()
+            .flatten()
+            .find(|p| p.name.as_deref() == Some(name))
+    }
+}
+
+impl ModulesData {
+    /// Find a module by module name or capability name (case-insensitive)
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<&Module> {
+        self.modules.iter().flatten().find(|m| m.matches(name))
+    }
+}
+
 /// Module parameter configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModuleParameter {
     /// Parameter name
@@ -307,7 +452,7 @@ pub struct ModuleParameter {
 }
 
 /// Account system log entries response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountSystemLogEntries {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entries: Option<Vec<AccountSystemLogEntry>>,
@@ -318,7 +463,7 @@ pub struct AccountSystemLogEntries {
 }
 
 /// Query performance factors (search scaling) response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchScalingFactorsData {
     /// Available query performance factors (e.g., "Standard", "2x", "4x")
@@ -331,7 +476,7 @@ pub struct SearchScalingFactorsData {
 }
 
 /// Account session log entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountSessionLogEntry {
     /// Session log entry ID (UUID)
@@ -367,8 +512,16 @@ pub struct AccountSessionLogEntry {
     pub action: Option<String>,
 }
 
+impl AccountSessionLogEntry {
+    /// Parsed event timestamp, if present and valid RFC 3339
+    #[must_use]
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        parse_log_time(self.time.as_deref())
+    }
+}
+
 /// Data persistence option entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DataPersistenceEntry {
     /// Persistence option name (e.g., "none", "aof-every-1-second")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -380,7 +533,7 @@ pub struct DataPersistenceEntry {
 }
 
 /// Data persistence options response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataPersistenceOptions {
     /// Available data persistence options
@@ -393,7 +546,7 @@ pub struct DataPersistenceOptions {
 }
 
 /// Account session log entries response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountSessionLogEntries {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entries: Option<Vec<AccountSessionLogEntry>>,
@@ -411,7 +564,7 @@ pub struct AccountSessionLogEntries {
 /// Handler for account management operations
 ///
 /// Provides methods for managing account information, API keys, owners,
-/// payment methods, SSO/SAML configuration, and billing addresses.
+/// payment methods, and billing addresses.
 pub struct AccountHandler {
     client: CloudClient,
 }
@@ -466,6 +619,39 @@ impl AccountHandler {
         self.client.get("/database-modules").await
     }
 
+    /// Get advanced capabilities as a list
+    /// Same as [`get_supported_database_modules`](Self::get_supported_database_modules),
+    /// with the `modules` array unwrapped. Each entry carries the module's
+    /// name, version (when reported), and supported parameters.
+    ///
+    /// GET /database-modules
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// for module in client.account().get_database_modules().await? {
+    ///     let parameters = module.parameters.as_deref().unwrap_or_default();
+    ///     println!("{:?}: {} parameters", module.name, parameters.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_database_modules(&self) -> Result<Vec<Module>> {
+        Ok(self
+            .get_supported_database_modules()
+            .await?
+            .modules
+            .unwrap_or_default())
+    }
+
     /// Get system logs
     /// Gets [system logs](https://redis.io/docs/latest/operate/rc/api/examples/audit-system-logs/) for this account.
     ///
@@ -475,19 +661,76 @@ impl AccountHandler {
         offset: Option<i32>,
         limit: Option<i32>,
     ) -> Result<AccountSystemLogEntries> {
-        let mut query = Vec::new();
-        if let Some(v) = offset {
-            query.push(format!("offset={v}"));
-        }
-        if let Some(v) = limit {
-            query.push(format!("limit={v}"));
+        let query = QueryParams::new()
+            .push_opt("offset", offset)
+            .push_opt("limit", limit);
+        self.client.get(&format!("/logs{query}")).await
+    }
+
+    /// Stream all system log entries
+    ///
+    /// Walks the full system log history page by page (100 entries per
+    /// request), yielding entries one at a time. Useful for audit reporting
+    /// over long periods without loading every page up front.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    /// use futures::StreamExt;
+    /// use std::pin::pin;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let account = client.account();
+    /// let mut logs = pin!(account.stream_system_logs());
+    /// while let Some(entry) = logs.next().await {
+    ///     let entry = entry?;
+    ///     println!("{:?} {:?}: {:?}", entry.time, entry.originator, entry.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_system_logs(&self) -> impl Stream<Item = Result<AccountSystemLogEntry>> + '_ {
+        self.stream_system_logs_with_page_size(100)
+    }
+
+    /// Stream all system log entries with custom page size
+    ///
+    /// Like [`stream_system_logs`](Self::stream_system_logs), but allows
+    /// specifying the number of entries fetched per API request.
+    pub fn stream_system_logs_with_page_size(
+        &self,
+        page_size: i32,
+    ) -> impl Stream<Item = Result<AccountSystemLogEntry>> + '_ {
+        try_stream! {
+            let mut offset = 0;
+
+            loop {
+                let entries = self
+                    .get_account_system_logs(Some(offset), Some(page_size))
+                    .await?
+                    .entries
+                    .unwrap_or_default();
+
+                let count = entries.len();
+                for entry in entries {
+                    yield entry;
+                }
+
+                // A short page means we've reached the end
+                #[allow(clippy::cast_sign_loss)]
+                if count < page_size as usize {
+                    break;
+                }
+
+                offset += page_size;
+            }
         }
-        let query_string = if query.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", query.join("&"))
-        };
-        self.client.get(&format!("/logs{query_string}")).await
     }
 
     /// Get payment methods
@@ -498,6 +741,43 @@ impl AccountHandler {
         self.client.get("/payment-methods").await
     }
 
+    /// Get payment methods expiring soon
+    /// Gets the payment methods that expire within `months` months from today
+    /// (UTC), including any that have already expired. Useful for alerting
+    /// before subscription payments start failing.
+    ///
+    /// GET /payment-methods
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// for card in client.account().get_payment_methods_expiring_within(2).await? {
+    ///     println!("Card {:?} expires {:?}/{:?}", card.id, card.expiration_month, card.expiration_year);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_payment_methods_expiring_within(
+        &self,
+        months: u32,
+    ) -> Result<Vec<PaymentMethod>> {
+        let today = Utc::now().date_naive();
+        let methods = self.get_account_payment_methods().await?;
+        Ok(methods
+            .expiring_within(months, today)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
     /// Get query performance factors
     /// Gets a list of available [query performance factors](https://redis.io/docs/latest/operate/rc/databases/configuration/advanced-capabilities/#query-performance-factor).
     ///
@@ -511,16 +791,40 @@ impl AccountHandler {
     ///
     /// GET /regions
     pub async fn get_supported_regions(&self, provider: Option<String>) -> Result<Regions> {
-        let mut query = Vec::new();
-        if let Some(v) = provider {
-            query.push(format!("provider={v}"));
-        }
-        let query_string = if query.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", query.join("&"))
-        };
-        self.client.get(&format!("/regions{query_string}")).await
+        let query = QueryParams::new().push_opt("provider", provider);
+        self.client.get(&format!("/regions{query}")).await
+    }
+
+    /// Get available Pro plan regions as a list
+    /// Same as [`get_supported_regions`](Self::get_supported_regions), with the
+    /// `regions` array unwrapped.
+    ///
+    /// GET /regions
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let regions = client.account().get_regions(Some("GCP")).await?;
+    /// for region in &regions {
+    ///     println!("{:?}", region.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_regions(&self, provider: Option<&str>) -> Result<Vec<Region>> {
+        Ok(self
+            .get_supported_regions(provider.map(ToString::to_string))
+            .await?
+            .regions
+            .unwrap_or_default())
     }
 
     /// Get session logs
@@ -532,20 +836,188 @@ impl AccountHandler {
         offset: Option<i32>,
         limit: Option<i32>,
     ) -> Result<AccountSessionLogEntries> {
-        let mut query = Vec::new();
-        if let Some(v) = offset {
-            query.push(format!("offset={v}"));
+        let query = QueryParams::new()
+            .push_opt("offset", offset)
+            .push_opt("limit", limit);
+        self.client.get(&format!("/session-logs{query}")).await
+    }
+
+    /// Export system log entries in a time range as NDJSON
+    ///
+    /// Walks the full system log history and writes every entry whose time
+    /// falls in `[from, to)` to `writer`, one JSON object per line. Entries
+    /// without a valid timestamp are skipped. Returns the number of entries
+    /// written.
+    ///
+    /// The writer is called synchronously; wrap it in a `BufWriter` when
+    /// writing to a file. See [`export_system_logs_gzip`](Self::export_system_logs_gzip)
+    /// for compressed archives.
+    ///
+    /// GET /logs
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use redis_cloud::CloudClient;
+    /// use std::fs::File;
+    /// use std::io::BufWriter;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let to = Utc::now();
+    /// let from = to - Duration::days(1);
+    /// let file = BufWriter::new(File::create("system-logs.ndjson")?);
+    /// let written = client.account().export_system_logs(from, to, file).await?;
+    /// println!("Archived {written} entries");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_system_logs<W: Write>(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        writer: W,
+    ) -> Result<usize> {
+        write_ndjson(
+            self.stream_system_logs(),
+            AccountSystemLogEntry::timestamp,
+            from..to,
+            writer,
+        )
+        .await
+    }
+
+    /// Export system log entries in a time range as gzip-compressed NDJSON
+    ///
+    /// Like [`export_system_logs`](Self::export_system_logs), with the output
+    /// compressed. Requires the `gzip` feature.
+    ///
+    /// GET /logs
+    #[cfg(feature = "gzip")]
+    pub async fn export_system_logs_gzip<W: Write>(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        writer: W,
+    ) -> Result<usize> {
+        let mut encoder = GzEncoder::new(writer, Compression::default());
+        let written = self.export_system_logs(from, to, &mut encoder).await?;
+        encoder.finish()?;
+        Ok(written)
+    }
+
+    /// Stream all session log entries
+    ///
+    /// Walks the full session log history page by page (100 entries per
+    /// request), like [`stream_system_logs`](Self::stream_system_logs).
+    pub fn stream_session_logs(&self) -> impl Stream<Item = Result<AccountSessionLogEntry>> + '_ {
+        self.stream_session_logs_with_page_size(100)
+    }
+
+    /// Stream all session log entries with custom page size
+    ///
+    /// Like [`stream_session_logs`](Self::stream_session_logs), but allows
+    /// specifying the number of entries fetched per API request.
+    pub fn stream_session_logs_with_page_size(
+        &self,
+        page_size: i32,
+    ) -> impl Stream<Item = Result<AccountSessionLogEntry>> + '_ {
+        try_stream! {
+            let mut offset = 0;
+
+            loop {
+                let entries = self
+                    .get_account_session_logs(Some(offset), Some(page_size))
+                    .await?
+                    .entries
+                    .unwrap_or_default();
+
+                let count = entries.len();
+                for entry in entries {
+                    yield entry;
+                }
+
+                // A short page means we've reached the end
+                #[allow(clippy::cast_sign_loss)]
+                if count < page_size as usize {
+                    break;
+                }
+
+                offset += page_size;
+            }
         }
-        if let Some(v) = limit {
-            query.push(format!("limit={v}"));
+    }
+
+    /// Export session log entries in a time range as NDJSON
+    ///
+    /// Like [`export_system_logs`](Self::export_system_logs), for session logs.
+    ///
+    /// GET /session-logs
+    pub async fn export_session_logs<W: Write>(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        writer: W,
+    ) -> Result<usize> {
+        write_ndjson(
+            self.stream_session_logs(),
+            AccountSessionLogEntry::timestamp,
+            from..to,
+            writer,
+        )
+        .await
+    }
+
+    /// Export session log entries in a time range as gzip-compressed NDJSON
+    ///
+    /// Like [`export_session_logs`](Self::export_session_logs), with the
+    /// output compressed. Requires the `gzip` feature.
+    ///
+    /// GET /session-logs
+    #[cfg(feature = "gzip")]
+    pub async fn export_session_logs_gzip<W: Write>(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        writer: W,
+    ) -> Result<usize> {
+        let mut encoder = GzEncoder::new(writer, Compression::default());
+        let written = self.export_session_logs(from, to, &mut encoder).await?;
+        encoder.finish()?;
+        Ok(written)
+    }
+}
+
+/// Parse a log entry timestamp
+fn parse_log_time(time: Option<&str>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(time?)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// Write the entries of `entries` whose timestamp falls in `range` as NDJSON
+async fn write_ndjson<T: Serialize>(
+    entries: impl Stream<Item = Result<T>>,
+    timestamp: fn(&T) -> Option<DateTime<Utc>>,
+    range: std::ops::Range<DateTime<Utc>>,
+    mut writer: impl Write,
+) -> Result<usize> {
+    let mut entries = pin!(entries);
+    let mut written = 0;
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        if !timestamp(&entry).is_some_and(|time| range.contains(&time)) {
+            continue;
         }
-        let query_string = if query.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", query.join("&"))
-        };
-        self.client
-            .get(&format!("/session-logs{query_string}"))
-            .await
+        serde_json::to_writer(&mut writer, &entry)?;
+        writer.write_all(b"\n")?;
+        written += 1;
     }
+    writer.flush()?;
+    Ok(written)
 }
@@ -40,16 +40,19 @@
 //! # }
 //! ```
 
-use crate::types::{Link, ProcessorResponse};
-use crate::{CloudClient, Result};
+use crate::tasks::{TaskWaitOptions, TasksHandler};
+use crate::types::{Link, NameMatches, ProcessorResponse, Timestamp};
+use crate::{Bytes, CloudClient, CloudError, Result};
+use futures_util::future::try_join3;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 // ============================================================================
 // Models
 // ============================================================================
 
 /// ACL role create request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclRoleCreateRequest {
     /// Database access role name.
@@ -63,7 +66,7 @@ pub struct AclRoleCreateRequest {
 }
 
 /// ACL user update request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclUserUpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -84,7 +87,7 @@ pub struct AclUserUpdateRequest {
 /// ACL users response
 ///
 /// Response from GET /acl/users
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountACLUsers {
     /// Account ID
@@ -103,7 +106,7 @@ pub struct AccountACLUsers {
 /// ACL Redis rules response
 ///
 /// Response from GET /acl/redisRules
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountACLRedisRules {
     /// Account ID
@@ -120,7 +123,7 @@ pub struct AccountACLRedisRules {
 }
 
 /// ACL Redis rule
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ACLRedisRule {
     /// Rule ID
@@ -145,7 +148,7 @@ pub struct ACLRedisRule {
 }
 
 /// ACL Redis rule create request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclRedisRuleCreateRequest {
     /// Redis ACL rule name.
@@ -161,7 +164,7 @@ pub struct AclRedisRuleCreateRequest {
 /// ACL roles response
 ///
 /// Response from GET /acl/roles
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountACLRoles {
     /// Account ID
@@ -178,7 +181,7 @@ pub struct AccountACLRoles {
 }
 
 /// ACL role
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ACLRole {
     /// Role ID
@@ -205,7 +208,7 @@ pub struct ACLRole {
 }
 
 /// User reference in an ACL role
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ACLRoleUser {
     /// User ID
@@ -221,7 +224,7 @@ pub struct ACLRoleUser {
 ///
 /// This has different field names than `ACLRedisRule` because the API
 /// uses different JSON keys when rules are embedded in role responses.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ACLRoleRedisRule {
     /// Rule ID
@@ -238,7 +241,7 @@ pub struct ACLRoleRedisRule {
 }
 
 /// Database reference in an ACL role's redis rule
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ACLRoleDatabase {
     /// Subscription ID
@@ -259,7 +262,7 @@ pub struct ACLRoleDatabase {
 }
 
 /// ACL Redis rule update request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclRedisRuleUpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -276,7 +279,7 @@ pub struct AclRedisRuleUpdateRequest {
 }
 
 /// Database specification for ACL role assignment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclRoleDatabaseSpec {
     /// Subscription ID for the database's subscription. Use 'GET /subscriptions' or 'GET /fixed/subscriptions' to get a list of available subscriptions and their IDs.
@@ -291,7 +294,7 @@ pub struct AclRoleDatabaseSpec {
 }
 
 /// ACL user create request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclUserCreateRequest {
     /// Access control user name.
@@ -308,7 +311,7 @@ pub struct AclUserCreateRequest {
 }
 
 /// ACL user information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ACLUser {
     /// User ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -332,7 +335,7 @@ pub struct ACLUser {
 }
 
 /// ACL role update request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclRoleUpdateRequest {
     /// Optional. Changes the database access role name.
@@ -351,7 +354,7 @@ pub struct AclRoleUpdateRequest {
 }
 
 /// Redis rule specification for role assignment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AclRoleRedisRuleSpec {
     /// The name of a Redis ACL rule to assign to the role. Use 'GET /acl/redisRules' to get a list of available rules for your account.
@@ -362,7 +365,7 @@ pub struct AclRoleRedisRuleSpec {
 }
 
 /// Task state update response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskStateUpdate {
     /// Task ID (UUID)
@@ -383,7 +386,7 @@ pub struct TaskStateUpdate {
 
     /// Timestamp of the task
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
 
     /// Task response with resource info
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -394,6 +397,798 @@ pub struct TaskStateUpdate {
     pub links: Option<Vec<Link>>,
 }
 
+// ============================================================================
+// Rule templates
+// ============================================================================
+
+/// A Redis ACL rule for a common access persona
+///
+/// Templates render to the rule string expected by
+/// [`AclHandler::create_redis_rule`] via `Display`, and can build the create
+/// request directly.
+///
+/// # Example
+///
+/// ```
+/// use redis_cloud::acl::{FullAccess, ReadOnly, ReadWriteNoAdmin, RuleTemplate};
+///
+/// assert_eq!(ReadOnly::on_prefix("cache:").to_string(), "+@read ~cache:*");
+/// assert_eq!(ReadWriteNoAdmin::all_keys().to_string(), "+@all -@dangerous ~*");
+/// assert_eq!(FullAccess::all_keys().to_string(), "+@all ~*");
+///
+/// let request = ReadOnly::on_prefix("cache:").create_request("cache-readers");
+/// assert_eq!(request.redis_rule, "+@read ~cache:*");
+/// ```
+pub trait RuleTemplate: std::fmt::Display {
+    /// Request creating a Redis rule named `name` from this template
+    fn create_request(&self, name: impl Into<String>) -> AclRedisRuleCreateRequest {
+        AclRedisRuleCreateRequest {
+            name: name.into(),
+            redis_rule: self.to_string(),
+            command_type: None,
+        }
+    }
+}
+
+/// Key pattern matching every key that starts with `prefix`
+fn prefix_pattern(prefix: &str) -> String {
+    format!("{}*", prefix.trim_end_matches('*'))
+}
+
+/// Read-only commands (`+@read`) on the matching keys
+///
+/// Same permissions as the built-in "Read-Only" rule when applied to all keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadOnly {
+    key_pattern: String,
+}
+
+impl ReadOnly {
+    /// Read access to every key
+    #[must_use]
+    pub fn all_keys() -> Self {
+        Self::on_pattern("*")
+    }
+
+    /// Read access to keys starting with `prefix`, e.g. `"cache:"`
+    #[must_use]
+    pub fn on_prefix(prefix: &str) -> Self {
+        Self::on_pattern(prefix_pattern(prefix))
+    }
+
+    /// Read access to keys matching a glob-style `pattern`
+    #[must_use]
+    pub fn on_pattern(pattern: impl Into<String>) -> Self {
+        Self {
+            key_pattern: pattern.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ReadOnly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+@read ~{}", self.key_pattern)
+    }
+}
+
+impl RuleTemplate for ReadOnly {}
+
+/// All commands except the `@dangerous` category on the matching keys
+///
+/// Allows reads and writes but not administrative commands such as
+/// `FLUSHALL`, `CONFIG`, or `DEBUG`. Same permissions as the built-in
+/// "Read-Write" rule when applied to all keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadWriteNoAdmin {
+    key_pattern: String,
+}
+
+impl ReadWriteNoAdmin {
+    /// Read and write access to every key
+    #[must_use]
+    pub fn all_keys() -> Self {
+        Self::on_pattern("*")
+    }
+
+    /// Read and write access to keys starting with `prefix`
+    #[must_use]
+    pub fn on_prefix(prefix: &str) -> Self {
+        Self::on_pattern(prefix_pattern(prefix))
+    }
+
+    /// Read and write access to keys matching a glob-style `pattern`
+    #[must_use]
+    pub fn on_pattern(pattern: impl Into<String>) -> Self {
+        Self {
+            key_pattern: pattern.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ReadWriteNoAdmin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+@all -@dangerous ~{}", self.key_pattern)
+    }
+}
+
+impl RuleTemplate for ReadWriteNoAdmin {}
+
+/// Every command on the matching keys
+///
+/// Same permissions as the built-in "Full-Access" rule when applied to all
+/// keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FullAccess {
+    key_pattern: String,
+}
+
+impl FullAccess {
+    /// Every command on every key
+    #[must_use]
+    pub fn all_keys() -> Self {
+        Self::on_pattern("*")
+    }
+
+    /// Every command on keys starting with `prefix`
+    #[must_use]
+    pub fn on_prefix(prefix: &str) -> Self {
+        Self::on_pattern(prefix_pattern(prefix))
+    }
+
+    /// Every command on keys matching a glob-style `pattern`
+    #[must_use]
+    pub fn on_pattern(pattern: impl Into<String>) -> Self {
+        Self {
+            key_pattern: pattern.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for FullAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+@all ~{}", self.key_pattern)
+    }
+}
+
+impl RuleTemplate for FullAccess {}
+
+/// A validated Redis ACL rule string
+///
+/// Compose one with [`AclRule::builder`], or check a hand-written rule with
+/// [`AclRule::parse`]. Like the templates above, it renders via `Display` and
+/// builds the create request through [`RuleTemplate`].
+///
+/// # Example
+///
+/// ```
+/// use redis_cloud::acl::{AclRule, RuleTemplate};
+///
+/// let rule = AclRule::builder()
+///     .allow_category("read")
+///     .allow_command("ping")
+///     .key_prefix("cache:")
+///     .build()
+///     .unwrap();
+/// assert_eq!(rule.to_string(), "+@read +ping ~cache:*");
+///
+/// assert!(AclRule::parse("+@read ~cache:*").is_ok());
+/// assert!(AclRule::parse("read everything").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AclRule {
+    rule: String,
+}
+
+impl AclRule {
+    /// Start composing a rule
+    #[must_use]
+    pub fn builder() -> AclRuleBuilder {
+        AclRuleBuilder::default()
+    }
+
+    /// Validate a hand-written rule string
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CloudError::BadRequest`] if the rule is empty, contains a
+    /// token that is not a command, category, key or channel selector, or
+    /// grants no commands.
+    pub fn parse(rule: &str) -> Result<Self> {
+        validate_rule(rule)?;
+        Ok(Self {
+            rule: rule.split_whitespace().collect::<Vec<_>>().join(" "),
+        })
+    }
+
+    /// The rule string
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.rule
+    }
+}
+
+impl std::fmt::Display for AclRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.rule)
+    }
+}
+
+impl std::str::FromStr for AclRule {
+    type Err = CloudError;
+
+    fn from_str(rule: &str) -> Result<Self> {
+        Self::parse(rule)
+    }
+}
+
+impl RuleTemplate for AclRule {}
+
+/// Builder for [`AclRule`]
+///
+/// Tokens are rendered in the order they are added. Without any key
+/// selector the rule applies to all keys (`~*`).
+#[derive(Debug, Clone, Default)]
+pub struct AclRuleBuilder {
+    commands: Vec<String>,
+    keys: Vec<String>,
+}
+
+impl AclRuleBuilder {
+    /// Allow a command category, e.g. `"read"` for `+@read`
+    #[must_use]
+    pub fn allow_category(mut self, category: &str) -> Self {
+        self.commands
+            .push(format!("+@{}", category.trim_start_matches('@')));
+        self
+    }
+
+    /// Deny a command category, e.g. `"dangerous"` for `-@dangerous`
+    #[must_use]
+    pub fn deny_category(mut self, category: &str) -> Self {
+        self.commands
+            .push(format!("-@{}", category.trim_start_matches('@')));
+        self
+    }
+
+    /// Allow a single command, e.g. `"get"` or `"client|setname"`
+    #[must_use]
+    pub fn allow_command(mut self, command: &str) -> Self {
+        self.commands.push(format!("+{command}"));
+        self
+    }
+
+    /// Deny a single command
+    #[must_use]
+    pub fn deny_command(mut self, command: &str) -> Self {
+        self.commands.push(format!("-{command}"));
+        self
+    }
+
+    /// Allow read commands (`+@read`)
+    #[must_use]
+    pub fn read(self) -> Self {
+        self.allow_category("read")
+    }
+
+    /// Allow write commands (`+@write`)
+    #[must_use]
+    pub fn write(self) -> Self {
+        self.allow_category("write")
+    }
+
+    /// Restrict to keys matching a glob-style `pattern`
+    #[must_use]
+    pub fn key_pattern(mut self, pattern: &str) -> Self {
+        self.keys.push(format!("~{pattern}"));
+        self
+    }
+
+    /// Restrict to keys starting with `prefix`, e.g. `"cache:"`
+    #[must_use]
+    pub fn key_prefix(self, prefix: &str) -> Self {
+        self.key_pattern(&prefix_pattern(prefix))
+    }
+
+    /// Restrict Pub/Sub to channels matching `pattern`
+    #[must_use]
+    pub fn channel_pattern(mut self, pattern: &str) -> Self {
+        self.keys.push(format!("&{pattern}"));
+        self
+    }
+
+    /// Build and validate the rule
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CloudError::BadRequest`] if no command was allowed or a
+    /// token is malformed.
+    pub fn build(self) -> Result<AclRule> {
+        let mut tokens = self.commands;
+        if !self.keys.iter().any(|token| token.starts_with('~')) {
+            tokens.push("~*".to_string());
+        }
+        tokens.extend(self.keys);
+        AclRule::parse(&tokens.join(" "))
+    }
+}
+
+/// Check the basic syntax of a Redis ACL rule
+fn validate_rule(rule: &str) -> Result<()> {
+    let invalid = |message: String| CloudError::BadRequest { message };
+    let mut grants = false;
+    for token in rule.split_whitespace() {
+        let (sign, name) = token.split_at(token.chars().next().map_or(0, char::len_utf8));
+        match sign {
+            "+" | "-" => {
+                let name = name.strip_prefix('@').unwrap_or(name);
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '|' | '_' | '-'))
+                {
+                    return Err(invalid(format!("Invalid command or category '{token}'")));
+                }
+                grants |= sign == "+";
+            }
+            "~" | "%" | "&" => {
+                if name.is_empty() {
+                    return Err(invalid(format!("Empty key or channel pattern '{token}'")));
+                }
+            }
+            _ if token == "allkeys" || token == "allchannels" || token == "allcommands" => {
+                grants |= token == "allcommands";
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "Unexpected token '{token}' in Redis ACL rule"
+                )));
+            }
+        }
+    }
+    if !grants {
+        return Err(invalid(format!(
+            "Redis ACL rule '{rule}' does not allow any commands"
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Request builders
+// ============================================================================
+
+/// Error for a required builder field that was not set
+fn required(field: &str) -> CloudError {
+    CloudError::BadRequest {
+        message: format!("{field} is required"),
+    }
+}
+
+impl AclRedisRuleCreateRequest {
+    /// Create a new Redis rule request builder
+    #[must_use]
+    pub fn builder() -> AclRedisRuleCreateRequestBuilder {
+        AclRedisRuleCreateRequestBuilder::default()
+    }
+}
+
+/// Builder for [`AclRedisRuleCreateRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct AclRedisRuleCreateRequestBuilder {
+    name: Option<String>,
+    redis_rule: Option<String>,
+}
+
+impl AclRedisRuleCreateRequestBuilder {
+    /// Set the rule name (required)
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the rule (required), e.g. an [`AclRule`], a template, or a string
+    #[must_use]
+    pub fn rule(mut self, rule: impl std::fmt::Display) -> Self {
+        self.redis_rule = Some(rule.to_string());
+        self
+    }
+
+    /// Build the request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` or `rule` is not set or the rule is not
+    /// valid ACL syntax.
+    pub fn build(self) -> Result<AclRedisRuleCreateRequest> {
+        let name = self.name.ok_or_else(|| required("name"))?;
+        let rule = AclRule::parse(&self.redis_rule.ok_or_else(|| required("rule"))?)?;
+        Ok(AclRedisRuleCreateRequest {
+            name,
+            redis_rule: rule.to_string(),
+            command_type: None,
+        })
+    }
+}
+
+impl AclRoleDatabaseSpec {
+    /// Association with one database
+    #[must_use]
+    pub fn new(subscription_id: i32, database_id: i32) -> Self {
+        Self {
+            subscription_id,
+            database_id,
+            regions: None,
+        }
+    }
+
+    /// Limit an Active-Active association to `regions`
+    #[must_use]
+    pub fn regions<I, S>(mut self, regions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.regions = Some(regions.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl AclRoleCreateRequest {
+    /// Create a new role request builder
+    #[must_use]
+    pub fn builder() -> AclRoleCreateRequestBuilder {
+        AclRoleCreateRequestBuilder::default()
+    }
+}
+
+/// Builder for [`AclRoleCreateRequest`]
+///
+/// # Example
+///
+/// ```
+/// use redis_cloud::acl::{AclRoleCreateRequest, AclRoleDatabaseSpec};
+///
+/// let request = AclRoleCreateRequest::builder()
+///     .name("cache-readers")
+///     .rule_on("Read-Only", 123, 456)
+///     .rule("Read-Only", [AclRoleDatabaseSpec::new(123, 789).regions(["us-east-1"])])
+///     .build()
+///     .unwrap();
+/// assert_eq!(request.redis_rules.len(), 1);
+/// assert_eq!(request.redis_rules[0].databases.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AclRoleCreateRequestBuilder {
+    name: Option<String>,
+    redis_rules: Vec<AclRoleRedisRuleSpec>,
+}
+
+impl AclRoleCreateRequestBuilder {
+    /// Set the role name (required)
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Apply the Redis rule named `rule_name` to `databases`
+    ///
+    /// Calling this again with the same rule adds to its databases.
+    #[must_use]
+    pub fn rule(
+        mut self,
+        rule_name: impl Into<String>,
+        databases: impl IntoIterator<Item = AclRoleDatabaseSpec>,
+    ) -> Self {
+        let rule_name = rule_name.into();
+        let index = match self
+            .redis_rules
+            .iter()
+            .position(|spec| spec.rule_name == rule_name)
+        {
+            Some(index) => index,
+            None => {
+                self.redis_rules.push(AclRoleRedisRuleSpec {
+                    rule_name,
+                    databases: Vec::new(),
+                });
+                self.redis_rules.len() - 1
+            }
+        };
+        self.redis_rules[index].databases.extend(databases);
+        self
+    }
+
+    /// Apply the Redis rule named `rule_name` to one database
+    #[must_use]
+    pub fn rule_on(
+        self,
+        rule_name: impl Into<String>,
+        subscription_id: i32,
+        database_id: i32,
+    ) -> Self {
+        self.rule(
+            rule_name,
+            [AclRoleDatabaseSpec::new(subscription_id, database_id)],
+        )
+    }
+
+    /// Build the request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` is not set, no rule was added, or a rule
+    /// has no databases.
+    pub fn build(self) -> Result<AclRoleCreateRequest> {
+        let name = self.name.ok_or_else(|| required("name"))?;
+        if self.redis_rules.is_empty() {
+            return Err(CloudError::BadRequest {
+                message: format!("Role '{name}' must apply at least one Redis rule"),
+            });
+        }
+        if let Some(spec) = self
+            .redis_rules
+            .iter()
+            .find(|spec| spec.databases.is_empty())
+        {
+            return Err(CloudError::BadRequest {
+                message: format!(
+                    "Redis rule '{}' in role '{name}' is not applied to any database",
+                    spec.rule_name
+                ),
+            });
+        }
+        Ok(AclRoleCreateRequest {
+            name,
+            redis_rules: self.redis_rules,
+            command_type: None,
+        })
+    }
+}
+
+impl AclUserCreateRequest {
+    /// Create a new ACL user request builder
+    #[must_use]
+    pub fn builder() -> AclUserCreateRequestBuilder {
+        AclUserCreateRequestBuilder::default()
+    }
+}
+
+/// Builder for [`AclUserCreateRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct AclUserCreateRequestBuilder {
+    name: Option<String>,
+    role: Option<String>,
+    password: Option<String>,
+}
+
+impl AclUserCreateRequestBuilder {
+    /// Set the user name (required)
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the name of the database access role (required)
+    #[must_use]
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    /// Set the database password (required)
+    #[must_use]
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Build the request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name`, `role`, or `password` is not set or empty.
+    pub fn build(self) -> Result<AclUserCreateRequest> {
+        let non_empty = |value: Option<String>, field: &str| {
+            value
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| required(field))
+        };
+        Ok(AclUserCreateRequest {
+            name: non_empty(self.name, "name")?,
+            role: non_empty(self.role, "role")?,
+            password: non_empty(self.password, "password")?,
+            command_type: None,
+        })
+    }
+}
+
+// ============================================================================
+// Declarative sync
+// ============================================================================
+
+/// Desired ACL state for [`AclHandler::sync`]
+///
+/// Entities are matched to the account's by name. A user's password is only
+/// used when the user is created: passwords cannot be read back, so an
+/// existing user is updated only when its role differs.
+#[derive(Debug, Clone, Default)]
+pub struct AclSpec {
+    /// Redis rules that should exist
+    pub redis_rules: Vec<AclRedisRuleCreateRequest>,
+    /// Database access roles that should exist, with their associations
+    pub roles: Vec<AclRoleCreateRequest>,
+    /// ACL users that should exist
+    pub users: Vec<AclUserCreateRequest>,
+}
+
+/// Options for [`AclHandler::sync`]
+#[derive(Debug, Clone, Default)]
+pub struct AclSyncOptions {
+    /// Apply the changes. When `false`, only report what would change.
+    pub confirm: bool,
+
+    /// Delete users, roles, and Redis rules that are not in the spec.
+    /// Built-in Redis rules are never deleted.
+    pub prune: bool,
+
+    /// Polling configuration for each change's task
+    pub wait: TaskWaitOptions,
+}
+
+/// Kind of ACL entity changed by [`AclHandler::sync`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AclEntity {
+    /// A Redis ACL rule
+    RedisRule,
+    /// A database access role
+    Role,
+    /// An ACL user
+    User,
+}
+
+/// One change made or planned by [`AclHandler::sync`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AclChange {
+    /// The entity does not exist yet
+    Create {
+        /// Kind of entity
+        entity: AclEntity,
+        /// Name of the entity
+        name: String,
+    },
+    /// The entity exists but differs from the spec
+    Update {
+        /// Kind of entity
+        entity: AclEntity,
+        /// ID of the entity
+        id: i32,
+        /// Name of the entity
+        name: String,
+    },
+    /// The entity is not in the spec and `prune` is set
+    Delete {
+        /// Kind of entity
+        entity: AclEntity,
+        /// ID of the entity
+        id: i32,
+        /// Name of the entity
+        name: String,
+    },
+}
+
+/// Report produced by [`AclHandler::sync`]
+#[derive(Debug, Clone, Default)]
+pub struct AclSyncReport {
+    /// Changes in the order they were, or would be, applied
+    pub changes: Vec<AclChange>,
+    /// Whether the changes were applied
+    pub applied: bool,
+}
+
+impl AclSyncReport {
+    /// Returns true if the account already matched the spec
+    #[must_use]
+    pub fn is_in_sync(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Rule string with whitespace normalized, for comparison
+fn normalize_rule(rule: &str) -> String {
+    rule.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A role's rule/database associations, order-independent
+type Associations = BTreeSet<(String, i32, i32, Vec<String>)>;
+
+/// A role's current associations in request form
+///
+/// Fails if an association lacks its rule name or database IDs, since sending
+/// the rest back would silently drop it from the role.
+fn role_rule_specs(role: &ACLRole) -> Result<Vec<AclRoleRedisRuleSpec>> {
+    let incomplete = || CloudError::UnexpectedResponse {
+        message: format!(
+            "Role '{}' has an association without a rule name or database IDs",
+            role.name.as_deref().unwrap_or_default()
+        ),
+    };
+    role.redis_rules
+        .iter()
+        .flatten()
+        .map(|rule| {
+            Ok(AclRoleRedisRuleSpec {
+                rule_name: rule.rule_name.clone().ok_or_else(incomplete)?,
+                databases: rule
+                    .databases
+                    .iter()
+                    .flatten()
+                    .map(|db| {
+                        Ok(AclRoleDatabaseSpec {
+                            subscription_id: db.subscription_id.ok_or_else(incomplete)?,
+                            database_id: db.database_id.ok_or_else(incomplete)?,
+                            regions: db.regions.clone(),
+                        })
+                    })
+                    .collect::<Result<_>>()?,
+            })
+        })
+        .collect()
+}
+
+fn desired_associations(role: &AclRoleCreateRequest) -> Associations {
+    role.redis_rules
+        .iter()
+        .flat_map(|rule| {
+            rule.databases.iter().map(|database| {
+                let mut regions = database.regions.clone().unwrap_or_default();
+                regions.sort();
+                (
+                    rule.rule_name.clone(),
+                    database.subscription_id,
+                    database.database_id,
+                    regions,
+                )
+            })
+        })
+        .collect()
+}
+
+fn current_associations(role: &ACLRole) -> Associations {
+    role.redis_rules
+        .iter()
+        .flatten()
+        .flat_map(|rule| {
+            let rule_name = rule.rule_name.clone().unwrap_or_default();
+            rule.databases.iter().flatten().map(move |database| {
+                let mut regions = database.regions.clone().unwrap_or_default();
+                regions.sort();
+                (
+                    rule_name.clone(),
+                    database.subscription_id.unwrap_or_default(),
+                    database.database_id.unwrap_or_default(),
+                    regions,
+                )
+            })
+        })
+        .collect()
+}
+
+/// Reject specs that name the same entity twice
+fn check_unique_names<'a>(kind: &str, names: impl IntoIterator<Item = &'a str>) -> Result<()> {
+    let mut seen = BTreeSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            return Err(CloudError::BadRequest {
+                message: format!("ACL spec lists {kind} '{name}' more than once"),
+            });
+        }
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Handler
 // ============================================================================
@@ -437,6 +1232,19 @@ impl AclHandler {
         self.client.get("/acl/redisRules").await
     }
 
+    /// Find Redis ACL rules by name
+    /// Names are not unique; see [`NameMatches`].
+    ///
+    /// GET /acl/redisRules
+    pub async fn find_redis_rules_by_name(&self, name: &str) -> Result<NameMatches<ACLRedisRule>> {
+        let rules = self.get_all_redis_rules().await?.redis_rules;
+        Ok(NameMatches::from_items(
+            name,
+            rules.into_iter().flatten(),
+            |rule| rule.name.as_deref(),
+        ))
+    }
+
     /// Create Redis ACL rule
     /// Creates a new Redis ACL rule.
     ///
@@ -498,6 +1306,19 @@ impl AclHandler {
         self.client.get("/acl/roles").await
     }
 
+    /// Find database access roles by name
+    /// Names are not unique; see [`NameMatches`].
+    ///
+    /// GET /acl/roles
+    pub async fn find_roles_by_name(&self, name: &str) -> Result<NameMatches<ACLRole>> {
+        let roles = self.get_roles().await?.roles;
+        Ok(NameMatches::from_items(
+            name,
+            roles.into_iter().flatten(),
+            |role| role.name.as_deref(),
+        ))
+    }
+
     /// Create database access role
     /// Creates a new database access role with the assigned permissions and associates it with the provided databases.
     ///
@@ -506,6 +1327,16 @@ impl AclHandler {
         self.client.post("/acl/roles", request).await
     }
 
+    /// Create database access role from a pre-serialized request
+    /// Like [`create_role`](Self::create_role), with `body` holding the JSON
+    /// of an [`AclRoleCreateRequest`]. The bytes are sent as-is, which keeps
+    /// peak memory down for roles spanning many databases.
+    ///
+    /// POST /acl/roles
+    pub async fn create_role_from_value(&self, body: impl Into<Bytes>) -> Result<TaskStateUpdate> {
+        self.client.post_bytes("/acl/roles", body.into()).await
+    }
+
     /// Delete database access role
     /// Deletes a database access role.
     ///
@@ -532,6 +1363,129 @@ impl AclHandler {
             .await
     }
 
+    /// Update database access role from a pre-serialized request
+    /// Like [`update_role`](Self::update_role), with `body` holding the JSON
+    /// of an [`AclRoleUpdateRequest`], sent as-is.
+    ///
+    /// PUT /acl/roles/{aclRoleId}
+    pub async fn update_role_from_value(
+        &self,
+        acl_role_id: i32,
+        body: impl Into<Bytes>,
+    ) -> Result<TaskStateUpdate> {
+        self.client
+            .put_bytes(&format!("/acl/roles/{acl_role_id}"), body.into())
+            .await
+    }
+
+    /// Apply a Redis rule to a database within a role
+    /// Looks up the role by name and adds the database to the rule's
+    /// associations, creating the association if the role does not use the
+    /// rule yet. The role's other associations are sent back unchanged; if
+    /// one of them lacks its rule name or IDs, the call fails with
+    /// `CloudError::UnexpectedResponse` rather than dropping it.
+    ///
+    /// Returns `None` without calling the API if the rule already applies to
+    /// the database. Fails with `CloudError::BadRequest` unless exactly one
+    /// role is named `role`.
+    ///
+    /// GET /acl/roles, then PUT /acl/roles/{aclRoleId}
+    pub async fn grant_role_on_database(
+        &self,
+        role: &str,
+        subscription_id: i32,
+        database_id: i32,
+        rule: &str,
+    ) -> Result<Option<TaskStateUpdate>> {
+        let current = self
+            .find_roles_by_name(role)
+            .await?
+            .into_unique("ACL role", role)?;
+        let mut rules = role_rule_specs(&current)?;
+        let index = match rules.iter().position(|spec| spec.rule_name == rule) {
+            Some(index) => index,
+            None => {
+                rules.push(AclRoleRedisRuleSpec {
+                    rule_name: rule.to_string(),
+                    databases: Vec::new(),
+                });
+                rules.len() - 1
+            }
+        };
+        let databases = &mut rules[index].databases;
+        if databases
+            .iter()
+            .any(|db| db.subscription_id == subscription_id && db.database_id == database_id)
+        {
+            return Ok(None);
+        }
+        databases.push(AclRoleDatabaseSpec::new(subscription_id, database_id));
+        self.put_role_rules(&current, role, rules).await.map(Some)
+    }
+
+    /// Stop applying a Redis rule to a database within a role
+    /// Looks up the role by name and removes the database from the rule's
+    /// associations, dropping the rule from the role once it applies to no
+    /// database. The role's other associations are sent back unchanged, as
+    /// with [`grant_role_on_database`](Self::grant_role_on_database).
+    ///
+    /// Returns `None` without calling the API if the rule does not apply to
+    /// the database. Fails with `CloudError::BadRequest` unless exactly one
+    /// role is named `role`, or if the role would be left without any rule;
+    /// delete the role instead.
+    ///
+    /// GET /acl/roles, then PUT /acl/roles/{aclRoleId}
+    pub async fn revoke_role_on_database(
+        &self,
+        role: &str,
+        subscription_id: i32,
+        database_id: i32,
+        rule: &str,
+    ) -> Result<Option<TaskStateUpdate>> {
+        let current = self
+            .find_roles_by_name(role)
+            .await?
+            .into_unique("ACL role", role)?;
+        let mut rules = role_rule_specs(&current)?;
+        let Some(index) = rules.iter().position(|spec| spec.rule_name == rule) else {
+            return Ok(None);
+        };
+        let databases = &mut rules[index].databases;
+        let before = databases.len();
+        databases
+            .retain(|db| db.subscription_id != subscription_id || db.database_id != database_id);
+        if databases.len() == before {
+            return Ok(None);
+        }
+        if databases.is_empty() {
+            rules.remove(index);
+        }
+        if rules.is_empty() {
+            return Err(CloudError::BadRequest {
+                message: format!(
+                    "Revoking '{rule}' on database {database_id} would leave role '{role}' without rules"
+                ),
+            });
+        }
+        self.put_role_rules(&current, role, rules).await.map(Some)
+    }
+
+    /// Replace a role's rule associations, keeping its name
+    async fn put_role_rules(
+        &self,
+        current: &ACLRole,
+        role: &str,
+        redis_rules: Vec<AclRoleRedisRuleSpec>,
+    ) -> Result<TaskStateUpdate> {
+        let id = entity_id(current.id, "role", role)?;
+        let request = AclRoleUpdateRequest {
+            name: Some(role.to_string()),
+            redis_rules: Some(redis_rules),
+            ..Default::default()
+        };
+        self.update_role(id, &request).await
+    }
+
     /// Get access control users
     /// Gets a list of all access control users for this account.
     ///
@@ -540,6 +1494,19 @@ impl AclHandler {
         self.client.get("/acl/users").await
     }
 
+    /// Find ACL users by name
+    /// Names are not unique; see [`NameMatches`].
+    ///
+    /// GET /acl/users
+    pub async fn find_users_by_name(&self, name: &str) -> Result<NameMatches<ACLUser>> {
+        let users = self.get_all_acl_users().await?.users;
+        Ok(NameMatches::from_items(
+            name,
+            users.into_iter().flatten(),
+            |user| user.name.as_deref(),
+        ))
+    }
+
     /// Create access control user
     /// Creates a new access control user with the assigned database access role.
     ///
@@ -581,4 +1548,287 @@ impl AclHandler {
             .put(&format!("/acl/users/{acl_user_id}"), request)
             .await
     }
+
+    /// Bring the account's ACLs in line with `desired`
+    ///
+    /// Compares the spec with the current Redis rules, roles, and users by
+    /// name and issues only the creates and updates needed, followed by
+    /// deletes of unlisted entities when [`AclSyncOptions::prune`] is set.
+    /// Rules are created before the roles that use them and roles before
+    /// their users; deletes run in the opposite order. Each change waits for
+    /// its task before the next is submitted.
+    ///
+    /// Without [`AclSyncOptions::confirm`] nothing is changed and the report
+    /// lists the planned changes. Built-in Redis rules are never updated or
+    /// deleted.
+    ///
+    /// Stops at the first failing change. The changes before it stay
+    /// applied, so running `sync` again picks up where it stopped.
+    ///
+    /// GET /acl/redisRules, GET /acl/roles, GET /acl/users, then POST, PUT,
+    /// and DELETE on the same paths as needed
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    /// use redis_cloud::acl::{
+    ///     AclRedisRuleCreateRequest, AclRoleCreateRequest, AclSpec, AclSyncOptions,
+    ///     AclUserCreateRequest, ReadOnly,
+    /// };
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let spec = AclSpec {
+    ///     redis_rules: vec![
+    ///         AclRedisRuleCreateRequest::builder()
+    ///             .name("cache-readers")
+    ///             .rule(ReadOnly::on_prefix("cache:"))
+    ///             .build()?,
+    ///     ],
+    ///     roles: vec![
+    ///         AclRoleCreateRequest::builder()
+    ///             .name("cache-role")
+    ///             .rule_on("cache-readers", 123, 456)
+    ///             .build()?,
+    ///     ],
+    ///     users: vec![
+    ///         AclUserCreateRequest::builder()
+    ///             .name("app")
+    ///             .role("cache-role")
+    ///             .password("initial-Passw0rd!")
+    ///             .build()?,
+    ///     ],
+    /// };
+    /// let options = AclSyncOptions {
+    ///     confirm: true,
+    ///     ..Default::default()
+    /// };
+    /// let report = client.acl().sync(&spec, &options).await?;
+    /// println!("{} changes applied", report.changes.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sync(&self, desired: &AclSpec, options: &AclSyncOptions) -> Result<AclSyncReport> {
+        check_unique_names(
+            "Redis rule",
+            desired.redis_rules.iter().map(|rule| rule.name.as_str()),
+        )?;
+        check_unique_names("role", desired.roles.iter().map(|role| role.name.as_str()))?;
+        check_unique_names("user", desired.users.iter().map(|user| user.name.as_str()))?;
+
+        let (rules, roles, users) = try_join3(
+            self.get_all_redis_rules(),
+            self.get_roles(),
+            self.get_all_acl_users(),
+        )
+        .await?;
+        let rules = rules.redis_rules.unwrap_or_default();
+        let roles = roles.roles.unwrap_or_default();
+        let users = users.users.unwrap_or_default();
+
+        let mut report = AclSyncReport {
+            changes: Vec::new(),
+            applied: options.confirm,
+        };
+
+        for rule in &desired.redis_rules {
+            let current = rules
+                .iter()
+                .find(|current| current.name.as_deref() == Some(rule.name.as_str()));
+            match current {
+                None => {
+                    if options.confirm {
+                        let task = self.create_redis_rule(rule).await?;
+                        self.wait(task, &options.wait).await?;
+                    }
+                    report.changes.push(AclChange::Create {
+                        entity: AclEntity::RedisRule,
+                        name: rule.name.clone(),
+                    });
+                }
+                Some(current) if current.is_default == Some(true) => {}
+                Some(current)
+                    if current.acl.as_deref().map(normalize_rule)
+                        != Some(normalize_rule(&rule.redis_rule)) =>
+                {
+                    let id = entity_id(current.id, "Redis rule", &rule.name)?;
+                    if options.confirm {
+                        let request = AclRedisRuleUpdateRequest {
+                            name: rule.name.clone(),
+                            redis_rule: rule.redis_rule.clone(),
+                            ..Default::default()
+                        };
+                        let task = self.update_redis_rule(id, &request).await?;
+                        self.wait(task, &options.wait).await?;
+                    }
+                    report.changes.push(AclChange::Update {
+                        entity: AclEntity::RedisRule,
+                        id,
+                        name: rule.name.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        for role in &desired.roles {
+            let current = roles
+                .iter()
+                .find(|current| current.name.as_deref() == Some(role.name.as_str()));
+            match current {
+                None => {
+                    if options.confirm {
+                        let task = self.create_role(role).await?;
+                        self.wait(task, &options.wait).await?;
+                    }
+                    report.changes.push(AclChange::Create {
+                        entity: AclEntity::Role,
+                        name: role.name.clone(),
+                    });
+                }
+                Some(current) if current_associations(current) != desired_associations(role) => {
+                    let id = entity_id(current.id, "role", &role.name)?;
+                    if options.confirm {
+                        let request = AclRoleUpdateRequest {
+                            name: Some(role.name.clone()),
+                            redis_rules: Some(role.redis_rules.clone()),
+                            ..Default::default()
+                        };
+                        let task = self.update_role(id, &request).await?;
+                        self.wait(task, &options.wait).await?;
+                    }
+                    report.changes.push(AclChange::Update {
+                        entity: AclEntity::Role,
+                        id,
+                        name: role.name.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        for user in &desired.users {
+            let current = users
+                .iter()
+                .find(|current| current.name.as_deref() == Some(user.name.as_str()));
+            match current {
+                None => {
+                    if options.confirm {
+                        let task = self.create_user(user).await?;
+                        self.wait(task, &options.wait).await?;
+                    }
+                    report.changes.push(AclChange::Create {
+                        entity: AclEntity::User,
+                        name: user.name.clone(),
+                    });
+                }
+                Some(current) if current.role.as_deref() != Some(user.role.as_str()) => {
+                    let id = entity_id(current.id, "user", &user.name)?;
+                    if options.confirm {
+                        let request = AclUserUpdateRequest {
+                            role: Some(user.role.clone()),
+                            ..Default::default()
+                        };
+                        let task = self.update_acl_user(id, &request).await?;
+                        self.wait(task, &options.wait).await?;
+                    }
+                    report.changes.push(AclChange::Update {
+                        entity: AclEntity::User,
+                        id,
+                        name: user.name.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        if !options.prune {
+            return Ok(report);
+        }
+
+        for user in &users {
+            let (Some(id), Some(name)) = (user.id, user.name.as_deref()) else {
+                continue;
+            };
+            if desired.users.iter().any(|desired| desired.name == name) {
+                continue;
+            }
+            if options.confirm {
+                let task = self.delete_user(id).await?;
+                self.wait(task, &options.wait).await?;
+            }
+            report.changes.push(AclChange::Delete {
+                entity: AclEntity::User,
+                id,
+                name: name.to_string(),
+            });
+        }
+
+        for role in &roles {
+            let (Some(id), Some(name)) = (role.id, role.name.as_deref()) else {
+                continue;
+            };
+            if desired.roles.iter().any(|desired| desired.name == name) {
+                continue;
+            }
+            if options.confirm {
+                let task = self.delete_acl_role(id).await?;
+                self.wait(task, &options.wait).await?;
+            }
+            report.changes.push(AclChange::Delete {
+                entity: AclEntity::Role,
+                id,
+                name: name.to_string(),
+            });
+        }
+
+        for rule in &rules {
+            let (Some(id), Some(name)) = (rule.id, rule.name.as_deref()) else {
+                continue;
+            };
+            if rule.is_default == Some(true)
+                || desired
+                    .redis_rules
+                    .iter()
+                    .any(|desired| desired.name == name)
+            {
+                continue;
+            }
+            if options.confirm {
+                let task = self.delete_redis_rule(id).await?;
+                self.wait(task, &options.wait).await?;
+            }
+            report.changes.push(AclChange::Delete {
+                entity: AclEntity::RedisRule,
+                id,
+                name: name.to_string(),
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// Wait for an ACL task to finish successfully
+    async fn wait(&self, task: TaskStateUpdate, options: &TaskWaitOptions) -> Result<()> {
+        let task_id = task.task_id.ok_or_else(|| CloudError::UnexpectedResponse {
+            message: "Response did not include a task ID".to_string(),
+        })?;
+        TasksHandler::new(self.client.clone())
+            .wait_for_task(&task_id, options)
+            .await?
+            .into_result()?;
+        Ok(())
+    }
+}
+
+/// ID of an existing entity that needs updating
+fn entity_id(id: Option<i32>, kind: &str, name: &str) -> Result<i32> {
+    id.ok_or_else(|| CloudError::UnexpectedResponse {
+        message: format!("ACL {kind} '{name}' was listed without an ID"),
+    })
 }
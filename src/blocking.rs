@@ -0,0 +1,407 @@
+//! Blocking (synchronous) client facade
+//!
+//! For tools that are not async, [`CloudClient`] wraps the async client with
+//! an internal single-threaded Tokio runtime. Handler accessors mirror the
+//! async client (`subscriptions()`, `databases()`, ...) and return a
+//! [`Handler`]. The core Pro subscription, database, and task operations are
+//! available as synchronous methods on it; [`call`](Handler::call) runs any
+//! other async handler method to completion. Raw verbs (`get_raw`,
+//! `post_raw`, ...) are available directly.
+//!
+//! Only available with the `blocking` feature. Blocking calls must not be
+//! made from inside an async runtime; doing so panics.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use redis_cloud::CloudClient;
+//!
+//! let client = CloudClient::builder()
+//!     .api_key("your-api-key")
+//!     .api_secret("your-api-secret")
+//!     .build_blocking()?;
+//!
+//! let subscriptions = client.subscriptions().get_all_subscriptions()?;
+//! let database = client.databases().get_subscription_database_by_id(123, 456)?;
+//!
+//! let backups = client
+//!     .databases()
+//!     .call(|h| h.get_database_backup_status(123, 456, None))?;
+//!
+//! let account = client.get_raw("/")?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::client::CredentialCheck;
+use crate::databases::{
+    self, AccountSubscriptionDatabases, Database, DatabaseCreateRequest, DatabaseUpdateRequest,
+};
+use crate::subscriptions::{
+    self, AccountSubscriptions, BaseSubscriptionUpdateRequest, Subscription,
+    SubscriptionCreateRequest,
+};
+use crate::tasks::{self, TaskWaitOptions};
+use crate::{CloudError, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Synchronous Redis Cloud API client
+///
+/// Cheap to clone; clones share the underlying HTTP client and runtime.
+#[derive(Clone)]
+pub struct CloudClient {
+    inner: crate::CloudClient,
+    runtime: Arc<Runtime>,
+}
+
+impl CloudClient {
+    /// Create a builder; finish it with `build_blocking()`
+    #[must_use]
+    pub fn builder() -> crate::CloudClientBuilder {
+        crate::CloudClientBuilder::new()
+    }
+
+    /// Wrap an existing async client
+    pub fn from_async(client: crate::CloudClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| CloudError::IoError(format!("Failed to start runtime: {e}")))?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The wrapped async client
+    #[must_use]
+    pub fn inner(&self) -> &crate::CloudClient {
+        &self.inner
+    }
+
+    /// Run a future to completion on the internal runtime
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Check that the configured credentials work
+    ///
+    /// See [`crate::CloudClient::verify_credentials`].
+    pub fn verify_credentials(&self) -> Result<CredentialCheck> {
+        self.block_on(self.inner.verify_credentials())
+    }
+
+    // ========================================================================
+    // Handler accessors
+    // ========================================================================
+
+    fn handler<H>(&self, handler: H) -> Handler<H> {
+        Handler {
+            handler,
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+
+    /// Account management operations
+    #[must_use]
+    pub fn account(&self) -> Handler<crate::AccountHandler> {
+        self.handler(self.inner.account())
+    }
+
+    /// Pro subscription operations
+    #[must_use]
+    pub fn subscriptions(&self) -> Handler<crate::SubscriptionHandler> {
+        self.handler(self.inner.subscriptions())
+    }
+
+    /// Pro database operations
+    #[must_use]
+    pub fn databases(&self) -> Handler<crate::DatabaseHandler> {
+        self.handler(self.inner.databases())
+    }
+
+    /// Pro database metrics
+    #[must_use]
+    pub fn metrics(&self) -> Handler<crate::MetricsHandler> {
+        self.handler(self.inner.metrics())
+    }
+
+    /// Essentials subscription operations
+    #[must_use]
+    pub fn fixed_subscriptions(&self) -> Handler<crate::FixedSubscriptionHandler> {
+        self.handler(self.inner.fixed_subscriptions())
+    }
+
+    /// Essentials database operations
+    #[must_use]
+    pub fn fixed_databases(&self) -> Handler<crate::FixedDatabaseHandler> {
+        self.handler(self.inner.fixed_databases())
+    }
+
+    /// ACL operations
+    #[must_use]
+    pub fn acl(&self) -> Handler<crate::AclHandler> {
+        self.handler(self.inner.acl())
+    }
+
+    /// User management operations
+    #[must_use]
+    pub fn users(&self) -> Handler<crate::UserHandler> {
+        self.handler(self.inner.users())
+    }
+
+    /// Async task tracking operations
+    #[must_use]
+    pub fn tasks(&self) -> Handler<crate::TaskHandler> {
+        self.handler(self.inner.tasks())
+    }
+
+    /// Cloud provider account operations
+    #[must_use]
+    pub fn cloud_accounts(&self) -> Handler<crate::CloudAccountHandler> {
+        self.handler(self.inner.cloud_accounts())
+    }
+
+    /// VPC peering operations
+    #[must_use]
+    pub fn vpc_peering(&self) -> Handler<crate::VpcPeeringHandler> {
+        self.handler(self.inner.vpc_peering())
+    }
+
+    /// Transit Gateway operations
+    #[must_use]
+    pub fn transit_gateway(&self) -> Handler<crate::TransitGatewayHandler> {
+        self.handler(self.inner.transit_gateway())
+    }
+
+    /// Private Service Connect operations
+    #[must_use]
+    pub fn psc(&self) -> Handler<crate::PscHandler> {
+        self.handler(self.inner.psc())
+    }
+
+    /// AWS `PrivateLink` operations
+    #[must_use]
+    pub fn private_link(&self) -> Handler<crate::PrivateLinkHandler> {
+        self.handler(self.inner.private_link())
+    }
+
+    /// Cost report operations
+    #[must_use]
+    pub fn cost_reports(&self) -> Handler<crate::CostReportHandler> {
+        self.handler(self.inner.cost_reports())
+    }
+
+    // ========================================================================
+    // Raw API access
+    // ========================================================================
+
+    /// Make a GET request and deserialize the response
+    pub fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.block_on(self.inner.get(path))
+    }
+
+    /// Make a POST request and deserialize the response
+    pub fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        self.block_on(self.inner.post(path, body))
+    }
+
+    /// Make a PUT request and deserialize the response
+    pub fn put<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        self.block_on(self.inner.put(path, body))
+    }
+
+    /// Make a DELETE request
+    pub fn delete(&self, path: &str) -> Result<()> {
+        self.block_on(self.inner.delete(path))
+    }
+
+    /// Make a GET request and return the raw JSON response
+    pub fn get_raw(&self, path: &str) -> Result<serde_json::Value> {
+        self.block_on(self.inner.get_raw(path))
+    }
+
+    /// Make a POST request with a JSON body and return the raw JSON response
+    pub fn post_raw(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
+        self.block_on(self.inner.post_raw(path, body))
+    }
+
+    /// Make a PUT request with a JSON body and return the raw JSON response
+    pub fn put_raw(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
+        self.block_on(self.inner.put_raw(path, body))
+    }
+
+    /// Make a DELETE request and return the raw JSON response
+    pub fn delete_raw(&self, path: &str) -> Result<serde_json::Value> {
+        self.block_on(self.inner.delete_raw(path))
+    }
+}
+
+/// Synchronous wrapper around an async handler
+pub struct Handler<H> {
+    handler: H,
+    runtime: Arc<Runtime>,
+}
+
+impl<H> Handler<H> {
+    /// Run one of the handler's async methods to completion
+    ///
+    /// ```rust,no_run
+    /// # let client = redis_cloud::CloudClient::builder()
+    /// #     .api_key("key")
+    /// #     .api_secret("secret")
+    /// #     .build_blocking()?;
+    /// let task = client.tasks().call(|h| h.get_task_by_id("task-id".to_string()))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn call<'a, F, Fut>(&'a self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'a H) -> Fut,
+        Fut: Future,
+    {
+        self.runtime.block_on(f(&self.handler))
+    }
+
+    /// The wrapped async handler
+    #[must_use]
+    pub fn inner(&self) -> &H {
+        &self.handler
+    }
+}
+
+impl Handler<crate::SubscriptionHandler> {
+    /// See [`crate::SubscriptionHandler::get_all_subscriptions`]
+    pub fn get_all_subscriptions(&self) -> Result<AccountSubscriptions> {
+        self.runtime.block_on(self.handler.get_all_subscriptions())
+    }
+
+    /// See [`crate::SubscriptionHandler::get_subscription_by_id`]
+    pub fn get_subscription_by_id(&self, subscription_id: i32) -> Result<Subscription> {
+        self.runtime
+            .block_on(self.handler.get_subscription_by_id(subscription_id))
+    }
+
+    /// See [`crate::SubscriptionHandler::create_subscription`]
+    pub fn create_subscription(
+        &self,
+        request: &SubscriptionCreateRequest,
+    ) -> Result<subscriptions::TaskStateUpdate> {
+        self.runtime
+            .block_on(self.handler.create_subscription(request))
+    }
+
+    /// See [`crate::SubscriptionHandler::update_subscription`]
+    pub fn update_subscription(
+        &self,
+        subscription_id: i32,
+        request: &BaseSubscriptionUpdateRequest,
+    ) -> Result<subscriptions::TaskStateUpdate> {
+        self.runtime
+            .block_on(self.handler.update_subscription(subscription_id, request))
+    }
+
+    /// See [`crate::SubscriptionHandler::delete_subscription_by_id`]
+    pub fn delete_subscription_by_id(
+        &self,
+        subscription_id: i32,
+    ) -> Result<subscriptions::TaskStateUpdate> {
+        self.runtime
+            .block_on(self.handler.delete_subscription_by_id(subscription_id))
+    }
+}
+
+impl Handler<crate::DatabaseHandler> {
+    /// See [`crate::DatabaseHandler::get_subscription_databases`]
+    pub fn get_subscription_databases(
+        &self,
+        subscription_id: i32,
+        offset: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<AccountSubscriptionDatabases> {
+        self.runtime
+            .block_on(
+                self.handler
+                    .get_subscription_databases(subscription_id, offset, limit),
+            )
+    }
+
+    /// See [`crate::DatabaseHandler::get_all_databases`]
+    pub fn get_all_databases(&self, subscription_id: i32) -> Result<Vec<Database>> {
+        self.runtime
+            .block_on(self.handler.get_all_databases(subscription_id))
+    }
+
+    /// See [`crate::DatabaseHandler::get_subscription_database_by_id`]
+    pub fn get_subscription_database_by_id(
+        &self,
+        subscription_id: i32,
+        database_id: i32,
+    ) -> Result<Database> {
+        self.runtime.block_on(
+            self.handler
+                .get_subscription_database_by_id(subscription_id, database_id),
+        )
+    }
+
+    /// See [`crate::DatabaseHandler::create_database`]
+    pub fn create_database(
+        &self,
+        subscription_id: i32,
+        request: &DatabaseCreateRequest,
+    ) -> Result<databases::TaskStateUpdate> {
+        self.runtime
+            .block_on(self.handler.create_database(subscription_id, request))
+    }
+
+    /// See [`crate::DatabaseHandler::update_database`]
+    pub fn update_database(
+        &self,
+        subscription_id: i32,
+        database_id: i32,
+        request: &DatabaseUpdateRequest,
+    ) -> Result<databases::TaskStateUpdate> {
+        self.runtime.block_on(
+            self.handler
+                .update_database(subscription_id, database_id, request),
+        )
+    }
+
+    /// See [`crate::DatabaseHandler::delete_database_by_id`]
+    pub fn delete_database_by_id(
+        &self,
+        subscription_id: i32,
+        database_id: i32,
+    ) -> Result<databases::TaskStateUpdate> {
+        self.runtime.block_on(
+            self.handler
+                .delete_database_by_id(subscription_id, database_id),
+        )
+    }
+}
+
+impl Handler<crate::TaskHandler> {
+    /// See [`crate::TaskHandler::get_all_tasks`]
+    pub fn get_all_tasks(&self) -> Result<Vec<tasks::TaskStateUpdate>> {
+        self.runtime.block_on(self.handler.get_all_tasks())
+    }
+
+    /// See [`crate::TaskHandler::get_task_by_id`]
+    pub fn get_task_by_id(&self, task_id: &str) -> Result<tasks::TaskStateUpdate> {
+        self.runtime
+            .block_on(self.handler.get_task_by_id(task_id.to_string()))
+    }
+
+    /// See [`crate::TaskHandler::wait_for_task`]
+    pub fn wait_for_task(
+        &self,
+        task_id: &str,
+        options: &TaskWaitOptions,
+    ) -> Result<tasks::TaskStateUpdate> {
+        self.runtime
+            .block_on(self.handler.wait_for_task(task_id, options))
+    }
+}
@@ -0,0 +1,204 @@
+//! In-memory caching of GET responses
+//!
+//! Catalog endpoints such as `/regions`, `/database-modules`, and
+//! `/fixed/plans` rarely change but tend to be read on every pass of a
+//! provisioning loop. With [`CloudClientBuilder::response_cache`] enabled,
+//! successful GET responses for allowlisted paths are kept for a fixed TTL and
+//! served locally instead of hitting the API again. The cache is shared by
+//! every clone of the client; other verbs and non-matching paths always go to
+//! the API.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use redis_cloud::CloudClient;
+//! use redis_cloud::cache::ResponseCacheConfig;
+//! use std::time::Duration;
+//!
+//! let client = CloudClient::builder()
+//!     .api_key("your-api-key")
+//!     .api_secret("your-api-secret")
+//!     .response_cache(
+//!         ResponseCacheConfig::catalog(Duration::from_secs(600)).path("/payment-methods"),
+//!     )
+//!     .build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`CloudClientBuilder::response_cache`]: crate::CloudClientBuilder::response_cache
+
+use crate::clock::Clock;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Paths cached by [`ResponseCacheConfig::catalog`]
+pub const CATALOG_PATHS: &[&str] = &[
+    "/regions",
+    "/database-modules",
+    "/subscriptions/redis-versions",
+    "/fixed/plans",
+    "/fixed/redis-versions",
+];
+
+/// Which GET responses to cache, and for how long
+#[derive(Debug, Clone)]
+pub struct ResponseCacheConfig {
+    ttl: Duration,
+    paths: Vec<String>,
+}
+
+impl ResponseCacheConfig {
+    /// Cache nothing until paths are added with [`path`](Self::path)
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            paths: Vec::new(),
+        }
+    }
+
+    /// Cache the read-only catalog endpoints listed in [`CATALOG_PATHS`]
+    #[must_use]
+    pub fn catalog(ttl: Duration) -> Self {
+        CATALOG_PATHS
+            .iter()
+            .fold(Self::new(ttl), |config, path| config.path(*path))
+    }
+
+    /// Allow caching of `path` and everything below it
+    ///
+    /// `/fixed/plans` matches `/fixed/plans`, `/fixed/plans?provider=AWS`, and
+    /// `/fixed/plans/98276`, but not `/fixed/plansx`.
+    #[must_use]
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.paths.push(normalize(&path.into()).to_string());
+        self
+    }
+
+    /// How long a cached response is served before it is fetched again
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The allowlisted path prefixes
+    #[must_use]
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    fn allows(&self, path: &str) -> bool {
+        let path = normalize(path);
+        self.paths.iter().any(|prefix| {
+            path.strip_prefix(prefix.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?']))
+        })
+    }
+}
+
+fn normalize(path: &str) -> &str {
+    path.trim_start_matches('/').trim_end_matches('/')
+}
+
+/// Cached response bodies keyed by path (including the query string)
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    config: ResponseCacheConfig,
+    entries: Mutex<HashMap<String, (Instant, Vec<u8>)>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: ResponseCacheConfig, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+
+    /// Whether responses for `path` may be cached
+    pub(crate) fn allows(&self, path: &str) -> bool {
+        self.config.allows(path)
+    }
+
+    /// A fresh cached body for `path`, if any
+    pub(crate) fn get(&self, path: &str) -> Option<Vec<u8>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let key = normalize(path);
+        let now = self.clock.now();
+        match entries.get(key) {
+            Some((stored, body)) if now.saturating_duration_since(*stored) < self.config.ttl => {
+                Some(body.clone())
+            }
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, path: &str, body: Vec<u8>) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(normalize(path).to_string(), (self.clock.now(), body));
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::SystemClock;
+
+    #[test]
+    fn test_path_matching() {
+        let config = ResponseCacheConfig::new(Duration::from_secs(60))
+            .path("/fixed/plans")
+            .path("regions/");
+
+        assert!(config.allows("/fixed/plans"));
+        assert!(config.allows("fixed/plans/98276"));
+        assert!(config.allows("/fixed/plans?provider=AWS"));
+        assert!(config.allows("/regions?provider=GCP"));
+        assert!(!config.allows("/fixed/plansx"));
+        assert!(!config.allows("/fixed/subscriptions"));
+        assert!(!config.allows("/subscriptions/1/regions"));
+    }
+
+    #[test]
+    fn test_catalog_paths() {
+        let config = ResponseCacheConfig::catalog(Duration::from_secs(60));
+        assert!(config.allows("/database-modules"));
+        assert!(config.allows("/subscriptions/redis-versions?subscriptionId=1"));
+        assert!(!config.allows("/subscriptions"));
+    }
+
+    #[test]
+    fn test_entries_expire() {
+        let cache = ResponseCache::new(
+            ResponseCacheConfig::new(Duration::ZERO).path("/regions"),
+            Arc::new(SystemClock),
+        );
+        cache.insert("/regions", b"[]".to_vec());
+        assert!(cache.get("/regions").is_none());
+
+        let cache = ResponseCache::new(
+            ResponseCacheConfig::new(Duration::from_secs(60)).path("/regions"),
+            Arc::new(SystemClock),
+        );
+        cache.insert("/regions", b"[]".to_vec());
+        assert_eq!(cache.get("regions").as_deref(), Some(&b"[]"[..]));
+        cache.clear();
+        assert!(cache.get("/regions").is_none());
+    }
+}
@@ -6,11 +6,19 @@
 //! The client is designed around a builder pattern for flexible configuration and supports
 //! both typed and untyped API interactions.
 
+use crate::cache::{ResponseCache, ResponseCacheConfig};
+use crate::clock::{Clock, SystemClock};
+use crate::error::{DEFAULT_BUSY_RETRY_AFTER, is_busy_message};
+use crate::interceptor::{Interceptors, RequestInterceptor};
+use crate::limiter::{ConcurrencyLimiter, ConcurrencyStats, LimitsSnapshot, RateLimiter};
 use crate::{CloudError as RestError, Result};
+use bytes::Bytes;
 use reqwest::Client;
-use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue, USER_AGENT};
 use serde::Serialize;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tracing::{debug, instrument, trace};
 
 /// Default user agent for the Redis Cloud client
@@ -48,6 +56,19 @@ pub struct CloudClientBuilder {
     base_url: String,
     timeout: std::time::Duration,
     user_agent: String,
+    busy_retries: u32,
+    rate_limit_backoff: Option<RateLimitBackoff>,
+    interceptors: Interceptors,
+    http_client: Option<Client>,
+    root_certificates: Vec<Vec<u8>>,
+    accept_invalid_certs: bool,
+    min_tls_version: Option<reqwest::tls::Version>,
+    http2_prior_knowledge: bool,
+    http2_keep_alive: Option<(Duration, Duration)>,
+    max_concurrent_requests: Option<usize>,
+    rate_limit: Option<(u32, u32)>,
+    response_cache: Option<ResponseCacheConfig>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for CloudClientBuilder {
@@ -58,6 +79,19 @@ impl Default for CloudClientBuilder {
             base_url: "https://api.redislabs.com/v1".to_string(),
             timeout: std::time::Duration::from_secs(30),
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            busy_retries: 0,
+            rate_limit_backoff: None,
+            interceptors: Interceptors::default(),
+            http_client: None,
+            root_certificates: Vec::new(),
+            accept_invalid_certs: false,
+            min_tls_version: None,
+            http2_prior_knowledge: false,
+            http2_keep_alive: None,
+            max_concurrent_requests: None,
+            rate_limit: None,
+            response_cache: None,
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -108,36 +142,482 @@ impl CloudClientBuilder {
         self
     }
 
+    /// Retry operations rejected because the target resource is busy
+    ///
+    /// When a subscription or database is still pending or transitioning, the
+    /// API rejects mutations with a 400/409 response. With retries enabled the
+    /// client waits for the suggested interval (or the `Retry-After` header)
+    /// and resends the request up to `max_retries` times before surfacing
+    /// `CloudError::ResourceBusy`. Busy rejections are recognised from the
+    /// error code or description of the response body. Disabled (0) by
+    /// default, in which case they surface as `BadRequest` or `Conflict`.
+    #[must_use]
+    pub fn retry_on_busy(mut self, max_retries: u32) -> Self {
+        self.busy_retries = max_retries;
+        self
+    }
+
+    /// Back off and resend requests rejected with 429 Too Many Requests
+    ///
+    /// The client waits for the `Retry-After` interval when the API sends
+    /// one, and otherwise for an exponentially growing delay, then resends the
+    /// same request. Pagination streams such as
+    /// [`DatabaseHandler::stream_databases`](crate::DatabaseHandler::stream_databases)
+    /// therefore continue from the page that was throttled instead of ending
+    /// with an error. Once the policy's time budget is spent,
+    /// `CloudError::RateLimited` is returned. Disabled by default.
+    #[must_use]
+    pub fn retry_on_rate_limit(mut self, backoff: RateLimitBackoff) -> Self {
+        self.rate_limit_backoff = Some(backoff);
+        self
+    }
+
+    /// Limit the number of requests in flight at once
+    ///
+    /// All clones of the built client, and every handler created from them,
+    /// share the same budget. Requests beyond the limit wait for a slot, which
+    /// avoids 429 responses when fanning out over many resources. Queue wait
+    /// times are available from [`CloudClient::concurrency_stats`]. Unlimited
+    /// by default; a limit of 0 is rejected by `build()`.
+    #[must_use]
+    pub fn max_concurrent_requests(mut self, limit: usize) -> Self {
+        self.max_concurrent_requests = Some(limit);
+        self
+    }
+
+    /// Limit the request rate with a token bucket
+    ///
+    /// Tokens refill at `requests_per_second` up to `burst`; each request
+    /// takes one and waits when none are left. The bucket is shared by all
+    /// clones of the built client and every handler created from them, so
+    /// the limit holds account-wide for this process. Combine with
+    /// [`max_concurrent_requests`](Self::max_concurrent_requests) to bound
+    /// both rate and parallelism. Metrics are available from
+    /// [`CloudClient::limits_snapshot`]. Unlimited by default; a rate or burst
+    /// of 0 is rejected by `build()`.
+    #[must_use]
+    pub fn rate_limit(mut self, requests_per_second: u32, burst: u32) -> Self {
+        self.rate_limit = Some((requests_per_second, burst));
+        self
+    }
+
+    /// Cache successful GET responses for allowlisted paths
+    ///
+    /// Cached bodies are shared by all clones of the built client and served
+    /// without contacting the API until the configured TTL expires. Use
+    /// [`ResponseCacheConfig::catalog`] for the read-only catalog endpoints
+    /// (regions, modules, Redis versions, Essentials plans). See
+    /// [`crate::cache`]. Disabled by default.
+    #[must_use]
+    pub fn response_cache(mut self, config: ResponseCacheConfig) -> Self {
+        self.response_cache = Some(config);
+        self
+    }
+
+    /// Replace the time source used for retries, polling, and caching
+    ///
+    /// Busy-retry and task-polling waits, concurrency wait metrics, and
+    /// response cache expiry all go through this clock. Intended for tests
+    /// that need to exercise timeouts without real delays; see
+    /// [`crate::clock`]. Defaults to [`SystemClock`].
+    #[must_use]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Use a preconfigured `reqwest::Client` for all requests
+    ///
+    /// Useful when proxies, TLS settings, and connection pooling are managed
+    /// centrally. The supplied client is used as-is: the `timeout`,
+    /// `user_agent`, TLS, and HTTP/2 settings of this builder are not applied to it,
+    /// so configure them on the client itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use redis_cloud::CloudClient;
+    ///
+    /// let http = reqwest::Client::builder()
+    ///     .proxy(reqwest::Proxy::all("http://proxy.internal:3128")?)
+    ///     .build()?;
+    ///
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .with_http_client(http)
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Trust an additional root certificate (PEM encoded)
+    ///
+    /// Needed when the API is reached through a gateway or proxy that
+    /// re-signs TLS with a private CA. The certificate is added to the
+    /// default trust store; it can be called multiple times. Invalid PEM data
+    /// is reported by `build()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use redis_cloud::CloudClient;
+    ///
+    /// let ca = std::fs::read("/etc/ssl/internal-ca.pem")?;
+    ///
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .base_url("https://gateway.internal/redis-cloud/v1")
+    ///     .add_root_certificate(ca)
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn add_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Disable TLS certificate verification
+    ///
+    /// # Warning
+    ///
+    /// Any certificate, including expired or self-signed ones, is accepted,
+    /// which makes the connection vulnerable to interception. Only use this
+    /// against local test servers; prefer `add_root_certificate` otherwise.
+    #[must_use]
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Set the minimum TLS version accepted when connecting
+    ///
+    /// Defaults to the TLS backend's minimum. Versions the backend does not
+    /// support (TLS 1.0/1.1 with rustls) are reported by `build()`.
+    #[must_use]
+    pub fn min_tls_version(mut self, version: reqwest::tls::Version) -> Self {
+        self.min_tls_version = Some(version);
+        self
+    }
+
+    /// Speak HTTP/2 without negotiating it first
+    ///
+    /// By default HTTP/2 is only used when the server offers it during the
+    /// TLS handshake. With prior knowledge every connection is HTTP/2 from
+    /// the start, so this only works against servers and proxies that
+    /// accept HTTP/2 directly.
+    #[must_use]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Send HTTP/2 keepalive pings on open connections
+    ///
+    /// A ping is sent every `interval`, including while the connection is
+    /// idle between polls, and the connection is closed if no answer arrives
+    /// within `timeout`. This keeps long task-waiting sessions from being cut
+    /// by load balancers that drop quiet connections. Has no effect on
+    /// HTTP/1.1 connections.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use redis_cloud::CloudClient;
+    /// use std::time::Duration;
+    ///
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .http2_keep_alive(Duration::from_secs(30), Duration::from_secs(10))
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn http2_keep_alive(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.http2_keep_alive = Some((interval, timeout));
+        self
+    }
+
+    /// Register a request interceptor
+    ///
+    /// Interceptors run before every request is sent and after every response
+    /// is received, for all verbs including the raw helpers. Multiple
+    /// interceptors run in the order they were registered.
+    #[must_use]
+    pub fn interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
     /// Build the client
     pub fn build(self) -> Result<CloudClient> {
-        let api_key = self
-            .api_key
-            .ok_or_else(|| RestError::ConnectionError("API key is required".to_string()))?;
-        let api_secret = self
-            .api_secret
-            .ok_or_else(|| RestError::ConnectionError("API secret is required".to_string()))?;
-
-        let mut default_headers = HeaderMap::new();
-        default_headers.insert(
-            USER_AGENT,
-            HeaderValue::from_str(&self.user_agent)
-                .map_err(|e| RestError::ConnectionError(format!("Invalid user agent: {e}")))?,
-        );
-
-        let client = Client::builder()
-            .timeout(self.timeout)
-            .default_headers(default_headers)
-            .build()
-            .map_err(|e| RestError::ConnectionError(e.to_string()))?;
+        let api_key = self.api_key.ok_or_else(|| RestError::Configuration {
+            message: "API key is required".to_string(),
+        })?;
+        let api_secret = self.api_secret.ok_or_else(|| RestError::Configuration {
+            message: "API secret is required".to_string(),
+        })?;
+
+        let limiter = match self.max_concurrent_requests {
+            Some(0) => {
+                return Err(RestError::Configuration {
+                    message: "max_concurrent_requests must be at least 1".to_string(),
+                });
+            }
+            Some(limit) => Some(Arc::new(ConcurrencyLimiter::new(
+                limit,
+                Arc::clone(&self.clock),
+            ))),
+            None => None,
+        };
+
+        let rate_limiter = match self.rate_limit {
+            Some((0, _) | (_, 0)) => {
+                return Err(RestError::Configuration {
+                    message: "rate_limit requires a rate and burst of at least 1".to_string(),
+                });
+            }
+            Some((requests_per_second, burst)) => Some(Arc::new(RateLimiter::new(
+                requests_per_second,
+                burst,
+                Arc::clone(&self.clock),
+            ))),
+            None => None,
+        };
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut default_headers = HeaderMap::new();
+                default_headers.insert(
+                    USER_AGENT,
+                    HeaderValue::from_str(&self.user_agent).map_err(|e| {
+                        RestError::Configuration {
+                            message: format!("Invalid user agent: {e}"),
+                        }
+                    })?,
+                );
+
+                let mut builder = Client::builder()
+                    .timeout(self.timeout)
+                    .default_headers(default_headers)
+                    .danger_accept_invalid_certs(self.accept_invalid_certs);
+
+                for pem in &self.root_certificates {
+                    let certificate = reqwest::Certificate::from_pem(pem).map_err(|e| {
+                        RestError::Configuration {
+                            message: format!("Invalid root certificate: {e}"),
+                        }
+                    })?;
+                    builder = builder.add_root_certificate(certificate);
+                }
+
+                if let Some(version) = self.min_tls_version {
+                    builder = builder.min_tls_version(version);
+                }
+
+                if self.http2_prior_knowledge {
+                    builder = builder.http2_prior_knowledge();
+                }
+
+                if let Some((interval, timeout)) = self.http2_keep_alive {
+                    builder = builder
+                        .http2_keep_alive_interval(interval)
+                        .http2_keep_alive_timeout(timeout)
+                        .http2_keep_alive_while_idle(true);
+                }
+
+                builder.build().map_err(|e| RestError::Configuration {
+                    message: format!("Failed to build HTTP client: {e}"),
+                })?
+            }
+        };
 
         Ok(CloudClient {
             api_key,
             api_secret,
             base_url: self.base_url,
             timeout: self.timeout,
+            busy_retries: self.busy_retries,
+            rate_limit_backoff: self.rate_limit_backoff,
+            interceptors: self.interceptors,
+            limiter,
+            rate_limiter,
+            cache: self
+                .response_cache
+                .map(|config| Arc::new(ResponseCache::new(config, Arc::clone(&self.clock)))),
+            clock: self.clock,
             client: Arc::new(client),
         })
     }
+
+    /// Build a blocking (synchronous) client
+    ///
+    /// Available with the `blocking` feature. See [`crate::blocking`].
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::CloudClient> {
+        crate::blocking::CloudClient::from_async(self.build()?)
+    }
+}
+
+/// Outcome of [`CloudClient::verify_credentials`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialCheck {
+    /// The API accepted the credentials
+    Ok {
+        /// ID of the account the API key belongs to
+        account_id: Option<i32>,
+    },
+    /// The API rejected the API key or secret (401/403)
+    BadCredentials {
+        /// Error message from the API
+        message: String,
+    },
+    /// The API could not be reached (DNS, TLS, timeout, connection refused)
+    NetworkIssue {
+        /// Underlying transport error
+        message: String,
+    },
+}
+
+impl CredentialCheck {
+    /// Returns true if the credentials were accepted
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        matches!(self, CredentialCheck::Ok { .. })
+    }
+}
+
+/// Per-call overrides applied with [`CloudClient::with_options`]
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use redis_cloud::{CloudClient, RequestOptions};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = CloudClient::builder()
+///     .api_key("your-api-key")
+///     .api_secret("your-api-secret")
+///     .build()?;
+///
+/// let preview = client.with_options(
+///     RequestOptions::new().base_url("https://api-preview.redislabs.com/v1"),
+/// );
+/// let databases = preview.databases().get_subscription_databases(123, None, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+    base_url: Option<String>,
+}
+
+impl RequestOptions {
+    /// No overrides
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send requests to another API host, e.g. a regional or preview endpoint
+    #[must_use]
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+}
+
+/// Time-boxed exponential backoff for 429 responses
+///
+/// See [`CloudClientBuilder::retry_on_rate_limit`]. Without a `Retry-After`
+/// header the first wait is `initial_delay`, doubling on each further 429 up
+/// to `max_delay`. Retrying stops once the next wait would take the total
+/// time spent on the request past `max_elapsed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitBackoff {
+    /// Wait before the first retry when no `Retry-After` is given
+    pub initial_delay: Duration,
+    /// Upper bound for a single wait, including `Retry-After` values
+    pub max_delay: Duration,
+    /// Total time budget for retrying one request
+    pub max_elapsed: Duration,
+}
+
+impl Default for RateLimitBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_elapsed: Duration::from_secs(300),
+        }
+    }
+}
+
+impl RateLimitBackoff {
+    /// Set the wait before the first retry
+    #[must_use]
+    pub fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Set the upper bound for a single wait
+    #[must_use]
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the total time budget for retrying one request
+    #[must_use]
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = budget;
+        self
+    }
+
+    /// The wait before retry number `attempt` (starting at 0)
+    fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        retry_after
+            .unwrap_or_else(|| {
+                self.initial_delay
+                    .saturating_mul(2u32.saturating_pow(attempt.min(31)))
+            })
+            .min(self.max_delay)
+    }
+}
+
+/// Progress of a streamed download
+///
+/// Passed to the progress callback of [`CloudClient::get_bytes_to`] after
+/// each chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Bytes written to the destination so far
+    pub bytes_written: u64,
+    /// Size of the whole body, when the server sent `Content-Length`
+    pub total_bytes: Option<u64>,
+}
+
+impl DownloadProgress {
+    /// Fraction of the body written, between 0.0 and 1.0, if the size is known
+    #[must_use]
+    pub fn fraction(&self) -> Option<f64> {
+        self.total_bytes.map(|total| {
+            if total == 0 {
+                1.0
+            } else {
+                (self.bytes_written as f64 / total as f64).min(1.0)
+            }
+        })
+    }
 }
 
 /// Redis Cloud API client
@@ -147,6 +627,13 @@ pub struct CloudClient {
     pub(crate) api_secret: String,
     pub(crate) base_url: String,
     pub(crate) timeout: std::time::Duration,
+    pub(crate) busy_retries: u32,
+    pub(crate) rate_limit_backoff: Option<RateLimitBackoff>,
+    pub(crate) interceptors: Interceptors,
+    pub(crate) limiter: Option<Arc<ConcurrencyLimiter>>,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    pub(crate) cache: Option<Arc<ResponseCache>>,
+    pub(crate) clock: Arc<dyn Clock>,
     pub(crate) client: Arc<Client>,
 }
 
@@ -166,6 +653,115 @@ impl CloudClient {
         self.timeout
     }
 
+    /// The API base URL requests are sent to
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// A view of this client with per-call overrides applied
+    ///
+    /// The returned client shares the connection pool, credentials,
+    /// interceptors, and concurrency limit with `self`, so it is cheap to
+    /// create for a single call. Handlers created from it use the overrides.
+    /// When the base URL is overridden the response cache is bypassed, since
+    /// cached bodies belong to the original host.
+    #[must_use]
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        let mut client = self.clone();
+        if let Some(base_url) = options.base_url {
+            if base_url != client.base_url {
+                client.cache = None;
+            }
+            client.base_url = base_url;
+        }
+        client
+    }
+
+    /// Concurrency limiter metrics
+    ///
+    /// Returns `None` unless the client was built with
+    /// [`CloudClientBuilder::max_concurrent_requests`].
+    #[must_use]
+    pub fn concurrency_stats(&self) -> Option<ConcurrencyStats> {
+        self.limiter.as_ref().map(|limiter| limiter.stats())
+    }
+
+    /// Concurrency and rate limiter state shared by this client's clones
+    ///
+    /// Limiter state lives behind an `Arc`, so every clone of a client and
+    /// every handler created from one reports the same numbers here.
+    /// [`LimitsSnapshot::shared_handles`] counts those handles, which
+    /// confirms that e.g. a handler created for a background task draws from
+    /// the same budget.
+    #[must_use]
+    pub fn limits_snapshot(&self) -> LimitsSnapshot {
+        let shared_handles = self
+            .limiter
+            .as_ref()
+            .map(Arc::strong_count)
+            .into_iter()
+            .chain(self.rate_limiter.as_ref().map(Arc::strong_count))
+            .max()
+            .unwrap_or(0);
+        LimitsSnapshot {
+            concurrency: self.concurrency_stats(),
+            rate: self.rate_limiter.as_ref().map(|limiter| limiter.stats()),
+            shared_handles,
+        }
+    }
+
+    /// Drop every cached GET response
+    ///
+    /// Has no effect unless a response cache was configured with
+    /// [`CloudClientBuilder::response_cache`].
+    pub fn clear_response_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Check that the configured credentials work
+    ///
+    /// Performs a lightweight authenticated call (the current account) and
+    /// classifies the result, so configuration can be validated at startup.
+    /// Errors that are neither credential nor network problems (for example
+    /// a 5xx response) are returned as `Err`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use redis_cloud::{CloudClient, CredentialCheck};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// match client.verify_credentials().await? {
+    ///     CredentialCheck::Ok { account_id } => println!("Connected to account {account_id:?}"),
+    ///     CredentialCheck::BadCredentials { message } => eprintln!("Check API key: {message}"),
+    ///     CredentialCheck::NetworkIssue { message } => eprintln!("Cannot reach API: {message}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_credentials(&self) -> Result<CredentialCheck> {
+        match self.get::<crate::account::RootAccount>("/").await {
+            Ok(root) => Ok(CredentialCheck::Ok {
+                account_id: root.account.and_then(|account| account.id),
+            }),
+            Err(RestError::AuthenticationFailed { message } | RestError::Forbidden { message }) => {
+                Ok(CredentialCheck::BadCredentials { message })
+            }
+            Err(RestError::Request(message) | RestError::ConnectionError(message)) => {
+                Ok(CredentialCheck::NetworkIssue { message })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     // ========================================================================
     // Fluent API - Handler accessors
     // ========================================================================
@@ -233,6 +829,27 @@ impl CloudClient {
         crate::DatabaseHandler::new(self.clone())
     }
 
+    /// Get a metrics handler for Prometheus scrape endpoints
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use redis_cloud::CloudClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("key")
+    ///     .api_secret("secret")
+    ///     .build()?;
+    ///
+    /// let url = client.metrics().prometheus_url(123, 456).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn metrics(&self) -> crate::MetricsHandler {
+        crate::MetricsHandler::new(self.clone())
+    }
+
     /// Get a fixed subscription handler for Essentials subscription operations
     ///
     /// # Example
@@ -481,6 +1098,7 @@ impl CloudClient {
             401 => RestError::AuthenticationFailed { message: text },
             403 => RestError::Forbidden { message: text },
             404 => RestError::NotFound { message: text },
+            409 => RestError::Conflict { message: text },
             412 => RestError::PreconditionFailed,
             429 => RestError::RateLimited { message: text },
             500 => RestError::InternalServerError { message: text },
@@ -492,25 +1110,155 @@ impl CloudClient {
         }
     }
 
+    /// Send a request, retrying while the target resource is busy or the
+    /// account is rate limited
+    ///
+    /// Responses other than a busy rejection or a 429 are returned untouched
+    /// so the caller can apply its own handling. Requests whose body cannot be
+    /// cloned are sent once without retry.
+    pub(crate) async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let request = request.build()?;
+        let started = self.clock.now();
+        let mut attempt = 0;
+        let mut throttled = 0;
+        loop {
+            let Some(current) = request.try_clone() else {
+                return self.execute(request).await;
+            };
+            let response = self.execute(current).await?;
+            let status = response.status();
+            let header_wait = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+
+            if status.as_u16() == 429 {
+                let Some(backoff) = &self.rate_limit_backoff else {
+                    return Ok(response);
+                };
+                let wait = backoff.delay(throttled, header_wait);
+                let elapsed = self.clock.now().saturating_duration_since(started);
+                if elapsed + wait > backoff.max_elapsed {
+                    return Ok(response);
+                }
+                throttled += 1;
+                debug!("Rate limited, retrying in {wait:?} (attempt {throttled})");
+                self.clock.sleep(wait).await;
+                continue;
+            }
+            if self.busy_retries == 0 || !matches!(status.as_u16(), 400 | 409) {
+                return Ok(response);
+            }
+
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|e| format!("(failed to read response body: {e})"));
+            if !is_busy_message(&message) {
+                return Err(Self::status_to_error(status, message));
+            }
+            let retry_after = header_wait.unwrap_or(DEFAULT_BUSY_RETRY_AFTER);
+
+            if attempt >= self.busy_retries {
+                return Err(RestError::ResourceBusy {
+                    message,
+                    retry_after,
+                });
+            }
+            attempt += 1;
+            debug!(
+                "Resource busy, retrying in {retry_after:?} (attempt {attempt}/{}): {message}",
+                self.busy_retries
+            );
+            self.clock.sleep(retry_after).await;
+        }
+    }
+
+    /// Execute a single request, running registered interceptors around it
+    async fn execute(&self, mut request: reqwest::Request) -> Result<reqwest::Response> {
+        self.interceptors.before_request(&mut request)?;
+        let method = request.method().clone();
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let permit = match &self.limiter {
+            Some(limiter) => Some(limiter.acquire().await),
+            None => None,
+        };
+        let response = self.client.execute(request).await?;
+        self.interceptors.after_response(&method, &response);
+        Ok(match permit {
+            Some(permit) => crate::limiter::hold_until_read(response, permit),
+            None => response,
+        })
+    }
+
     /// Make a GET request with API key authentication
     #[instrument(skip(self), fields(method = "GET"))]
     pub async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = self.normalize_url(path);
+        if let Some(cache) = self.cache.as_ref().filter(|cache| cache.allows(path)) {
+            return self.get_cached(cache, path, &url).await;
+        }
         debug!("GET {}", url);
 
         // Redis Cloud API uses these headers for authentication
         let response = self
-            .client
-            .get(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .send()
+            .send(
+                self.client
+                    .get(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
         self.handle_response(response).await
     }
 
+    /// GET through the response cache
+    async fn get_cached<T: serde::de::DeserializeOwned>(
+        &self,
+        cache: &ResponseCache,
+        path: &str,
+        url: &str,
+    ) -> Result<T> {
+        if let Some(body) = cache.get(path) {
+            debug!("GET {} (cached)", url);
+            return Self::deserialize_body(&body);
+        }
+        debug!("GET {}", url);
+
+        let response = self
+            .send(
+                self.client
+                    .get(url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret),
+            )
+            .await?;
+
+        trace!("Response status: {}", response.status());
+        let status = response.status();
+        if !status.is_success() {
+            let text = response
+                .text()
+                .await
+                .unwrap_or_else(|e| format!("(failed to read response body: {e})"));
+            return Err(Self::status_to_error(status, text));
+        }
+
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| RestError::ConnectionError(format!("Failed to read response: {e}")))?;
+        let value = Self::deserialize_body(&bytes)?;
+        cache.insert(path, bytes.to_vec());
+        Ok(value)
+    }
+
     /// Make a POST request
     #[instrument(skip(self, body), fields(method = "POST"))]
     pub async fn post<B: Serialize, T: serde::de::DeserializeOwned>(
@@ -524,12 +1272,13 @@ impl CloudClient {
 
         // Same backwards header naming as GET
         let response = self
-            .client
-            .post(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .json(body)
-            .send()
+            .send(
+                self.client
+                    .post(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret)
+                    .json(body),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
@@ -549,12 +1298,70 @@ impl CloudClient {
 
         // Same backwards header naming as GET
         let response = self
-            .client
-            .put(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .json(body)
-            .send()
+            .send(
+                self.client
+                    .put(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret)
+                    .json(body),
+            )
+            .await?;
+
+        trace!("Response status: {}", response.status());
+        self.handle_response(response).await
+    }
+
+    /// Make a POST request with a pre-serialized JSON body
+    ///
+    /// The bytes are sent as-is with `Content-Type: application/json`, so a
+    /// large payload is not serialized again or copied into an intermediate
+    /// `Value`. Cloning [`Bytes`] is cheap, so busy and rate-limit retries
+    /// reuse the same buffer.
+    #[instrument(skip(self, body), fields(method = "POST", body_len = body.len()))]
+    pub async fn post_bytes<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: Bytes,
+    ) -> Result<T> {
+        let url = self.normalize_url(path);
+        debug!("POST {} ({} byte body)", url, body.len());
+
+        let response = self
+            .send(
+                self.client
+                    .post(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body),
+            )
+            .await?;
+
+        trace!("Response status: {}", response.status());
+        self.handle_response(response).await
+    }
+
+    /// Make a PUT request with a pre-serialized JSON body
+    ///
+    /// See [`post_bytes`](Self::post_bytes).
+    #[instrument(skip(self, body), fields(method = "PUT", body_len = body.len()))]
+    pub async fn put_bytes<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: Bytes,
+    ) -> Result<T> {
+        let url = self.normalize_url(path);
+        debug!("PUT {} ({} byte body)", url, body.len());
+
+        let response = self
+            .send(
+                self.client
+                    .put(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
@@ -569,11 +1376,12 @@ impl CloudClient {
 
         // Same backwards header naming as GET
         let response = self
-            .client
-            .delete(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .send()
+            .send(
+                self.client
+                    .delete(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
@@ -604,11 +1412,12 @@ impl CloudClient {
         debug!("GET {} (bytes)", url);
 
         let response = self
-            .client
-            .get(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .send()
+            .send(
+                self.client
+                    .get(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
@@ -629,6 +1438,61 @@ impl CloudClient {
         }
     }
 
+    /// Execute GET request, streaming the body into `writer`
+    ///
+    /// Like [`get_bytes`](Self::get_bytes) without holding the whole body in
+    /// memory. `on_progress` is called after each chunk is written. Returns
+    /// the number of bytes written; `writer` is flushed but not shut down.
+    #[instrument(skip(self, writer, on_progress), fields(method = "GET"))]
+    pub async fn get_bytes_to<W, F>(
+        &self,
+        path: &str,
+        writer: &mut W,
+        mut on_progress: F,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+        F: FnMut(DownloadProgress),
+    {
+        let url = self.normalize_url(path);
+        debug!("GET {} (stream)", url);
+
+        let mut response = self
+            .send(
+                self.client
+                    .get(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret),
+            )
+            .await?;
+
+        trace!("Response status: {}", response.status());
+        let status = response.status();
+        if !status.is_success() {
+            let text = response
+                .text()
+                .await
+                .unwrap_or_else(|e| format!("(failed to read response body: {e})"));
+            return Err(Self::status_to_error(status, text));
+        }
+
+        let mut progress = DownloadProgress {
+            bytes_written: 0,
+            total_bytes: response.content_length(),
+        };
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| RestError::ConnectionError(format!("Failed to read response: {e}")))?
+        {
+            writer.write_all(&chunk).await?;
+            progress.bytes_written += chunk.len() as u64;
+            on_progress(progress);
+        }
+        writer.flush().await?;
+        Ok(progress.bytes_written)
+    }
+
     /// Execute raw POST request with JSON body
     #[instrument(skip(self, body), fields(method = "POST"))]
     pub async fn post_raw(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
@@ -654,12 +1518,13 @@ impl CloudClient {
 
         // Use backwards header names for compatibility
         let response = self
-            .client
-            .patch(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .patch(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret)
+                    .json(&body),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
@@ -674,11 +1539,12 @@ impl CloudClient {
 
         // Use backwards header names for compatibility
         let response = self
-            .client
-            .delete(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .send()
+            .send(
+                self.client
+                    .delete(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
@@ -710,12 +1576,13 @@ impl CloudClient {
         trace!("Request body: {:?}", body);
 
         let response = self
-            .client
-            .delete(&url)
-            .header("x-api-key", &self.api_key)
-            .header("x-api-secret-key", &self.api_secret)
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .delete(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("x-api-secret-key", &self.api_secret)
+                    .json(&body),
+            )
             .await?;
 
         trace!("Response status: {}", response.status());
@@ -768,17 +1635,7 @@ impl CloudClient {
                 .await
                 .map_err(|e| RestError::ConnectionError(format!("Failed to read response: {e}")))?;
 
-            // Use serde_path_to_error for better deserialization error messages
-            let deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(deserializer).map_err(|err| {
-                let path = err.path().to_string();
-                // Use ConnectionError to provide detailed error message with field path
-                RestError::ConnectionError(format!(
-                    "Failed to deserialize field '{}': {}",
-                    path,
-                    err.inner()
-                ))
-            })
+            Self::deserialize_body(&bytes)
         } else {
             let text = response
                 .text()
@@ -787,6 +1644,21 @@ impl CloudClient {
             Err(Self::status_to_error(status, text))
         }
     }
+
+    /// Deserialize a successful response body
+    fn deserialize_body<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+        // Use serde_path_to_error for better deserialization error messages
+        let deserializer = &mut serde_json::Deserializer::from_slice(bytes);
+        serde_path_to_error::deserialize(deserializer).map_err(|err| {
+            let path = err.path().to_string();
+            // Use ConnectionError to provide detailed error message with field path
+            RestError::ConnectionError(format!(
+                "Failed to deserialize field '{}': {}",
+                path,
+                err.inner()
+            ))
+        })
+    }
 }
 
 /// Tower Service integration for `CloudClient`
@@ -986,10 +1858,12 @@ pub mod tower_support {
                     Method::Delete => client.client.delete(&url),
                 };
 
-                let response = request_builder
-                    .header("x-api-key", &client.api_key)
-                    .header("x-api-secret-key", &client.api_secret)
-                    .send()
+                let response = client
+                    .send(
+                        request_builder
+                            .header("x-api-key", &client.api_key)
+                            .header("x-api-secret-key", &client.api_secret),
+                    )
                     .await?;
 
                 let (status, body) = client.handle_response_with_status(response).await?;
@@ -0,0 +1,41 @@
+//! Time source abstraction
+//!
+//! Busy retries, task polling, the concurrency limiter's wait metrics, and the
+//! response cache's TTL all read time through a [`Clock`]. The default
+//! [`SystemClock`] uses the real monotonic clock and the runtime's timer.
+//! Tests can install a controllable clock with
+//! [`CloudClientBuilder::clock`] (see `testing::TestClock` with the
+//! `test-support` feature) to exercise timeout paths without real delays.
+//!
+//! [`CloudClientBuilder::clock`]: crate::CloudClientBuilder::clock
+
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+/// Future returned by [`Clock::sleep`]
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Source of the current time and of timers
+pub trait Clock: Debug + Send + Sync {
+    /// The current instant
+    fn now(&self) -> Instant;
+
+    /// A future that completes once `duration` has passed on this clock
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// The real clock, backed by [`Instant::now`] and the runtime's timer
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(crate::runtime::sleep(duration))
+    }
+}
@@ -29,24 +29,70 @@ pub use private_link::PrivateLinkHandler;
 
 // Re-export PrivateLink types
 pub use private_link::{
-    PrincipalType, PrivateLinkAddPrincipalRequest, PrivateLinkCreateRequest,
-    PrivateLinkRemovePrincipalRequest,
+    PrincipalType, PrivateLink, PrivateLinkAddPrincipalRequest, PrivateLinkConnection,
+    PrivateLinkCreateRequest, PrivateLinkPrincipal, PrivateLinkRemovePrincipalRequest,
+    PrivateLinkShare,
 };
 pub use psc::PscHandler;
 pub use transit_gateway::TransitGatewayHandler;
 pub use vpc_peering::VpcPeeringHandler;
 
 // Re-export types used by handlers
-pub use psc::PscEndpointUpdateRequest;
-pub use transit_gateway::{Cidr, TgwAttachmentRequest, TgwUpdateCidrsRequest};
+pub use psc::{
+    ActiveActivePscEndpointCreateRequest, ActiveActivePscEndpointUpdateRequest, GcpCreationScript,
+    PrivateServiceConnectEndpoints, PscEndpoint, PscEndpointStatus, PscEndpointUpdateRequest,
+    PscService, PscServiceStatus,
+};
+#[cfg(not(feature = "no-deprecated"))]
+pub use psc::{PrivateServiceConnectEndpoint, PrivateServiceConnectService};
+pub use transit_gateway::{
+    Cidr, TgwAttachmentRequest, TgwUpdateCidrsRequest, TransitGatewayInvitation,
+    TransitGatewayInvitations,
+};
+#[cfg(not(feature = "no-deprecated"))]
+pub use vpc_peering::VpcPeeringCreateBaseRequest;
 pub use vpc_peering::{
-    ActiveActiveVpcPeering, ActiveActiveVpcPeeringList, ActiveActiveVpcRegion, VpcCidr, VpcPeering,
-    VpcPeeringCreateBaseRequest, VpcPeeringCreateRequest, VpcPeeringUpdateAwsRequest,
-    VpcPeeringUpdateRequest,
+    ActiveActiveVpcPeering, ActiveActiveVpcPeeringCreateRequest, ActiveActiveVpcPeeringList,
+    ActiveActiveVpcRegion, VpcCidr, VpcPeering, VpcPeeringCreateRequest, VpcPeeringList,
+    VpcPeeringUpdateAwsRequest, VpcPeeringUpdateRequest,
 };
 
 // For backward compatibility, provide a unified handler
 use crate::CloudClient;
+use crate::tasks::TaskWaitOptions;
+use crate::types::{Section, TaskStateUpdate};
+
+/// Connectivity configuration of a subscription, one section per feature
+///
+/// Returned by [`ConnectivityHandler::status`]. Features that are not set up on
+/// the subscription are reported as [`Section::NotConfigured`].
+#[derive(Debug, Clone)]
+pub struct ConnectivityStatus {
+    /// Subscription ID
+    pub subscription_id: i32,
+    /// VPC peerings
+    pub vpc_peering: Section<TaskStateUpdate>,
+    /// Transit Gateway attachments
+    pub transit_gateway: Section<TaskStateUpdate>,
+    /// Private Service Connect service
+    pub psc_service: Section<TaskStateUpdate>,
+    /// Private Service Connect endpoints
+    pub psc_endpoints: Section<TaskStateUpdate>,
+    /// AWS `PrivateLink` configuration
+    pub private_link: Section<serde_json::Value>,
+}
+
+impl ConnectivityStatus {
+    /// Returns true if any section failed with an error other than "not configured"
+    #[must_use]
+    pub fn has_failures(&self) -> bool {
+        self.vpc_peering.error().is_some()
+            || self.transit_gateway.error().is_some()
+            || self.psc_service.error().is_some()
+            || self.psc_endpoints.error().is_some()
+            || self.private_link.error().is_some()
+    }
+}
 
 /// Unified connectivity handler - provides backward compatibility
 ///
@@ -58,6 +104,7 @@ pub struct ConnectivityHandler {
     pub vpc_peering: VpcPeeringHandler,
     pub psc: PscHandler,
     pub transit_gateway: TransitGatewayHandler,
+    pub private_link: PrivateLinkHandler,
 }
 
 impl ConnectivityHandler {
@@ -66,7 +113,45 @@ impl ConnectivityHandler {
         Self {
             vpc_peering: VpcPeeringHandler::new(client.clone()),
             psc: PscHandler::new(client.clone()),
-            transit_gateway: TransitGatewayHandler::new(client),
+            transit_gateway: TransitGatewayHandler::new(client.clone()),
+            private_link: PrivateLinkHandler::new(client),
+        }
+    }
+
+    /// Get the connectivity configuration of a subscription
+    /// Fetches VPC peering, Transit Gateway, PSC, and `PrivateLink` details.
+    /// Features that are not set up (404/412) are reported as
+    /// [`Section::NotConfigured`]; other errors are kept per section so one
+    /// failing call does not hide the others.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::{CloudClient, ConnectivityHandler};
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let status = ConnectivityHandler::new(client).status(123).await;
+    /// if status.psc_service.is_not_configured() {
+    ///     println!("PSC is not set up");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn status(&self, subscription_id: i32) -> ConnectivityStatus {
+        ConnectivityStatus {
+            subscription_id,
+            vpc_peering: Section::from_result(self.vpc_peering.get(subscription_id).await),
+            transit_gateway: Section::from_result(
+                self.transit_gateway.get_attachments(subscription_id).await,
+            ),
+            psc_service: Section::from_result(self.psc.get_service(subscription_id).await),
+            psc_endpoints: Section::from_result(self.psc.get_endpoints(subscription_id).await),
+            private_link: Section::from_result(self.private_link.get(subscription_id).await),
         }
     }
 
@@ -121,6 +206,24 @@ impl ConnectivityHandler {
         self.psc.get_service(subscription_id).await
     }
 
+    /// Get the typed PSC service of a subscription, waiting for its task
+    pub async fn get_psc_service_details(
+        &self,
+        subscription_id: i32,
+        options: &TaskWaitOptions,
+    ) -> crate::Result<PscService> {
+        self.psc.get_service_details(subscription_id, options).await
+    }
+
+    /// List the typed PSC endpoints of a subscription, waiting for their tasks
+    pub async fn list_psc_endpoints(
+        &self,
+        subscription_id: i32,
+        options: &TaskWaitOptions,
+    ) -> crate::Result<Vec<PscEndpoint>> {
+        self.psc.list_endpoints(subscription_id, options).await
+    }
+
     pub async fn create_psc_service(
         &self,
         subscription_id: i32,
@@ -198,6 +301,7 @@ impl ConnectivityHandler {
     }
 
     // Additional backward compatibility methods
+    #[cfg(not(feature = "no-deprecated"))]
     pub async fn update_psc_service_endpoint(
         &self,
         subscription_id: i32,
@@ -209,6 +313,7 @@ impl ConnectivityHandler {
             .await
     }
 
+    #[cfg(not(feature = "no-deprecated"))]
     pub async fn update_tgw_attachment_cidrs(
         &self,
         subscription_id: i32,
@@ -44,6 +44,8 @@
 //! # }
 //! ```
 
+use crate::tasks::{TaskWaitOptions, TasksHandler, Updated};
+use crate::types::TaskStateUpdate;
 use crate::{CloudClient, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -54,10 +56,11 @@ use serde_json::Value;
 // ============================================================================
 
 /// Principal type for `PrivateLink` access control
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PrincipalType {
     /// AWS account ID
+    #[default]
     AwsAccount,
     /// AWS Organization
     Organization,
@@ -69,10 +72,13 @@ pub enum PrincipalType {
     IamUser,
     /// Service Principal
     ServicePrincipal,
+    /// A principal type this crate does not know yet
+    #[serde(other)]
+    Unknown,
 }
 
 /// Request to create a `PrivateLink` configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLinkCreateRequest {
     /// Share name for the `PrivateLink` service (max 64 characters)
@@ -91,7 +97,7 @@ pub struct PrivateLinkCreateRequest {
 }
 
 /// Request to add a principal to `PrivateLink` access list
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLinkAddPrincipalRequest {
     /// AWS principal (account ID, role ARN, etc.)
@@ -106,8 +112,26 @@ pub struct PrivateLinkAddPrincipalRequest {
     pub alias: Option<String>,
 }
 
+impl PrivateLinkAddPrincipalRequest {
+    /// Request granting `principal` of the given type access
+    pub fn new(principal: impl Into<String>, principal_type: PrincipalType) -> Self {
+        Self {
+            principal: principal.into(),
+            principal_type: Some(principal_type),
+            alias: None,
+        }
+    }
+
+    /// Set the alias shown for the principal
+    #[must_use]
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+}
+
 /// Request to remove a principal from `PrivateLink` access list
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLinkRemovePrincipalRequest {
     /// AWS principal to remove
@@ -122,8 +146,29 @@ pub struct PrivateLinkRemovePrincipalRequest {
     pub alias: Option<String>,
 }
 
+impl PrivateLinkRemovePrincipalRequest {
+    /// Request revoking access of `principal` of the given type
+    pub fn new(principal: impl Into<String>, principal_type: PrincipalType) -> Self {
+        Self {
+            principal: principal.into(),
+            principal_type: Some(principal_type),
+            alias: None,
+        }
+    }
+}
+
+impl From<&PrivateLinkPrincipal> for PrivateLinkRemovePrincipalRequest {
+    fn from(principal: &PrivateLinkPrincipal) -> Self {
+        Self {
+            principal: principal.principal.clone().unwrap_or_default(),
+            principal_type: principal.principal_type.clone(),
+            alias: principal.alias.clone(),
+        }
+    }
+}
+
 /// `PrivateLink` configuration response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLink {
     /// `PrivateLink` status
@@ -171,8 +216,47 @@ pub struct PrivateLink {
     pub error_message: Option<String>,
 }
 
+impl PrivateLink {
+    /// The RAM share and resource configuration of this `PrivateLink`
+    #[must_use]
+    pub fn share(&self) -> PrivateLinkShare {
+        PrivateLinkShare {
+            share_name: self.share_name.clone(),
+            share_arn: self.share_arn.clone(),
+            resource_configuration_id: self.resource_configuration_id.clone(),
+            resource_configuration_arn: self.resource_configuration_arn.clone(),
+            status: self.status.clone(),
+        }
+    }
+}
+
+/// AWS RAM share through which a `PrivateLink` is offered to principals
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivateLinkShare {
+    /// Share name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_name: Option<String>,
+
+    /// RAM share ARN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_arn: Option<String>,
+
+    /// AWS Resource Configuration ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_configuration_id: Option<String>,
+
+    /// AWS Resource Configuration ARN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_configuration_arn: Option<String>,
+
+    /// `PrivateLink` status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
 /// `PrivateLink` principal information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLinkPrincipal {
     /// AWS principal (account ID, role ARN, etc.)
@@ -181,7 +265,7 @@ pub struct PrivateLinkPrincipal {
 
     /// Type of principal
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
-    pub principal_type: Option<String>,
+    pub principal_type: Option<PrincipalType>,
 
     /// Alias for the principal
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -193,7 +277,7 @@ pub struct PrivateLinkPrincipal {
 }
 
 /// `PrivateLink` connection information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLinkConnection {
     /// Association ID
@@ -218,7 +302,7 @@ pub struct PrivateLinkConnection {
 }
 
 /// Database accessible via `PrivateLink`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLinkDatabase {
     /// Database ID
@@ -235,7 +319,7 @@ pub struct PrivateLinkDatabase {
 }
 
 /// `PrivateLink` endpoint script response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateLinkEndpointScript {
     /// AWS CLI/CloudFormation script
@@ -280,6 +364,49 @@ impl PrivateLinkHandler {
             .await
     }
 
+    /// Get typed `PrivateLink` configuration
+    ///
+    /// Like [`get`](Self::get), but decodes the configuration.
+    ///
+    /// GET /subscriptions/{subscriptionId}/private-link
+    pub async fn get_config(&self, subscription_id: i32) -> Result<PrivateLink> {
+        self.client
+            .get(&format!("/subscriptions/{subscription_id}/private-link"))
+            .await
+    }
+
+    /// Get the RAM share of a `PrivateLink`
+    ///
+    /// GET /subscriptions/{subscriptionId}/private-link
+    pub async fn get_share(&self, subscription_id: i32) -> Result<PrivateLinkShare> {
+        Ok(self.get_config(subscription_id).await?.share())
+    }
+
+    /// Get the principals allowed to use a `PrivateLink`
+    ///
+    /// GET /subscriptions/{subscriptionId}/private-link
+    pub async fn get_principals(&self, subscription_id: i32) -> Result<Vec<PrivateLinkPrincipal>> {
+        Ok(self
+            .get_config(subscription_id)
+            .await?
+            .principals
+            .unwrap_or_default())
+    }
+
+    /// Get the VPC endpoint connections of a `PrivateLink`
+    ///
+    /// GET /subscriptions/{subscriptionId}/private-link
+    pub async fn get_connections(
+        &self,
+        subscription_id: i32,
+    ) -> Result<Vec<PrivateLinkConnection>> {
+        Ok(self
+            .get_config(subscription_id)
+            .await?
+            .connections
+            .unwrap_or_default())
+    }
+
     /// Create a `PrivateLink`
     ///
     /// Creates a new AWS `PrivateLink` configuration for a subscription.
@@ -361,6 +488,96 @@ impl PrivateLinkHandler {
             .await
     }
 
+    /// Add a principal to `PrivateLink`
+    ///
+    /// Like [`add_principals`](Self::add_principals), but decodes the
+    /// updated configuration.
+    ///
+    /// POST /subscriptions/{subscriptionId}/private-link/principals
+    pub async fn add_principal(
+        &self,
+        subscription_id: i32,
+        request: &PrivateLinkAddPrincipalRequest,
+    ) -> Result<PrivateLink> {
+        self.client
+            .post(
+                &format!("/subscriptions/{subscription_id}/private-link/principals"),
+                request,
+            )
+            .await
+    }
+
+    /// Remove a principal from `PrivateLink`
+    ///
+    /// Like [`remove_principals`](Self::remove_principals), but decodes the
+    /// updated configuration.
+    ///
+    /// DELETE /subscriptions/{subscriptionId}/private-link/principals
+    pub async fn remove_principal(
+        &self,
+        subscription_id: i32,
+        request: &PrivateLinkRemovePrincipalRequest,
+    ) -> Result<PrivateLink> {
+        self.client
+            .delete_with_body(
+                &format!("/subscriptions/{subscription_id}/private-link/principals"),
+                serde_json::to_value(request)?,
+            )
+            .await
+    }
+
+    /// Add a principal to `PrivateLink` and wait for the result
+    ///
+    /// Waits for the task to finish and re-fetches the principals, so the
+    /// caller sees the new principal (and its status) in one call.
+    ///
+    /// POST /subscriptions/{subscriptionId}/private-link/principals
+    pub async fn add_principal_and_fetch(
+        &self,
+        subscription_id: i32,
+        request: &PrivateLinkAddPrincipalRequest,
+        options: &TaskWaitOptions,
+    ) -> Result<Updated<Vec<PrivateLinkPrincipal>>> {
+        let task: TaskStateUpdate = self
+            .client
+            .post(
+                &format!("/subscriptions/{subscription_id}/private-link/principals"),
+                request,
+            )
+            .await?;
+        TasksHandler::new(self.client.clone())
+            .wait_and_fetch(task.task_id, options, || {
+                self.get_principals(subscription_id)
+            })
+            .await
+    }
+
+    /// Remove a principal from `PrivateLink` and wait for the result
+    ///
+    /// Sends the principal in the DELETE body, waits for the task to finish,
+    /// and re-fetches the remaining principals.
+    ///
+    /// DELETE /subscriptions/{subscriptionId}/private-link/principals
+    pub async fn remove_principal_and_fetch(
+        &self,
+        subscription_id: i32,
+        request: &PrivateLinkRemovePrincipalRequest,
+        options: &TaskWaitOptions,
+    ) -> Result<Updated<Vec<PrivateLinkPrincipal>>> {
+        let task: TaskStateUpdate = self
+            .client
+            .delete_with_body(
+                &format!("/subscriptions/{subscription_id}/private-link/principals"),
+                serde_json::to_value(request)?,
+            )
+            .await?;
+        TasksHandler::new(self.client.clone())
+            .wait_and_fetch(task.task_id, options, || {
+                self.get_principals(subscription_id)
+            })
+            .await
+    }
+
     /// Get endpoint creation script
     ///
     /// Gets a script to create the VPC endpoint in your AWS account.
@@ -3,11 +3,12 @@
 //! Manages Google Cloud Private Service Connect endpoints for secure connectivity
 //! to Redis Cloud databases without traversing the public internet.
 
-use crate::{CloudClient, Result};
+use crate::tasks::{TaskWaitOptions, TasksHandler};
+use crate::{CloudClient, CloudError, Result};
 use serde::{Deserialize, Serialize};
 
 /// Private Service Connect endpoint update request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PscEndpointUpdateRequest {
     pub subscription_id: i32,
@@ -31,13 +32,118 @@ pub struct PscEndpointUpdateRequest {
     pub endpoint_connection_name: Option<String>,
 }
 
+/// Active-Active Private Service Connect endpoint creation request
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveActivePscEndpointCreateRequest {
+    /// Google Cloud project ID
+    pub gcp_project_id: String,
+
+    /// Name of the Google Cloud VPC that hosts your application
+    pub gcp_vpc_name: String,
+
+    /// Name of your VPC's subnet of IP address ranges
+    pub gcp_vpc_subnet_name: String,
+
+    /// Prefix used to create PSC endpoints in the consumer application VPC
+    pub endpoint_connection_name: String,
+}
+
+/// Active-Active Private Service Connect endpoint update request
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveActivePscEndpointUpdateRequest {
+    /// Google Cloud project ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gcp_project_id: Option<String>,
+
+    /// Name of the Google Cloud VPC that hosts your application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gcp_vpc_name: Option<String>,
+
+    /// Name of your VPC's subnet of IP address ranges
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gcp_vpc_subnet_name: Option<String>,
+
+    /// Prefix used to create PSC endpoints in the consumer application VPC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_connection_name: Option<String>,
+
+    /// `accept` or `reject` a pending endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+}
+
 /// Task state update response
 pub use crate::types::TaskStateUpdate;
 
+/// Private Service Connect service status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PscServiceStatus {
+    CreatePending,
+    Active,
+    DeletePending,
+    Deleted,
+    Failed,
+    #[serde(other)]
+    Other,
+}
+
+impl PscServiceStatus {
+    /// Returns true if the service is being created or removed
+    #[must_use]
+    pub fn is_transitioning(&self) -> bool {
+        matches!(self, Self::CreatePending | Self::DeletePending)
+    }
+
+    /// Returns true if endpoints can be created against the service
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Active)
+    }
+}
+
+/// Connection status of a Private Service Connect endpoint
+///
+/// An endpoint is `Initialized` until the GCP-side forwarding rules are
+/// created, then `Pending` until the connection is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PscEndpointStatus {
+    Initialized,
+    Pending,
+    CreatePending,
+    Active,
+    Rejected,
+    DeletePending,
+    Deleted,
+    Failed,
+    #[serde(other)]
+    Other,
+}
+
+impl PscEndpointStatus {
+    /// Returns true if the endpoint is waiting on GCP resources, acceptance, or removal
+    #[must_use]
+    pub fn is_transitioning(&self) -> bool {
+        matches!(
+            self,
+            Self::Initialized | Self::Pending | Self::CreatePending | Self::DeletePending
+        )
+    }
+
+    /// Returns true if traffic can flow through the endpoint
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        matches!(self, Self::Active)
+    }
+}
+
 /// Private Service Connect service information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PrivateServiceConnectService {
+pub struct PscService {
     /// PSC service ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i32>,
@@ -52,13 +158,13 @@ pub struct PrivateServiceConnectService {
 
     /// PSC service status
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PscServiceStatus>,
 }
 
 /// Private Service Connect endpoint information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PrivateServiceConnectEndpoint {
+pub struct PscEndpoint {
     /// Endpoint ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i32>,
@@ -79,13 +185,37 @@ pub struct PrivateServiceConnectEndpoint {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub endpoint_connection_name: Option<String>,
 
-    /// Endpoint status
+    /// Endpoint connection status
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PscEndpointStatus>,
+
+    /// GCP resources backing the endpoint, one per service attachment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_attachments: Option<Vec<TerraformGcpServiceAttachment>>,
+}
+
+impl PscEndpoint {
+    /// Names of the GCP forwarding rules created for this endpoint
+    #[must_use]
+    pub fn forwarding_rules(&self) -> Vec<&str> {
+        self.service_attachments
+            .iter()
+            .flatten()
+            .filter_map(|attachment| attachment.forwarding_rule_name.as_deref())
+            .collect()
+    }
 }
 
+/// Former name of [`PscService`]
+#[cfg(not(feature = "no-deprecated"))]
+pub type PrivateServiceConnectService = PscService;
+
+/// Former name of [`PscEndpoint`]
+#[cfg(not(feature = "no-deprecated"))]
+pub type PrivateServiceConnectEndpoint = PscEndpoint;
+
 /// Private Service Connect endpoints response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateServiceConnectEndpoints {
     /// PSC service ID
@@ -94,11 +224,28 @@ pub struct PrivateServiceConnectEndpoints {
 
     /// List of PSC endpoints
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoints: Option<Vec<PrivateServiceConnectEndpoint>>,
+    pub endpoints: Option<Vec<PscEndpoint>>,
+}
+
+impl PrivateServiceConnectEndpoints {
+    /// GCP projects with an accepted (active) endpoint, without duplicates
+    #[must_use]
+    pub fn connected_projects(&self) -> Vec<&str> {
+        let mut projects: Vec<&str> = self
+            .endpoints
+            .iter()
+            .flatten()
+            .filter(|endpoint| endpoint.status.is_some_and(|s| s.is_connected()))
+            .filter_map(|endpoint| endpoint.gcp_project_id.as_deref())
+            .collect();
+        projects.sort_unstable();
+        projects.dedup();
+        projects
+    }
 }
 
 /// GCP creation script for PSC endpoint
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GcpCreationScript {
     /// Bash script for endpoint creation
@@ -115,7 +262,7 @@ pub struct GcpCreationScript {
 }
 
 /// Terraform GCP configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TerraformGcp {
     /// Service attachment configurations
@@ -124,7 +271,7 @@ pub struct TerraformGcp {
 }
 
 /// Terraform GCP service attachment configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TerraformGcpServiceAttachment {
     /// Service attachment name
@@ -145,7 +292,7 @@ pub struct TerraformGcpServiceAttachment {
 }
 
 /// GCP deletion script for PSC endpoint
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GcpDeletionScript {
     /// Bash script for endpoint deletion
@@ -211,6 +358,59 @@ impl PscHandler {
             .await
     }
 
+    /// Get Private Service Connect endpoints of a PSC service
+    ///
+    /// GET /subscriptions/{subscriptionId}/private-service-connect/{pscServiceId}
+    pub async fn get_service_endpoints(
+        &self,
+        subscription_id: i32,
+        psc_service_id: i32,
+    ) -> Result<TaskStateUpdate> {
+        self.client
+            .get(&format!(
+                "/subscriptions/{subscription_id}/private-service-connect/{psc_service_id}"
+            ))
+            .await
+    }
+
+    /// Get the Private Service Connect service of a subscription
+    ///
+    /// Resolves the task returned by [`get_service`](Self::get_service).
+    pub async fn get_service_details(
+        &self,
+        subscription_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<PscService> {
+        let task = self.get_service(subscription_id).await?;
+        TasksHandler::new(self.client.clone())
+            .wait_for_resource(task.task_id, options)
+            .await
+    }
+
+    /// List the Private Service Connect endpoints of a subscription
+    ///
+    /// Looks up the subscription's PSC service, then resolves the endpoints
+    /// task for it. Each entry carries the GCP project, VPC, connection status,
+    /// and the forwarding rules backing the endpoint.
+    pub async fn list_endpoints(
+        &self,
+        subscription_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<Vec<PscEndpoint>> {
+        let service = self.get_service_details(subscription_id, options).await?;
+        let psc_service_id = service.id.ok_or_else(|| CloudError::NotFound {
+            message: format!("Subscription {subscription_id} has no PSC service"),
+        })?;
+
+        let task = self
+            .get_service_endpoints(subscription_id, psc_service_id)
+            .await?;
+        let endpoints: PrivateServiceConnectEndpoints = TasksHandler::new(self.client.clone())
+            .wait_for_resource(task.task_id, options)
+            .await?;
+        Ok(endpoints.endpoints.unwrap_or_default())
+    }
+
     /// Create Private Service Connect endpoint
     pub async fn create_endpoint(
         &self,
@@ -271,6 +471,30 @@ impl PscHandler {
             .await
     }
 
+    /// Get the gcloud, `PowerShell`, and Terraform scripts that create a PSC endpoint
+    ///
+    /// Resolves the script task and returns the same scripts the console
+    /// offers for the endpoint.
+    ///
+    /// GET /subscriptions/{subscriptionId}/private-service-connect/{pscServiceId}/endpoints/{endpointId}/creationScripts
+    pub async fn get_endpoint_creation_scripts(
+        &self,
+        subscription_id: i32,
+        psc_service_id: i32,
+        endpoint_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<GcpCreationScript> {
+        let task: TaskStateUpdate = self
+            .client
+            .get(&format!(
+                "/subscriptions/{subscription_id}/private-service-connect/{psc_service_id}/endpoints/{endpoint_id}/creationScripts"
+            ))
+            .await?;
+        TasksHandler::new(self.client.clone())
+            .wait_for_resource(task.task_id, options)
+            .await
+    }
+
     /// Get PSC endpoint deletion script
     pub async fn get_endpoint_deletion_script(
         &self,
@@ -288,63 +512,85 @@ impl PscHandler {
     // Active-Active PSC Operations
     // ========================================================================
 
-    /// Delete Active-Active PSC service
+    /// Delete Active-Active PSC service for a region
+    ///
+    /// DELETE /subscriptions/{subscriptionId}/regions/{regionId}/private-service-connect
     pub async fn delete_service_active_active(
         &self,
         subscription_id: i32,
+        region_id: i32,
     ) -> Result<serde_json::Value> {
         self.client
             .delete(&format!(
-                "/subscriptions/{subscription_id}/regions/private-service-connect"
+                "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect"
             ))
             .await?;
         Ok(serde_json::Value::Null)
     }
 
-    /// Get Active-Active PSC service
-    pub async fn get_service_active_active(&self, subscription_id: i32) -> Result<TaskStateUpdate> {
+    /// Get Active-Active PSC service for a region
+    ///
+    /// GET /subscriptions/{subscriptionId}/regions/{regionId}/private-service-connect
+    pub async fn get_service_active_active(
+        &self,
+        subscription_id: i32,
+        region_id: i32,
+    ) -> Result<TaskStateUpdate> {
         self.client
             .get(&format!(
-                "/subscriptions/{subscription_id}/regions/private-service-connect"
+                "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect"
             ))
             .await
     }
 
-    /// Create Active-Active PSC service
+    /// Create Active-Active PSC service for a region
+    ///
+    /// POST /subscriptions/{subscriptionId}/regions/{regionId}/private-service-connect
     pub async fn create_service_active_active(
         &self,
         subscription_id: i32,
+        region_id: i32,
     ) -> Result<TaskStateUpdate> {
         self.client
             .post(
-                &format!("/subscriptions/{subscription_id}/regions/private-service-connect"),
+                &format!(
+                    "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect"
+                ),
                 &serde_json::json!({}),
             )
             .await
     }
 
-    /// Get Active-Active PSC endpoints
+    /// Get Active-Active PSC endpoints of a region's PSC service
+    ///
+    /// GET /subscriptions/{subscriptionId}/regions/{regionId}/private-service-connect/{pscServiceId}
     pub async fn get_endpoints_active_active(
         &self,
         subscription_id: i32,
+        region_id: i32,
+        psc_service_id: i32,
     ) -> Result<TaskStateUpdate> {
         self.client
             .get(&format!(
-                "/subscriptions/{subscription_id}/regions/private-service-connect/endpoints"
+                "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect/{psc_service_id}"
             ))
             .await
     }
 
     /// Create Active-Active PSC endpoint
+    ///
+    /// POST /subscriptions/{subscriptionId}/regions/{regionId}/private-service-connect/{pscServiceId}
     pub async fn create_endpoint_active_active(
         &self,
         subscription_id: i32,
-        request: &PscEndpointUpdateRequest,
+        region_id: i32,
+        psc_service_id: i32,
+        request: &ActiveActivePscEndpointCreateRequest,
     ) -> Result<TaskStateUpdate> {
         self.client
             .post(
                 &format!(
-                    "/subscriptions/{subscription_id}/regions/private-service-connect/endpoints"
+                    "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect/{psc_service_id}"
                 ),
                 request,
             )
@@ -352,32 +598,38 @@ impl PscHandler {
     }
 
     /// Delete Active-Active PSC endpoint
+    ///
+    /// DELETE /subscriptions/{subscriptionId}/regions/{regionId}/private-service-connect/{pscServiceId}/endpoints/{endpointId}
     pub async fn delete_endpoint_active_active(
         &self,
         subscription_id: i32,
         region_id: i32,
+        psc_service_id: i32,
         endpoint_id: i32,
     ) -> Result<serde_json::Value> {
         self.client
             .delete(&format!(
-                "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect/endpoints/{endpoint_id}"
+                "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect/{psc_service_id}/endpoints/{endpoint_id}"
             ))
             .await?;
         Ok(serde_json::Value::Null)
     }
 
     /// Update Active-Active PSC endpoint
+    ///
+    /// PUT /subscriptions/{subscriptionId}/regions/{regionId}/private-service-connect/{pscServiceId}/endpoints/{endpointId}
     pub async fn update_endpoint_active_active(
         &self,
         subscription_id: i32,
         region_id: i32,
+        psc_service_id: i32,
         endpoint_id: i32,
-        request: &PscEndpointUpdateRequest,
+        request: &ActiveActivePscEndpointUpdateRequest,
     ) -> Result<TaskStateUpdate> {
         self.client
             .put(
                 &format!(
-                    "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect/{subscription_id}/endpoints/{endpoint_id}"
+                    "/subscriptions/{subscription_id}/regions/{region_id}/private-service-connect/{psc_service_id}/endpoints/{endpoint_id}"
                 ),
                 request,
             )
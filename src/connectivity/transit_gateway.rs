@@ -3,11 +3,13 @@
 //! Manages AWS Transit Gateway attachments for hub-and-spoke network topologies,
 //! enabling centralized connectivity management for Redis Cloud subscriptions.
 
+use crate::query;
+use crate::tasks::{TaskWaitOptions, TasksHandler};
 use crate::{CloudClient, Result};
 use serde::{Deserialize, Serialize};
 
 /// CIDR block definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Cidr {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -15,7 +17,7 @@ pub struct Cidr {
 }
 
 /// Transit Gateway CIDRs update request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TgwUpdateCidrsRequest {
     /// Optional. List of transit gateway attachment CIDRs.
@@ -27,7 +29,7 @@ pub struct TgwUpdateCidrsRequest {
 }
 
 /// Transit Gateway attachment request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TgwAttachmentRequest {
     /// AWS account ID
@@ -47,7 +49,7 @@ pub struct TgwAttachmentRequest {
 pub use crate::types::TaskStateUpdate;
 
 /// Transit Gateway attachment information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransitGatewayAttachment {
     /// Attachment ID
@@ -80,7 +82,7 @@ pub struct TransitGatewayAttachment {
 }
 
 /// CIDR block with status
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CidrStatus {
     /// CIDR address
@@ -93,7 +95,7 @@ pub struct CidrStatus {
 }
 
 /// Transit Gateway resource share invitation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransitGatewayInvitation {
     /// Invitation ID
@@ -121,6 +123,25 @@ pub struct TransitGatewayInvitation {
     pub shared_date: Option<String>,
 }
 
+impl TransitGatewayInvitation {
+    /// Returns true if the invitation is still waiting to be accepted or rejected
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.status
+            .as_deref()
+            .is_some_and(|status| status.eq_ignore_ascii_case("pending"))
+    }
+}
+
+/// Transit Gateway invitations reported by a completed invitations task
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitGatewayInvitations {
+    /// Resource share invitations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitations: Option<Vec<TransitGatewayInvitation>>,
+}
+
 /// Transit Gateway handler
 pub struct TransitGatewayHandler {
     client: CloudClient,
@@ -144,25 +165,67 @@ impl TransitGatewayHandler {
             .await
     }
 
-    /// Get Transit Gateway shared invitations
+    /// Get Transit Gateway resource share invitations
+    ///
+    /// GET /subscriptions/{subscriptionId}/transitGateways/invitations
     pub async fn get_shared_invitations(&self, subscription_id: i32) -> Result<TaskStateUpdate> {
         self.client
             .get(&format!(
-                "/subscriptions/{subscription_id}/tgw/shared-invitations"
+                "/subscriptions/{subscription_id}/transitGateways/invitations"
             ))
             .await
     }
 
+    /// List Transit Gateway resource share invitations
+    ///
+    /// Resolves the task returned by
+    /// [`get_shared_invitations`](Self::get_shared_invitations) and returns the
+    /// invitations it reports.
+    pub async fn get_invitations(
+        &self,
+        subscription_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<Vec<TransitGatewayInvitation>> {
+        let task = self.get_shared_invitations(subscription_id).await?;
+        self.resolve_invitations(task, options).await
+    }
+
+    /// Accept a Transit Gateway resource share invitation
+    ///
+    /// PUT /subscriptions/{subscriptionId}/transitGateways/invitations/{tgwInvitationId}/accept
+    pub async fn accept_invitation(
+        &self,
+        subscription_id: i32,
+        invitation_id: i32,
+    ) -> Result<TaskStateUpdate> {
+        self.accept_resource_share(subscription_id, invitation_id.to_string())
+            .await
+    }
+
+    /// Reject a Transit Gateway resource share invitation
+    ///
+    /// PUT /subscriptions/{subscriptionId}/transitGateways/invitations/{tgwInvitationId}/reject
+    pub async fn reject_invitation(
+        &self,
+        subscription_id: i32,
+        invitation_id: i32,
+    ) -> Result<TaskStateUpdate> {
+        self.reject_resource_share(subscription_id, invitation_id.to_string())
+            .await
+    }
+
     /// Accept Transit Gateway resource share
+    ///
+    /// PUT /subscriptions/{subscriptionId}/transitGateways/invitations/{tgwInvitationId}/accept
     pub async fn accept_resource_share(
         &self,
         subscription_id: i32,
         invitation_id: String,
     ) -> Result<TaskStateUpdate> {
         self.client
-            .post(
+            .put(
                 &format!(
-                    "/subscriptions/{subscription_id}/tgw/shared-invitations/{invitation_id}/accept"
+                    "/subscriptions/{subscription_id}/transitGateways/invitations/{invitation_id}/accept"
                 ),
                 &serde_json::json!({}),
             )
@@ -170,15 +233,17 @@ impl TransitGatewayHandler {
     }
 
     /// Reject Transit Gateway resource share
+    ///
+    /// PUT /subscriptions/{subscriptionId}/transitGateways/invitations/{tgwInvitationId}/reject
     pub async fn reject_resource_share(
         &self,
         subscription_id: i32,
         invitation_id: String,
     ) -> Result<TaskStateUpdate> {
         self.client
-            .post(
+            .put(
                 &format!(
-                    "/subscriptions/{subscription_id}/tgw/shared-invitations/{invitation_id}/reject"
+                    "/subscriptions/{subscription_id}/transitGateways/invitations/{invitation_id}/reject"
                 ),
                 &serde_json::json!({}),
             )
@@ -191,6 +256,7 @@ impl TransitGatewayHandler {
         subscription_id: i32,
         attachment_id: String,
     ) -> Result<serde_json::Value> {
+        let attachment_id = query::segment(&attachment_id);
         self.client
             .delete(&format!(
                 "/subscriptions/{subscription_id}/transitGateways/{attachment_id}/attachment"
@@ -211,6 +277,7 @@ impl TransitGatewayHandler {
             cidrs: None,
         };
 
+        let tgw_id = query::segment(tgw_id);
         self.client
             .post(
                 &format!("/subscriptions/{subscription_id}/transitGateways/{tgw_id}/attachment"),
@@ -240,6 +307,7 @@ impl TransitGatewayHandler {
         attachment_id: String,
         request: &TgwAttachmentRequest,
     ) -> Result<TaskStateUpdate> {
+        let attachment_id = query::segment(&attachment_id);
         self.client
             .put(
                 &format!(
@@ -254,31 +322,55 @@ impl TransitGatewayHandler {
     // Active-Active Transit Gateway Operations
     // ========================================================================
 
-    /// Get Active-Active Transit Gateway attachments
+    /// Get Active-Active Transit Gateway attachments for a region
+    ///
+    /// GET /subscriptions/{subscriptionId}/regions/{regionId}/transitGateways
     pub async fn get_attachments_active_active(
         &self,
         subscription_id: i32,
+        region_id: i32,
     ) -> Result<TaskStateUpdate> {
         self.client
             .get(&format!(
-                "/subscriptions/{subscription_id}/regions/transitGateways"
+                "/subscriptions/{subscription_id}/regions/{region_id}/transitGateways"
             ))
             .await
     }
 
-    /// Get Active-Active Transit Gateway shared invitations
+    /// Get Active-Active Transit Gateway resource share invitations for a region
+    ///
+    /// GET /subscriptions/{subscriptionId}/regions/{regionId}/transitGateways/invitations
     pub async fn get_shared_invitations_active_active(
         &self,
         subscription_id: i32,
+        region_id: i32,
     ) -> Result<TaskStateUpdate> {
         self.client
             .get(&format!(
-                "/subscriptions/{subscription_id}/regions/tgw/shared-invitations"
+                "/subscriptions/{subscription_id}/regions/{region_id}/transitGateways/invitations"
             ))
             .await
     }
 
+    /// List Active-Active Transit Gateway resource share invitations for a region
+    ///
+    /// Resolves the task returned by
+    /// [`get_shared_invitations_active_active`](Self::get_shared_invitations_active_active).
+    pub async fn get_invitations_active_active(
+        &self,
+        subscription_id: i32,
+        region_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<Vec<TransitGatewayInvitation>> {
+        let task = self
+            .get_shared_invitations_active_active(subscription_id, region_id)
+            .await?;
+        self.resolve_invitations(task, options).await
+    }
+
     /// Accept Active-Active Transit Gateway resource share
+    ///
+    /// PUT /subscriptions/{subscriptionId}/regions/{regionId}/transitGateways/invitations/{tgwInvitationId}/accept
     pub async fn accept_resource_share_active_active(
         &self,
         subscription_id: i32,
@@ -286,9 +378,9 @@ impl TransitGatewayHandler {
         invitation_id: String,
     ) -> Result<TaskStateUpdate> {
         self.client
-            .post(
+            .put(
                 &format!(
-                    "/subscriptions/{subscription_id}/regions/{region_id}/tgw/shared-invitations/{invitation_id}/accept"
+                    "/subscriptions/{subscription_id}/regions/{region_id}/transitGateways/invitations/{invitation_id}/accept"
                 ),
                 &serde_json::json!({}),
             )
@@ -296,6 +388,8 @@ impl TransitGatewayHandler {
     }
 
     /// Reject Active-Active Transit Gateway resource share
+    ///
+    /// PUT /subscriptions/{subscriptionId}/regions/{regionId}/transitGateways/invitations/{tgwInvitationId}/reject
     pub async fn reject_resource_share_active_active(
         &self,
         subscription_id: i32,
@@ -303,9 +397,9 @@ impl TransitGatewayHandler {
         invitation_id: String,
     ) -> Result<TaskStateUpdate> {
         self.client
-            .post(
+            .put(
                 &format!(
-                    "/subscriptions/{subscription_id}/regions/{region_id}/tgw/shared-invitations/{invitation_id}/reject"
+                    "/subscriptions/{subscription_id}/regions/{region_id}/transitGateways/invitations/{invitation_id}/reject"
                 ),
                 &serde_json::json!({}),
             )
@@ -313,50 +407,75 @@ impl TransitGatewayHandler {
     }
 
     /// Delete Active-Active Transit Gateway attachment
+    ///
+    /// DELETE /subscriptions/{subscriptionId}/regions/{regionId}/transitGateways/{TgwId}/attachment
     pub async fn delete_attachment_active_active(
         &self,
         subscription_id: i32,
         region_id: i32,
-        attachment_id: String,
+        tgw_id: &str,
     ) -> Result<serde_json::Value> {
+        let tgw_id = query::segment(tgw_id);
         self.client
             .delete(&format!(
-                "/subscriptions/{subscription_id}/regions/{region_id}/tgw/attachments/{attachment_id}"
+                "/subscriptions/{subscription_id}/regions/{region_id}/transitGateways/{tgw_id}/attachment"
             ))
             .await?;
         Ok(serde_json::Value::Null)
     }
 
     /// Create Active-Active Transit Gateway attachment
+    ///
+    /// Attaches a region of the subscription to a Transit Gateway whose
+    /// resource share has already been accepted.
+    ///
+    /// POST /subscriptions/{subscriptionId}/regions/{regionId}/transitGateways/{TgwId}/attachment
     pub async fn create_attachment_active_active(
         &self,
         subscription_id: i32,
         region_id: i32,
-        request: &TgwAttachmentRequest,
+        tgw_id: &str,
     ) -> Result<TaskStateUpdate> {
+        let tgw_id = query::segment(tgw_id);
         self.client
             .post(
-                &format!("/subscriptions/{subscription_id}/regions/{region_id}/tgw/attachments"),
-                request,
+                &format!(
+                    "/subscriptions/{subscription_id}/regions/{region_id}/transitGateways/{tgw_id}/attachment"
+                ),
+                &serde_json::json!({}),
             )
             .await
     }
 
     /// Update Active-Active Transit Gateway attachment CIDRs
+    ///
+    /// PUT /subscriptions/{subscriptionId}/regions/{regionId}/transitGateways/{TgwId}/attachment
     pub async fn update_attachment_cidrs_active_active(
         &self,
         subscription_id: i32,
         region_id: i32,
-        attachment_id: String,
-        request: &TgwAttachmentRequest,
+        tgw_id: &str,
+        request: &TgwUpdateCidrsRequest,
     ) -> Result<TaskStateUpdate> {
+        let tgw_id = query::segment(tgw_id);
         self.client
             .put(
                 &format!(
-                    "/subscriptions/{subscription_id}/regions/{region_id}/tgw/attachments/{attachment_id}/cidrs"
+                    "/subscriptions/{subscription_id}/regions/{region_id}/transitGateways/{tgw_id}/attachment"
                 ),
                 request,
             )
             .await
     }
+
+    async fn resolve_invitations(
+        &self,
+        task: TaskStateUpdate,
+        options: &TaskWaitOptions,
+    ) -> Result<Vec<TransitGatewayInvitation>> {
+        let invitations: TransitGatewayInvitations = TasksHandler::new(self.client.clone())
+            .wait_for_resource(task.task_id, options)
+            .await?;
+        Ok(invitations.invitations.unwrap_or_default())
+    }
 }
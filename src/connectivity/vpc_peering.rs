@@ -3,11 +3,12 @@
 //! Manages VPC peering connections between Redis Cloud VPCs and customer VPCs
 //! for both standard and Active-Active subscriptions.
 
+use crate::tasks::{TaskWaitOptions, TasksHandler};
 use crate::{CloudClient, Result};
 use serde::{Deserialize, Serialize};
 
 /// VPC peering creation request
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VpcPeeringCreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,10 +47,55 @@ pub struct VpcPeeringCreateRequest {
 }
 
 /// Base VPC peering creation request (for backward compatibility)
+#[cfg(not(feature = "no-deprecated"))]
 pub type VpcPeeringCreateBaseRequest = VpcPeeringCreateRequest;
 
+/// Active-Active VPC peering creation request
+///
+/// Peers one region of an Active-Active subscription with a customer VPC.
+/// AWS peerings set `destination_region`, `aws_account_id`, and `vpc_id`;
+/// GCP peerings set `vpc_project_uid` and `vpc_network_name`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveActiveVpcPeeringCreateRequest {
+    /// Cloud provider (`AWS` or `GCP`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+
+    /// Name of the subscription region to create the peering from
+    pub source_region: String,
+
+    /// Name of the region to create the peering to (AWS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_region: Option<String>,
+
+    /// AWS account ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_account_id: Option<String>,
+
+    /// AWS VPC ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_id: Option<String>,
+
+    /// VPC CIDR
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_cidr: Option<String>,
+
+    /// List of VPC CIDRs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_cidrs: Option<Vec<String>>,
+
+    /// GCP project ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_project_uid: Option<String>,
+
+    /// GCP network name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_network_name: Option<String>,
+}
+
 /// VPC peering update request for AWS
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VpcPeeringUpdateAwsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,7 +124,7 @@ pub type VpcPeeringUpdateRequest = VpcPeeringUpdateAwsRequest;
 pub use crate::types::TaskStateUpdate;
 
 /// VPC CIDR with status
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VpcCidr {
     /// VPC CIDR block
@@ -91,7 +137,7 @@ pub struct VpcCidr {
 }
 
 /// VPC Peering information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VpcPeering {
     /// VPC Peering ID
@@ -151,8 +197,19 @@ pub struct VpcPeering {
     pub provider: Option<String>,
 }
 
+/// VPC peerings of a subscription
+///
+/// The `resource` of the task returned by `GET /subscriptions/{id}/peerings`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VpcPeeringList {
+    /// VPC peerings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peerings: Option<Vec<VpcPeering>>,
+}
+
 /// Active-Active VPC Peering information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveActiveVpcPeering {
     /// VPC Peering ID
@@ -221,7 +278,7 @@ pub struct ActiveActiveVpcPeering {
 }
 
 /// Active-Active VPC Peering region
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveActiveVpcRegion {
     /// Region ID
@@ -238,7 +295,7 @@ pub struct ActiveActiveVpcRegion {
 }
 
 /// Active-Active VPC Peering list response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveActiveVpcPeeringList {
     /// Subscription ID
@@ -273,6 +330,22 @@ impl VpcPeeringHandler {
             .await
     }
 
+    /// List VPC peerings for subscription
+    ///
+    /// Resolves the task returned by [`get`](Self::get) and returns the
+    /// peerings it reports.
+    pub async fn list(
+        &self,
+        subscription_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<Vec<VpcPeering>> {
+        let task = self.get(subscription_id).await?;
+        let list: VpcPeeringList = TasksHandler::new(self.client.clone())
+            .wait_for_resource(task.task_id, options)
+            .await?;
+        Ok(list.peerings.unwrap_or_default())
+    }
+
     /// Create VPC peering
     pub async fn create(
         &self,
@@ -315,49 +388,79 @@ impl VpcPeeringHandler {
     // ========================================================================
     // Active-Active VPC Peering
     // ========================================================================
-    //
-    // Note: Active-Active VPC peering uses the same API endpoints as standard
-    // VPC peering. These methods are provided for API consistency and to match
-    // the naming convention used by other connectivity handlers.
 
     /// Get Active-Active VPC peerings
     ///
-    /// Note: Uses the same endpoint as standard VPC peering.
+    /// GET /subscriptions/{subscriptionId}/regions/peerings
     pub async fn get_active_active(&self, subscription_id: i32) -> Result<TaskStateUpdate> {
-        self.get(subscription_id).await
+        self.client
+            .get(&format!(
+                "/subscriptions/{subscription_id}/regions/peerings"
+            ))
+            .await
+    }
+
+    /// List Active-Active VPC peerings, grouped by region
+    ///
+    /// Resolves the task returned by [`get_active_active`](Self::get_active_active).
+    pub async fn list_active_active(
+        &self,
+        subscription_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<Vec<ActiveActiveVpcRegion>> {
+        let task = self.get_active_active(subscription_id).await?;
+        let list: ActiveActiveVpcPeeringList = TasksHandler::new(self.client.clone())
+            .wait_for_resource(task.task_id, options)
+            .await?;
+        Ok(list.regions.unwrap_or_default())
     }
 
     /// Create Active-Active VPC peering
     ///
-    /// Note: Uses the same endpoint as standard VPC peering.
+    /// POST /subscriptions/{subscriptionId}/regions/peerings
     pub async fn create_active_active(
         &self,
         subscription_id: i32,
-        request: &VpcPeeringCreateRequest,
+        request: &ActiveActiveVpcPeeringCreateRequest,
     ) -> Result<TaskStateUpdate> {
-        self.create(subscription_id, request).await
+        self.client
+            .post(
+                &format!("/subscriptions/{subscription_id}/regions/peerings"),
+                request,
+            )
+            .await
     }
 
     /// Delete Active-Active VPC peering
     ///
-    /// Note: Uses the same endpoint as standard VPC peering.
+    /// DELETE /subscriptions/{subscriptionId}/regions/peerings/{peeringId}
     pub async fn delete_active_active(
         &self,
         subscription_id: i32,
         peering_id: i32,
     ) -> Result<serde_json::Value> {
-        self.delete(subscription_id, peering_id).await
+        self.client
+            .delete(&format!(
+                "/subscriptions/{subscription_id}/regions/peerings/{peering_id}"
+            ))
+            .await?;
+        Ok(serde_json::Value::Null)
     }
 
-    /// Update Active-Active VPC peering
+    /// Update Active-Active VPC peering CIDRs
     ///
-    /// Note: Uses the same endpoint as standard VPC peering.
+    /// PUT /subscriptions/{subscriptionId}/regions/peerings/{peeringId}
     pub async fn update_active_active(
         &self,
         subscription_id: i32,
         peering_id: i32,
-        request: &VpcPeeringCreateRequest,
+        request: &VpcPeeringUpdateAwsRequest,
     ) -> Result<TaskStateUpdate> {
-        self.update(subscription_id, peering_id, request).await
+        self.client
+            .put(
+                &format!("/subscriptions/{subscription_id}/regions/peerings/{peering_id}"),
+                request,
+            )
+            .await
     }
 }
@@ -24,6 +24,8 @@
 //! - **Plan Type Filtering**: Filter by "pro" or "essentials"
 //! - **Region Filtering**: Filter by cloud regions
 //! - **Tag Filtering**: Filter by custom key-value tags
+//! - **Streaming Downloads**: Write large reports to any `AsyncWrite` with progress callbacks
+//! - **Line Item Parsing**: Parse CSV or JSON reports into `CostLineItem`s (`cost-parsing` feature)
 //!
 //! # Example Usage
 //!
@@ -65,9 +67,12 @@
 //! - Service categories and SKU details
 //! - Billing period and usage information
 
-use crate::{CloudClient, Result, tasks::TaskStateUpdate};
+#[cfg(feature = "cost-parsing")]
+use crate::CloudError;
+use crate::{CloudClient, DownloadProgress, Result, tasks::TaskStateUpdate};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tokio::io::AsyncWrite;
 
 // ============================================================================
 // Models
@@ -113,7 +118,7 @@ impl std::fmt::Display for SubscriptionType {
 }
 
 /// Tag filter for cost reports
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Tag {
     /// Tag key
     pub key: String,
@@ -136,7 +141,7 @@ impl Tag {
 /// Cost reports are generated asynchronously. After submitting a request,
 /// you'll receive a task ID that can be used to track the generation progress.
 /// Once complete, use the costReportId from the task response to download the report.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct CostReportCreateRequest {
     /// Start date for the report (YYYY-MM-DD format, required)
@@ -297,6 +302,210 @@ impl CostReportCreateRequestBuilder {
     }
 }
 
+// ============================================================================
+// Line item parsing
+// ============================================================================
+
+/// One charge from a downloaded cost report
+///
+/// Produced by [`parse_cost_report`]. Fields are read from the FOCUS columns
+/// of the report; identifiers are kept as strings as they appear in the file.
+#[cfg(feature = "cost-parsing")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostLineItem {
+    /// Subscription the charge belongs to
+    pub subscription: Option<String>,
+    /// Database (resource) the charge belongs to
+    pub database: Option<String>,
+    /// Cloud region of the resource
+    pub region: Option<String>,
+    /// What was consumed, e.g. a shard type or network traffic
+    pub usage_type: Option<String>,
+    /// Amount consumed
+    pub quantity: Option<f64>,
+    /// Billed cost
+    pub amount: f64,
+    /// Currency of `amount`, e.g. `USD`
+    pub currency: Option<String>,
+}
+
+/// Columns read for each [`CostLineItem`] field, in order of preference
+#[cfg(feature = "cost-parsing")]
+mod columns {
+    pub(super) const SUBSCRIPTION: &[&str] =
+        &["x_SubscriptionId", "SubscriptionId", "SubAccountId"];
+    pub(super) const DATABASE: &[&str] = &["x_DatabaseId", "DatabaseId", "ResourceId"];
+    pub(super) const REGION: &[&str] = &["RegionId", "RegionName", "Region"];
+    pub(super) const USAGE_TYPE: &[&str] =
+        &["x_UsageType", "UsageType", "ChargeDescription", "SkuId"];
+    pub(super) const QUANTITY: &[&str] = &["ConsumedQuantity", "PricingQuantity", "Quantity"];
+    pub(super) const AMOUNT: &[&str] = &["BilledCost", "Cost", "Amount"];
+    pub(super) const CURRENCY: &[&str] = &["BillingCurrency", "Currency"];
+}
+
+/// One row of a downloaded cost report
+///
+/// Column names and values in file order. CSV cells are strings (empty cells
+/// included); JSON values are kept as they appear in the report.
+#[cfg(feature = "cost-parsing")]
+pub type CostReportRow = Vec<(String, Value)>;
+
+#[cfg(feature = "cost-parsing")]
+impl CostReportFormat {
+    /// Guess the format of a downloaded report from its first character
+    #[must_use]
+    pub fn detect(data: &[u8]) -> Self {
+        let text = data.strip_prefix(UTF8_BOM).unwrap_or(data);
+        match text.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(b'[' | b'{') => CostReportFormat::Json,
+            _ => CostReportFormat::Csv,
+        }
+    }
+}
+
+#[cfg(feature = "cost-parsing")]
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Parse a downloaded cost report into line items
+///
+/// `data` is the body returned by
+/// [`CostReportHandler::download_cost_report`] and `format` the format the
+/// report was generated in. Column names are matched case-insensitively.
+/// Requires the `cost-parsing` feature.
+///
+/// # Errors
+///
+/// Returns [`CloudError::UnexpectedResponse`] if the report is malformed, has
+/// no cost column, or contains a cost or quantity that is not a number.
+#[cfg(feature = "cost-parsing")]
+pub fn parse_cost_report(data: &[u8], format: &CostReportFormat) -> Result<Vec<CostLineItem>> {
+    parse_cost_report_rows(data, format)?
+        .iter()
+        .enumerate()
+        .map(|(index, row)| line_item(index + 1, row))
+        .collect()
+}
+
+/// Parse a downloaded cost report into rows, keeping every column
+///
+/// The lower-level form of [`parse_cost_report`], for callers that need
+/// columns [`CostLineItem`] does not model. Blank CSV lines are skipped, as
+/// are JSON rows that are not objects. Requires the `cost-parsing` feature.
+///
+/// # Errors
+///
+/// Returns [`CloudError::UnexpectedResponse`] if the report is malformed.
+#[cfg(feature = "cost-parsing")]
+pub fn parse_cost_report_rows(
+    data: &[u8],
+    format: &CostReportFormat,
+) -> Result<Vec<CostReportRow>> {
+    let data = data.strip_prefix(UTF8_BOM).unwrap_or(data);
+    match format {
+        CostReportFormat::Csv => csv_rows(data),
+        CostReportFormat::Json => json_rows(data),
+    }
+}
+
+#[cfg(feature = "cost-parsing")]
+fn csv_rows(data: &[u8]) -> Result<Vec<CostReportRow>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(data);
+    let headers = reader.headers().map_err(malformed_report)?.clone();
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(malformed_report)?;
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        rows.push(
+            headers
+                .iter()
+                .enumerate()
+                .map(|(index, header)| {
+                    let value = record.get(index).unwrap_or_default();
+                    (header.to_string(), Value::String(value.to_string()))
+                })
+                .collect(),
+        );
+    }
+    Ok(rows)
+}
+
+#[cfg(feature = "cost-parsing")]
+fn json_rows(data: &[u8]) -> Result<Vec<CostReportRow>> {
+    let report: Value = serde_json::from_slice(data).map_err(malformed_report)?;
+    // Either a bare array of rows or an object wrapping one
+    let rows = match report {
+        Value::Array(rows) => rows,
+        Value::Object(fields) => fields
+            .into_iter()
+            .find_map(|(_, value)| match value {
+                Value::Array(rows) => Some(rows),
+                _ => None,
+            })
+            .unwrap_or_default(),
+        _ => return Err(malformed_report("expected an array of rows")),
+    };
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| match row {
+            Value::Object(fields) => Some(fields.into_iter().collect()),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Build a line item from report row number `index`
+#[cfg(feature = "cost-parsing")]
+fn line_item(index: usize, row: &CostReportRow) -> Result<CostLineItem> {
+    let get = |names: &[&str]| {
+        names.iter().find_map(|name| {
+            let (_, value) = row
+                .iter()
+                .find(|(column, _)| column.eq_ignore_ascii_case(name))?;
+            match value {
+                Value::String(value) if !value.trim().is_empty() => Some(value.trim().to_string()),
+                Value::Number(value) => Some(value.to_string()),
+                _ => None,
+            }
+        })
+    };
+    let number = |names: &[&str]| -> Result<Option<f64>> {
+        get(names)
+            .map(|value| {
+                value.parse::<f64>().map_err(|_| {
+                    malformed_report(format!(
+                        "row {index}: '{value}' in {} is not a number",
+                        names[0]
+                    ))
+                })
+            })
+            .transpose()
+    };
+    Ok(CostLineItem {
+        subscription: get(columns::SUBSCRIPTION),
+        database: get(columns::DATABASE),
+        region: get(columns::REGION),
+        usage_type: get(columns::USAGE_TYPE),
+        quantity: number(columns::QUANTITY)?,
+        amount: number(columns::AMOUNT)?.ok_or_else(|| {
+            malformed_report(format!("row {index} has no {} value", columns::AMOUNT[0]))
+        })?,
+        currency: get(columns::CURRENCY),
+    })
+}
+
+#[cfg(feature = "cost-parsing")]
+fn malformed_report(error: impl std::fmt::Display) -> CloudError {
+    CloudError::UnexpectedResponse {
+        message: format!("Malformed cost report: {error}"),
+    }
+}
+
 // ============================================================================
 // Handler
 // ============================================================================
@@ -391,6 +600,69 @@ impl CostReportHandler {
             .get_bytes(&format!("/cost-report/{cost_report_id}"))
             .await
     }
+
+    /// Download a generated cost report into `writer` (Beta)
+    ///
+    /// Streams the report chunk by chunk instead of buffering it like
+    /// [`download_cost_report`](Self::download_cost_report), so large CSV
+    /// exports can go straight to a file. `on_progress` is called after each
+    /// chunk. Returns the number of bytes written.
+    ///
+    /// GET /cost-report/{costReportId}
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use redis_cloud::{CloudClient, CostReportHandler};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = CloudClient::builder().api_key("k").api_secret("s").build()?;
+    /// let handler = CostReportHandler::new(client);
+    /// let mut file = tokio::fs::File::create("cost-report.csv").await?;
+    /// let written = handler
+    ///     .download_to("cost-report-12345", &mut file, |progress| {
+    ///         if let Some(fraction) = progress.fraction() {
+    ///             eprintln!("{:.0}%", fraction * 100.0);
+    ///         }
+    ///     })
+    ///     .await?;
+    /// println!("Wrote {written} bytes");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_to<W, F>(
+        &self,
+        cost_report_id: &str,
+        writer: &mut W,
+        on_progress: F,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+        F: FnMut(DownloadProgress),
+    {
+        self.client
+            .get_bytes_to(
+                &format!("/cost-report/{cost_report_id}"),
+                writer,
+                on_progress,
+            )
+            .await
+    }
+
+    /// Download a generated cost report and parse it into line items (Beta)
+    ///
+    /// Combines [`download_cost_report`](Self::download_cost_report) with
+    /// [`parse_cost_report`]; `format` must match the format the report was
+    /// generated in. Requires the `cost-parsing` feature.
+    ///
+    /// GET /cost-report/{costReportId}
+    #[cfg(feature = "cost-parsing")]
+    pub async fn download_line_items(
+        &self,
+        cost_report_id: &str,
+        format: &CostReportFormat,
+    ) -> Result<Vec<CostLineItem>> {
+        let data = self.download_cost_report(cost_report_id).await?;
+        parse_cost_report(&data, format)
+    }
 }
 
 #[cfg(test)]
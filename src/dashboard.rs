@@ -0,0 +1,180 @@
+//! Account overview for dashboards
+//!
+//! [`CloudClient::dashboard`] gathers what an overview page usually shows in
+//! one call: every Pro subscription with its databases, the database alert
+//! settings, and the most recent tasks. Independent requests are issued
+//! concurrently, with at most [`ALL_DATABASES_CONCURRENCY`] database listings
+//! in flight, so the page renders after a few round trips instead of one per
+//! subscription. The client adds no further limit unless one is configured
+//! with [`CloudClientBuilder::max_concurrent_requests`](crate::CloudClientBuilder::max_concurrent_requests).
+//!
+//! Only the subscription list is required. Databases of a single
+//! subscription and the task list are reported as [`Section`]s, so one failing
+//! subscription does not blank the whole page.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use redis_cloud::CloudClient;
+//!
+//! # async fn example() -> redis_cloud::Result<()> {
+//! let client = CloudClient::builder()
+//!     .api_key("your-api-key")
+//!     .api_secret("your-api-secret")
+//!     .build()?;
+//!
+//! let dashboard = client.dashboard().await?;
+//! for overview in &dashboard.subscriptions {
+//!     let databases = overview.databases.available().map_or(0, Vec::len);
+//!     println!("{:?}: {databases} databases", overview.subscription.name);
+//! }
+//! for alert in dashboard.alerts() {
+//!     println!("{} on {}: {}", alert.alert.name, alert.database_id, alert.alert.value);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::databases::{self, ALL_DATABASES_CONCURRENCY, Database};
+use crate::subscriptions::Subscription;
+use crate::tasks::TaskStateUpdate;
+use crate::types::Section;
+use crate::{CloudClient, Result};
+use futures_util::future::join;
+use futures_util::stream::{self, StreamExt};
+
+/// Number of tasks kept in [`Dashboard::recent_tasks`]
+pub const RECENT_TASKS: usize = 20;
+
+/// Overview of the account's Pro subscriptions, databases, and recent tasks
+#[derive(Debug, Clone)]
+pub struct Dashboard {
+    /// Subscriptions with their databases, in API order
+    pub subscriptions: Vec<SubscriptionOverview>,
+    /// Most recent tasks, newest first, at most [`RECENT_TASKS`]
+    pub recent_tasks: Section<Vec<TaskStateUpdate>>,
+}
+
+/// A subscription and its databases
+#[derive(Debug, Clone)]
+pub struct SubscriptionOverview {
+    /// The subscription
+    pub subscription: Subscription,
+    /// Databases in the subscription
+    pub databases: Section<Vec<Database>>,
+}
+
+/// An alert configured on a database
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatabaseAlert<'a> {
+    /// Subscription the database belongs to
+    pub subscription_id: Option<i32>,
+    /// Database the alert is set on
+    pub database_id: i32,
+    /// Database name
+    pub database_name: Option<&'a str>,
+    /// Alert type and threshold
+    pub alert: &'a databases::DatabaseAlert,
+}
+
+impl Dashboard {
+    /// Total number of databases that could be fetched
+    #[must_use]
+    pub fn database_count(&self) -> usize {
+        self.subscriptions
+            .iter()
+            .filter_map(|overview| overview.databases.available())
+            .map(Vec::len)
+            .sum()
+    }
+
+    /// Returns true if any subscription's databases or the task list failed to load
+    #[must_use]
+    pub fn has_failures(&self) -> bool {
+        self.recent_tasks.error().is_some()
+            || self
+                .subscriptions
+                .iter()
+                .any(|overview| overview.databases.error().is_some())
+    }
+
+    /// Alert settings of every fetched database
+    pub fn alerts(&self) -> impl Iterator<Item = DatabaseAlert<'_>> {
+        self.subscriptions.iter().flat_map(|overview| {
+            overview
+                .databases
+                .available()
+                .into_iter()
+                .flatten()
+                .flat_map(move |database| {
+                    database
+                        .alerts
+                        .iter()
+                        .flatten()
+                        .map(move |alert| DatabaseAlert {
+                            subscription_id: overview.subscription.id,
+                            database_id: database.database_id,
+                            database_name: database.name.as_deref(),
+                            alert,
+                        })
+                })
+        })
+    }
+}
+
+impl CloudClient {
+    /// Fetch an overview of the account for rendering a dashboard
+    ///
+    /// Lists subscriptions and tasks concurrently, then fetches the databases
+    /// of up to [`ALL_DATABASES_CONCURRENCY`] subscriptions at a time. Fails
+    /// only if the subscription list cannot be fetched.
+    ///
+    /// GET /subscriptions, GET /tasks, GET /subscriptions/{subscriptionId}/databases
+    pub async fn dashboard(&self) -> Result<Dashboard> {
+        let subscriptions = self.subscriptions();
+        let tasks = self.tasks();
+        let (account, tasks) =
+            join(subscriptions.get_all_subscriptions(), tasks.get_all_tasks()).await;
+
+        let databases = self.databases();
+        let subscriptions = account?.subscriptions.unwrap_or_default();
+        let ids: Vec<_> = subscriptions.iter().map(|s| s.id).enumerate().collect();
+        let databases = &databases;
+        let mut fetched: Vec<_> = stream::iter(ids)
+            .map(|(index, id)| async move {
+                let section = match id {
+                    Some(id) => Section::from_result(databases.get_all_databases(id).await),
+                    None => Section::NotConfigured { message: None },
+                };
+                (index, section)
+            })
+            .buffer_unordered(ALL_DATABASES_CONCURRENCY)
+            .collect()
+            .await;
+        // Completion order is arbitrary; restore API order
+        fetched.sort_unstable_by_key(|(index, _)| *index);
+        let fetched = fetched.into_iter().map(|(_, section)| section);
+
+        Ok(Dashboard {
+            subscriptions: subscriptions
+                .into_iter()
+                .zip(fetched)
+                .map(|(subscription, databases)| SubscriptionOverview {
+                    subscription,
+                    databases,
+                })
+                .collect(),
+            recent_tasks: Section::from_result(tasks.map(recent)),
+        })
+    }
+}
+
+/// Newest tasks first, capped at [`RECENT_TASKS`]
+fn recent(mut tasks: Vec<TaskStateUpdate>) -> Vec<TaskStateUpdate> {
+    // ISO 8601 timestamps sort chronologically as strings
+    // `Timestamp` is only `Copy` with chrono-timestamps
+    #[cfg_attr(feature = "chrono-timestamps", allow(clippy::clone_on_copy))]
+    tasks.sort_by_key(|task| std::cmp::Reverse(task.timestamp.clone()));
+    tasks.truncate(RECENT_TASKS);
+    tasks
+}
@@ -9,21 +9,97 @@
 //! - `CloudError::AuthenticationFailed` - HTTP 401 errors
 //! - `CloudError::Forbidden` - HTTP 403 errors
 //! - `CloudError::NotFound` - HTTP 404 errors
+//! - `CloudError::Conflict` - HTTP 409 errors
 //! - `CloudError::RateLimited` - HTTP 429 errors
 //! - `CloudError::InternalServerError` - HTTP 500 errors
 //! - `CloudError::ServiceUnavailable` - HTTP 503 errors
+//! - `CloudError::ResourceBusy` - HTTP 400/409 errors caused by a subscription or
+//!   database that is still pending or transitioning between states, when the
+//!   client was built with `retry_on_busy`
+//! - `CloudError::Configuration` - invalid client configuration detected by the builder
+//! - `CloudError::UnexpectedResponse` - a successful response missing data the
+//!   operation needs, such as a task ID
 //!
 //! # Retryable Errors
 //!
 //! Some errors are considered retryable (transient failures that may succeed on retry):
 //! - Rate limited (429)
 //! - Service unavailable (503)
+//! - Resource busy (target still pending/transitioning)
 //! - Connection/request errors (network issues)
 //!
 //! Use `CloudError::is_retryable()` to check if an error should be retried.
+//!
+//! # Error Bodies
+//!
+//! Variants produced from an API response keep the raw response body as their
+//! `message`. `CloudError::error_body()` parses the standard Redis Cloud error
+//! JSON (error code, description, status, timestamp, trace ID) into an
+//! [`ApiErrorBody`]; `CloudError::raw_body()` returns the text as received.
+//!
+//! # Error Kinds
+//!
+//! `CloudError::kind()` groups every variant into a coarse [`ErrorKind`]
+//! (authentication, not found, quota, validation, transient, task failure),
+//! for callers such as CLIs that map errors to exit codes.
 
+use serde_json::Value;
+use std::time::Duration;
 use thiserror::Error;
 
+/// Default wait suggested when the API reports a resource as busy without a
+/// `Retry-After` header
+pub const DEFAULT_BUSY_RETRY_AFTER: Duration = Duration::from_secs(5);
+
+/// Error code fragments the API uses when rejecting an operation because the
+/// target subscription or database has not settled yet
+const BUSY_CODE_MARKERS: &[&str] = &[
+    "NOT_ACTIVE",
+    "PENDING",
+    "DRAFT",
+    "IN_PROGRESS",
+    "IN_TRANSITION",
+    "BEING_PROCESSED",
+];
+
+/// Description phrases the API uses for the same rejections when the error
+/// code is generic
+const BUSY_DESCRIPTION_MARKERS: &[&str] = &[
+    "is pending",
+    "still pending",
+    "in draft",
+    "is not active",
+    "is in progress",
+    "is being processed",
+    "in transition",
+    "is transitioning",
+];
+
+/// Error code fragments the API uses when an account or plan limit is hit
+const QUOTA_CODE_MARKERS: &[&str] = &["QUOTA", "LIMIT_EXCEEDED", "LIMIT_REACHED", "EXCEEDS_LIMIT"];
+
+/// Coarse classification of a [`CloudError`]
+///
+/// Stable across new `CloudError` variants, so downstream tools can map
+/// errors to exit codes or messages without matching every variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Credentials were rejected or lack permission (401, 403)
+    Auth,
+    /// The requested resource does not exist (404)
+    NotFound,
+    /// A rate limit or account/plan limit was reached
+    Quota,
+    /// The request was rejected as invalid or conflicting, or the client is misconfigured
+    Validation,
+    /// A temporary failure; retrying later may succeed
+    Transient,
+    /// An asynchronous task finished with an error
+    TaskFailure,
+    /// Anything else, such as an unexpected response body
+    Unknown,
+}
+
 /// Errors that can occur when interacting with the Redis Cloud API
 #[derive(Error, Debug, Clone)]
 pub enum CloudError {
@@ -59,6 +135,13 @@ pub enum CloudError {
         message: String,
     },
 
+    /// Conflict (409) - Duplicate resource or concurrent modification
+    #[error("Conflict (409): {message}")]
+    Conflict {
+        /// Error message from the API
+        message: String,
+    },
+
     /// Precondition Failed (412) - Feature flag is disabled
     #[error("Precondition Failed (412): Feature flag for this flow is off")]
     PreconditionFailed,
@@ -84,6 +167,20 @@ pub enum CloudError {
         message: String,
     },
 
+    /// Resource busy (400/409) - The target subscription or database is still
+    /// pending, in draft, or transitioning and cannot accept the operation yet
+    ///
+    /// Only produced by clients built with
+    /// [`retry_on_busy`](crate::CloudClientBuilder::retry_on_busy); otherwise
+    /// such rejections surface as `BadRequest` or `Conflict`.
+    #[error("Resource busy: {message} (retry after {}s)", .retry_after.as_secs())]
+    ResourceBusy {
+        /// Error message from the API
+        message: String,
+        /// Suggested wait before retrying the operation
+        retry_after: Duration,
+    },
+
     /// Generic API error for other HTTP status codes
     #[error("API error ({code}): {message}")]
     ApiError {
@@ -93,13 +190,51 @@ pub enum CloudError {
         message: String,
     },
 
+    /// Timed out waiting for an asynchronous task to finish
+    #[error("Timed out waiting for task {task_id}")]
+    TaskTimeout {
+        /// ID of the task that did not finish in time
+        task_id: String,
+    },
+
+    /// An asynchronous task finished with `processing-error`
+    #[error("Task {task_id} failed: {}", .error.as_deref().unwrap_or("no error reported"))]
+    TaskFailed {
+        /// ID of the failed task
+        task_id: String,
+        /// Error code reported by the task, e.g. `DATABASE_NAME_ALREADY_EXISTS`
+        error: Option<String>,
+        /// Additional details reported by the task
+        additional_info: Option<String>,
+    },
+
+    /// The API answered successfully, but the response lacked something the
+    /// operation needs (a task ID, the ID of a created resource, ...) or
+    /// could not be interpreted
+    #[error("Unexpected response: {message}")]
+    UnexpectedResponse {
+        /// What was missing or malformed
+        message: String,
+    },
+
     /// Connection error (failed to establish connection)
     #[error("Connection error: {0}")]
     ConnectionError(String),
 
+    /// Invalid client configuration (missing credentials, bad certificate, ...)
+    #[error("Configuration error: {message}")]
+    Configuration {
+        /// Description of the configuration problem
+        message: String,
+    },
+
     /// JSON serialization/deserialization error
     #[error("JSON error: {0}")]
     JsonError(String),
+
+    /// Error writing to or reading from a caller-provided writer or file
+    #[error("I/O error: {0}")]
+    IoError(String),
 }
 
 impl CloudError {
@@ -108,6 +243,7 @@ impl CloudError {
     /// Retryable errors include:
     /// - Rate limited (429)
     /// - Service unavailable (503)
+    /// - Resource busy (target still pending/transitioning)
     /// - Connection/request errors (may be transient network issues)
     ///
     /// # Examples
@@ -127,10 +263,235 @@ impl CloudError {
             self,
             CloudError::RateLimited { .. }
                 | CloudError::ServiceUnavailable { .. }
+                | CloudError::ResourceBusy { .. }
                 | CloudError::Request(_)
                 | CloudError::ConnectionError(_)
         )
     }
+
+    /// Classify the error into an [`ErrorKind`]
+    ///
+    /// Rejections whose error code names a quota or limit (for example
+    /// `..._LIMIT_EXCEEDED`) are reported as [`ErrorKind::Quota`] even when
+    /// the API answered 400 or 403.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_cloud::{CloudError, ErrorKind};
+    ///
+    /// let error = CloudError::Forbidden { message: "No access".to_string() };
+    /// assert_eq!(error.kind(), ErrorKind::Auth);
+    ///
+    /// let error = CloudError::ServiceUnavailable { message: "Maintenance".to_string() };
+    /// assert_eq!(error.kind(), ErrorKind::Transient);
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        if self.is_quota_code() {
+            return ErrorKind::Quota;
+        }
+        match self {
+            CloudError::AuthenticationFailed { .. } | CloudError::Forbidden { .. } => {
+                ErrorKind::Auth
+            }
+            CloudError::NotFound { .. } => ErrorKind::NotFound,
+            CloudError::RateLimited { .. } => ErrorKind::Quota,
+            CloudError::BadRequest { .. }
+            | CloudError::Conflict { .. }
+            | CloudError::PreconditionFailed
+            | CloudError::Configuration { .. } => ErrorKind::Validation,
+            CloudError::InternalServerError { .. }
+            | CloudError::ServiceUnavailable { .. }
+            | CloudError::ResourceBusy { .. }
+            | CloudError::TaskTimeout { .. }
+            | CloudError::Request(_)
+            | CloudError::ConnectionError(_) => ErrorKind::Transient,
+            CloudError::TaskFailed { .. } => ErrorKind::TaskFailure,
+            CloudError::ApiError { code, .. } => match code {
+                401 | 403 => ErrorKind::Auth,
+                404 => ErrorKind::NotFound,
+                429 => ErrorKind::Quota,
+                400 | 409 | 412 | 422 => ErrorKind::Validation,
+                500..=599 => ErrorKind::Transient,
+                _ => ErrorKind::Unknown,
+            },
+            CloudError::JsonError(_)
+            | CloudError::IoError(_)
+            | CloudError::UnexpectedResponse { .. } => ErrorKind::Unknown,
+        }
+    }
+
+    /// Returns true if the API error code names a quota or limit
+    fn is_quota_code(&self) -> bool {
+        self.error_body()
+            .and_then(|body| body.error_code)
+            .is_some_and(|code| {
+                let code = code.to_ascii_uppercase();
+                QUOTA_CODE_MARKERS
+                    .iter()
+                    .any(|marker| code.contains(marker))
+            })
+    }
+
+    /// Returns true if the target resource was still pending or transitioning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_cloud::CloudError;
+    /// use std::time::Duration;
+    ///
+    /// let error = CloudError::ResourceBusy {
+    ///     message: "Subscription is pending".to_string(),
+    ///     retry_after: Duration::from_secs(5),
+    /// };
+    /// assert!(error.is_resource_busy());
+    /// assert_eq!(error.retry_after(), Some(Duration::from_secs(5)));
+    /// ```
+    #[must_use]
+    pub fn is_resource_busy(&self) -> bool {
+        matches!(self, CloudError::ResourceBusy { .. })
+    }
+
+    /// Returns the suggested wait before retrying, if the API provided one.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            CloudError::ResourceBusy { retry_after, .. } => Some(*retry_after),
+            _ => None,
+        }
+    }
+
+    /// Returns the response body for errors returned by the API
+    #[must_use]
+    pub fn raw_body(&self) -> Option<&str> {
+        match self {
+            CloudError::BadRequest { message }
+            | CloudError::AuthenticationFailed { message }
+            | CloudError::Forbidden { message }
+            | CloudError::NotFound { message }
+            | CloudError::Conflict { message }
+            | CloudError::RateLimited { message }
+            | CloudError::InternalServerError { message }
+            | CloudError::ServiceUnavailable { message }
+            | CloudError::ResourceBusy { message, .. }
+            | CloudError::ApiError { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Parses the response body into the standard Redis Cloud error format.
+    ///
+    /// Returns `None` when the error did not come from an API response or the
+    /// body is not a recognizable error object; use
+    /// [`raw_body`](Self::raw_body) for those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_cloud::CloudError;
+    ///
+    /// let error = CloudError::NotFound {
+    ///     message: r#"{"type":"SUBSCRIPTION_NOT_FOUND","status":"404 NOT_FOUND","description":"Subscription was not found"}"#.to_string(),
+    /// };
+    /// let body = error.error_body().unwrap();
+    /// assert_eq!(body.error_code.as_deref(), Some("SUBSCRIPTION_NOT_FOUND"));
+    /// assert_eq!(body.description.as_deref(), Some("Subscription was not found"));
+    /// ```
+    #[must_use]
+    pub fn error_body(&self) -> Option<ApiErrorBody> {
+        self.raw_body().and_then(ApiErrorBody::parse)
+    }
+}
+
+/// Structured Redis Cloud error response
+///
+/// The API reports errors as JSON objects, though field names vary between
+/// endpoints (`type`/`errorCode`/`code`, `description`/`message`/`error`).
+/// [`ApiErrorBody::parse`] accepts all of these.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiErrorBody {
+    /// Machine-readable error code (e.g. `SUBSCRIPTION_NOT_FOUND`)
+    pub error_code: Option<String>,
+    /// Human-readable description
+    pub description: Option<String>,
+    /// HTTP status as reported in the body (e.g. `404 NOT_FOUND`)
+    pub status: Option<String>,
+    /// When the error occurred
+    pub timestamp: Option<String>,
+    /// Trace ID to quote when contacting Redis support
+    pub trace_id: Option<String>,
+}
+
+impl ApiErrorBody {
+    /// Parse an error response body
+    ///
+    /// Returns `None` if the body is not a JSON object or contains none of
+    /// the known error fields.
+    #[must_use]
+    pub fn parse(raw: &str) -> Option<Self> {
+        let Value::Object(fields) = serde_json::from_str::<Value>(raw).ok()? else {
+            return None;
+        };
+
+        let field = |names: &[&str]| {
+            names.iter().find_map(|name| match fields.get(*name)? {
+                Value::String(s) => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        };
+
+        let description = field(&["description", "message", "error"]);
+        let mut error_code = field(&["errorCode", "type", "code"]);
+        // `error` is a code when a separate description is present
+        if error_code.is_none() && description != field(&["error"]) {
+            error_code = field(&["error"]);
+        }
+
+        let body = Self {
+            error_code,
+            description,
+            status: field(&["status"]),
+            timestamp: field(&["timestamp"]),
+            trace_id: field(&["traceId", "trace_id", "requestId"]),
+        };
+
+        (body != Self::default()).then_some(body)
+    }
+
+    /// Returns true if the error reports that the target resource is still
+    /// pending, in draft, or transitioning between states
+    #[must_use]
+    pub fn is_busy(&self) -> bool {
+        // A bare `{"error": "CODE"}` body is parsed as a description
+        let code = self.error_code.as_deref().or_else(|| {
+            self.description
+                .as_deref()
+                .filter(|description| !description.contains(char::is_whitespace))
+        });
+        let code_matches = code.is_some_and(|code| {
+            let code = code.to_ascii_uppercase();
+            BUSY_CODE_MARKERS.iter().any(|marker| code.contains(marker))
+        });
+        let description_matches = self.description.as_deref().is_some_and(|description| {
+            let description = description.to_ascii_lowercase();
+            BUSY_DESCRIPTION_MARKERS
+                .iter()
+                .any(|marker| description.contains(marker))
+        });
+        code_matches || description_matches
+    }
+}
+
+/// Returns true if an API error response body reports that the target
+/// resource is still pending, in draft, or transitioning between states.
+///
+/// Only structured error bodies are classified; see [`ApiErrorBody::is_busy`].
+#[must_use]
+pub fn is_busy_message(message: &str) -> bool {
+    ApiErrorBody::parse(message).is_some_and(|body| body.is_busy())
 }
 
 impl From<reqwest::Error> for CloudError {
@@ -145,5 +506,11 @@ impl From<serde_json::Error> for CloudError {
     }
 }
 
+impl From<std::io::Error> for CloudError {
+    fn from(err: std::io::Error) -> Self {
+        CloudError::IoError(err.to_string())
+    }
+}
+
 /// Result type alias for Redis Cloud operations
 pub type Result<T> = std::result::Result<T, CloudError>;
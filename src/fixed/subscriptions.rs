@@ -49,24 +49,24 @@
 //! # }
 //! ```
 
-use crate::types::{Link, ProcessorResponse};
-use crate::{CloudClient, Result};
-use serde::{Deserialize, Serialize};
+use crate::query::QueryParams;
+use crate::tasks::{TaskResultExt, TaskWaitOptions, TasksHandler, Updated};
+use crate::types::{CloudProvider, Link, ProcessorResponse, Timestamp};
+use crate::{CloudClient, CloudError, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// Essentials and Pro share the Redis version model, so
+/// [`RedisVersions::default_version`], [`RedisVersions::matching`] and
+/// [`RedisVersion::eol`] work the same for both.
+pub use crate::flexible::subscriptions::{RedisVersion, RedisVersions};
 
 // ============================================================================
 // Models
 // ============================================================================
 
-/// `RedisVersions`
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct RedisVersions {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub redis_versions: Option<Vec<RedisVersion>>,
-}
-
 /// Redis list of Essentials subscriptions plans
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FixedSubscriptionsPlans {
     /// HATEOAS links
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -74,7 +74,7 @@ pub struct FixedSubscriptionsPlans {
 }
 
 /// Essentials subscription update request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FixedSubscriptionUpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,7 +101,7 @@ pub struct FixedSubscriptionUpdateRequest {
 }
 
 /// Redis Essentials subscription plan information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FixedSubscriptionsPlan {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -120,7 +120,7 @@ pub struct FixedSubscriptionsPlan {
     pub size_measurement_unit: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider: Option<String>,
+    pub provider: Option<CloudProvider>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
@@ -149,7 +149,12 @@ pub struct FixedSubscriptionsPlan {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub availability: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Maximum concurrent connections ("1024", "unlimited")
+    #[serde(
+        default,
+        deserialize_with = "deserialize_connections",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub connections: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -185,8 +190,29 @@ pub struct FixedSubscriptionsPlan {
     pub links: Option<Vec<Link>>,
 }
 
+/// Essentials plan from the `/fixed/plans` catalog
+///
+/// Describes the dataset size, price, cloud provider and region, and the
+/// features supported by databases created on the plan.
+pub type FixedPlan = FixedSubscriptionsPlan;
+
+/// Typed list of Essentials plans
+///
+/// Response from GET /fixed/plans and GET /fixed/plans/subscriptions/{subscriptionId}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedPlans {
+    /// Available plans
+    #[serde(default)]
+    pub plans: Vec<FixedPlan>,
+
+    /// HATEOAS links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<Link>>,
+}
+
 /// Essentials subscription create request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FixedSubscriptionCreateRequest {
     /// New Essentials subscription name.
@@ -208,7 +234,7 @@ pub struct FixedSubscriptionCreateRequest {
 }
 
 /// Redis list of Essentials subscriptions in current account
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FixedSubscriptions {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -223,25 +249,24 @@ pub struct FixedSubscriptions {
     pub links: Option<Vec<Link>>,
 }
 
-/// `RedisVersion`
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct RedisVersion {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub eol_date: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_preview: Option<bool>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_default: Option<bool>,
+/// The API reports `connections` as a string ("1024", "unlimited") for most
+/// plans but as a bare number for some, so accept both.
+fn deserialize_connections<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(Value::Number(n)) => Ok(Some(n.to_string())),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "expected string or number for connections, got {other}"
+        ))),
+    }
 }
 
 /// Redis Essentials Subscription information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FixedSubscription {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -275,7 +300,7 @@ pub struct FixedSubscription {
     pub size_measurement_unit: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider: Option<String>,
+    pub provider: Option<CloudProvider>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
@@ -295,7 +320,12 @@ pub struct FixedSubscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub availability: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Maximum concurrent connections ("1024", "unlimited")
+    #[serde(
+        default,
+        deserialize_with = "deserialize_connections",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub connections: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -328,7 +358,7 @@ pub struct FixedSubscription {
 }
 
 /// `TaskStateUpdate`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskStateUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -344,7 +374,7 @@ pub struct TaskStateUpdate {
     pub description: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub response: Option<ProcessorResponse>,
@@ -376,27 +406,66 @@ impl FixedSubscriptionHandler {
     /// Get Essentials plans
     /// Gets a list of Essentials plans. The plan describes the dataset size, cloud provider and region, and available database configuration options for an Essentials database.
     ///
+    /// The API filters by `provider`; `region` (e.g. "us-east-1") is matched
+    /// case-insensitively against each plan's region.
+    ///
     /// GET /fixed/plans
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let plans = client
+    ///     .fixed_subscriptions()
+    ///     .list_plans(Some("AWS".to_string()), Some("us-east-1".to_string()))
+    ///     .await?;
+    /// for plan in &plans.plans {
+    ///     println!("{:?}: {:?} {:?} for {:?}", plan.id, plan.size, plan.size_measurement_unit, plan.price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn list_plans(
+        &self,
+        provider: Option<String>,
+        region: Option<String>,
+    ) -> Result<FixedPlans> {
+        self.fetch_plans(provider, None, region).await
+    }
+
+    /// Get Redis Flex Essentials plans
+    /// Gets the list of Essentials plans that support Redis Flex.
+    ///
+    /// GET /fixed/plans?redisFlex=true
+    pub async fn list_redis_flex_plans(&self, provider: Option<String>) -> Result<FixedPlans> {
+        self.fetch_plans(provider, Some(true), None).await
+    }
+
+    async fn fetch_plans(
         &self,
         provider: Option<String>,
         redis_flex: Option<bool>,
-    ) -> Result<FixedSubscriptionsPlans> {
-        let mut query = Vec::new();
-        if let Some(v) = provider {
-            query.push(format!("provider={v}"));
-        }
-        if let Some(v) = redis_flex {
-            query.push(format!("redisFlex={v}"));
+        region: Option<String>,
+    ) -> Result<FixedPlans> {
+        let query = QueryParams::new()
+            .push_opt("provider", provider)
+            .push_opt("redisFlex", redis_flex);
+        let mut plans: FixedPlans = self.client.get(&format!("/fixed/plans{query}")).await?;
+        if let Some(region) = region {
+            plans.plans.retain(|plan| {
+                plan.region
+                    .as_deref()
+                    .is_some_and(|r| r.eq_ignore_ascii_case(&region))
+            });
         }
-        let query_string = if query.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", query.join("&"))
-        };
-        self.client
-            .get(&format!("/fixed/plans{query_string}"))
-            .await
+        Ok(plans)
     }
 
     /// Get Essentials plans for a subscription
@@ -412,8 +481,18 @@ impl FixedSubscriptionHandler {
             .await
     }
 
+    /// Get typed Essentials plans for a subscription
+    /// Gets the list of compatible Essentials plans for the specified Essentials subscription.
+    ///
+    /// GET /fixed/plans/subscriptions/{subscriptionId}
+    pub async fn get_plans_for_subscription(&self, subscription_id: i32) -> Result<FixedPlans> {
+        self.client
+            .get(&format!("/fixed/plans/subscriptions/{subscription_id}"))
+            .await
+    }
+
     /// Get a single Essentials plan
-    /// Gets information on the specified Essentials plan.
+    /// Gets the size, price, region, and supported features of the specified Essentials plan.
     ///
     /// GET /fixed/plans/{planId}
     pub async fn get_plan_by_id(&self, plan_id: i32) -> Result<FixedSubscriptionsPlan> {
@@ -425,18 +504,26 @@ impl FixedSubscriptionHandler {
     ///
     /// GET /fixed/redis-versions
     pub async fn get_redis_versions(&self, subscription_id: i32) -> Result<RedisVersions> {
-        let mut query = Vec::new();
-        query.push(format!("subscriptionId={subscription_id}"));
-        let query_string = if query.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", query.join("&"))
-        };
+        let query = QueryParams::new().push("subscriptionId", subscription_id);
         self.client
-            .get(&format!("/fixed/redis-versions{query_string}"))
+            .get(&format!("/fixed/redis-versions{query}"))
             .await
     }
 
+    /// Get the default Redis version for new databases in an Essentials subscription
+    ///
+    /// GET /fixed/redis-versions
+    pub async fn get_default_redis_version(
+        &self,
+        subscription_id: i32,
+    ) -> Result<Option<RedisVersion>> {
+        Ok(self
+            .get_redis_versions(subscription_id)
+            .await?
+            .default_version()
+            .cloned())
+    }
+
     /// Get Essentials subscriptions
     /// Gets a list of all Essentials subscriptions in the current account.
     ///
@@ -445,6 +532,28 @@ impl FixedSubscriptionHandler {
         self.client.get("/fixed/subscriptions").await
     }
 
+    /// Get a page of Essentials subscriptions
+    /// Like [`list`](Self::list), but only returns up to `limit`
+    /// subscriptions starting at `offset`. The endpoint does not page
+    /// server-side, so the full list is fetched and sliced.
+    ///
+    /// GET /fixed/subscriptions
+    pub async fn list_paginated(
+        &self,
+        offset: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<FixedSubscriptions> {
+        let mut response = self.list().await?;
+
+        let skip = offset.map_or(0, |v| usize::try_from(v).unwrap_or(0));
+        let take = limit.map_or(usize::MAX, |v| usize::try_from(v).unwrap_or(0));
+        if let Some(subscriptions) = response.subscriptions.take() {
+            response.subscriptions =
+                Some(subscriptions.into_iter().skip(skip).take(take).collect());
+        }
+        Ok(response)
+    }
+
     /// Create Essentials subscription
     /// Creates a new Essentials subscription.
     ///
@@ -456,6 +565,33 @@ impl FixedSubscriptionHandler {
         self.client.post("/fixed/subscriptions", request).await
     }
 
+    /// Create Essentials subscription and wait for it
+    /// Creates the subscription, waits for the create task to finish, and
+    /// fetches the new subscription by the ID the task reports.
+    ///
+    /// POST /fixed/subscriptions
+    pub async fn create_and_wait(
+        &self,
+        request: &FixedSubscriptionCreateRequest,
+        options: &TaskWaitOptions,
+    ) -> Result<Updated<FixedSubscription>> {
+        let task = self.create(request).await?;
+        let task_id = task.task_id.ok_or_else(|| CloudError::UnexpectedResponse {
+            message: "Response did not include a task ID".to_string(),
+        })?;
+        let task = TasksHandler::new(self.client.clone())
+            .wait_for_task(&task_id, options)
+            .await?
+            .into_result()?;
+        let subscription_id = task
+            .resource_id()
+            .ok_or_else(|| CloudError::UnexpectedResponse {
+                message: format!("Task {task_id} did not report the new subscription ID"),
+            })?;
+        let resource = self.get_by_id(subscription_id).await?;
+        Ok(Updated { task, resource })
+    }
+
     /// Delete Essentials subscription
     /// Deletes the specified Essentials subscription. All databases in the subscription must be deleted before deleting it.
     ///
@@ -491,4 +627,54 @@ impl FixedSubscriptionHandler {
             .put(&format!("/fixed/subscriptions/{subscription_id}"), request)
             .await
     }
+
+    /// Update Essentials subscription and wait for the result
+    /// Updates the subscription, waits for the update task to finish, and
+    /// re-fetches the subscription so the caller gets both in one call.
+    ///
+    /// PUT /fixed/subscriptions/{subscriptionId}
+    pub async fn update_and_fetch(
+        &self,
+        subscription_id: i32,
+        request: &FixedSubscriptionUpdateRequest,
+        options: &TaskWaitOptions,
+    ) -> Result<Updated<FixedSubscription>> {
+        let task = self.update(subscription_id, request).await?;
+        TasksHandler::new(self.client.clone())
+            .wait_and_fetch(task.task_id, options, || self.get_by_id(subscription_id))
+            .await
+    }
+
+    /// Move an Essentials subscription to another plan and wait for it
+    /// Checks that `new_plan_id` is one of the plans compatible with the
+    /// subscription, submits the change, waits for the task, and re-fetches
+    /// the subscription. An incompatible plan is rejected with
+    /// `CloudError::BadRequest` before anything is submitted.
+    ///
+    /// GET /fixed/plans/subscriptions/{subscriptionId}, then PUT /fixed/subscriptions/{subscriptionId}
+    pub async fn change_plan(
+        &self,
+        subscription_id: i32,
+        new_plan_id: i32,
+        options: &TaskWaitOptions,
+    ) -> Result<Updated<FixedSubscription>> {
+        let compatible = self.get_plans_for_subscription(subscription_id).await?;
+        if !compatible
+            .plans
+            .iter()
+            .any(|plan| plan.id == Some(new_plan_id))
+        {
+            return Err(CloudError::BadRequest {
+                message: format!(
+                    "Plan {new_plan_id} is not compatible with Essentials subscription {subscription_id}"
+                ),
+            });
+        }
+        let request = FixedSubscriptionUpdateRequest {
+            plan_id: Some(new_plan_id),
+            ..Default::default()
+        };
+        self.update_and_fetch(subscription_id, &request, options)
+            .await
+    }
 }
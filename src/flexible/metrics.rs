@@ -0,0 +1,100 @@
+//! Database metrics for Pro subscriptions
+//!
+//! Redis Cloud exposes database metrics through a Prometheus scrape endpoint
+//! per cluster. It is not part of the REST API: it listens on port 8070 of
+//! the cluster's private (`internal.`) hostname and is only reachable over
+//! private connectivity (VPC peering, Transit Gateway, `PrivateLink`, or PSC).
+//! It does not use the API key and secret; access is controlled by the
+//! network path alone. [`prometheus_scrape_url`] derives the URL from a
+//! database's private endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use redis_cloud::CloudClient;
+//!
+//! # async fn example() -> redis_cloud::Result<()> {
+//! let client = CloudClient::builder()
+//!     .api_key("your-api-key")
+//!     .api_secret("your-api-secret")
+//!     .build()?;
+//!
+//! if let Some(url) = client.metrics().prometheus_url(123, 456).await? {
+//!     println!("scrape {url} from inside the peered VPC");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::databases::DatabaseHandler;
+use crate::{CloudClient, Result};
+
+/// Port the Prometheus endpoint listens on
+pub const PROMETHEUS_PORT: u16 = 8070;
+
+/// Build the Prometheus scrape URL for a database's cluster
+///
+/// Takes the database's private endpoint (e.g.
+/// `redis-12345.internal.c1.us-east-1-1.ec2.cloud.redislabs.com:12345`),
+/// drops the database-specific `redis-<port>.` label and the port, and
+/// returns `https://internal.c1.us-east-1-1.ec2.cloud.redislabs.com:8070`.
+/// Returns `None` if the endpoint is not a private `internal.` hostname.
+///
+/// The URL must be scraped from a network with private connectivity to the
+/// subscription; no API credentials are involved.
+#[must_use]
+pub fn prometheus_scrape_url(private_endpoint: &str) -> Option<String> {
+    let endpoint = private_endpoint
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("redis://")
+        .trim_start_matches("rediss://");
+    let host = endpoint.split([':', '/']).next().unwrap_or_default();
+
+    let host = match host.split_once('.') {
+        Some((first, rest)) if !first.starts_with("internal") => rest,
+        _ => host,
+    };
+    if !host.starts_with("internal.") || host.len() == "internal.".len() {
+        return None;
+    }
+
+    Some(format!("https://{host}:{PROMETHEUS_PORT}"))
+}
+
+/// Handler for database metrics
+pub struct MetricsHandler {
+    databases: DatabaseHandler,
+}
+
+impl MetricsHandler {
+    /// Create a new handler
+    #[must_use]
+    pub fn new(client: CloudClient) -> Self {
+        Self {
+            databases: DatabaseHandler::new(client),
+        }
+    }
+
+    /// Get the Prometheus scrape URL for a database's cluster
+    /// Looks up the database's private endpoint and converts it with
+    /// [`prometheus_scrape_url`]. Returns `None` if the database has no
+    /// private endpoint.
+    ///
+    /// GET /subscriptions/{subscriptionId}/databases/{databaseId}
+    pub async fn prometheus_url(
+        &self,
+        subscription_id: i32,
+        database_id: i32,
+    ) -> Result<Option<String>> {
+        let database = self
+            .databases
+            .get_subscription_database_by_id(subscription_id, database_id)
+            .await?;
+        Ok(database
+            .private_endpoint
+            .as_deref()
+            .and_then(prometheus_scrape_url))
+    }
+}
@@ -4,8 +4,10 @@
 //! as opposed to fixed plans with predetermined resources.
 
 pub mod databases;
+pub mod metrics;
 pub mod subscriptions;
 
 // Re-export handlers for convenience
 pub use databases::DatabaseHandler;
+pub use metrics::MetricsHandler;
 pub use subscriptions::SubscriptionHandler;
@@ -51,8 +51,17 @@
 //! # }
 //! ```
 
-use crate::types::{Link, ProcessorResponse};
-use crate::{CloudClient, Result};
+use crate::account::{AccountHandler, Regions};
+use crate::databases::{Database, DatabaseHandler};
+use crate::query::QueryParams;
+use crate::tasks::{TaskResultExt, TaskWaitOptions, TasksHandler, Updated};
+use crate::types::{
+    CloudProvider, DataPersistence, Link, NameMatches, ProcessorResponse, Protocol, Timestamp,
+};
+use crate::version::{RedisVersionReq, Version};
+use crate::{Bytes, CloudClient, CloudError, Result};
+use chrono::{Duration, NaiveDate, Utc};
+use futures_util::future::try_join;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -62,7 +71,7 @@ use std::collections::HashMap;
 // ============================================================================
 
 /// Subscription update request message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BaseSubscriptionUpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,7 +82,7 @@ pub struct BaseSubscriptionUpdateRequest {
 }
 
 /// Subscription update request message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionUpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -95,13 +104,125 @@ pub struct SubscriptionUpdateRequest {
     pub command_type: Option<String>,
 }
 
+/// Throughput assumed for a database created with
+/// [`SubscriptionCreateRequest::single_region`] when none is given
+pub const DEFAULT_THROUGHPUT_OPS: i64 = 25_000;
+
+impl SubscriptionCreateRequest {
+    /// Request for a single-region subscription holding one database
+    ///
+    /// The subscription is named after the database and uses the provider's
+    /// default networking (a Redis-managed VPC with the default deployment
+    /// CIDR). A database without a throughput setting gets
+    /// [`DEFAULT_THROUGHPUT_OPS`] operations per second.
+    /// Set `payment_method_id` on the result if the account pays by credit card.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use redis_cloud::subscriptions::{SubscriptionCreateRequest, SubscriptionDatabaseSpec};
+    ///
+    /// let request = SubscriptionCreateRequest::single_region(
+    ///     "AWS",
+    ///     "us-east-1",
+    ///     SubscriptionDatabaseSpec {
+    ///         name: "cache".to_string(),
+    ///         dataset_size_in_gb: Some(1.0),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// assert_eq!(request.name.as_deref(), Some("cache"));
+    /// assert_eq!(request.cloud_providers[0].regions[0].region, "us-east-1");
+    /// ```
+    #[must_use]
+    pub fn single_region(
+        provider: impl Into<CloudProvider>,
+        region: impl Into<String>,
+        mut database: SubscriptionDatabaseSpec,
+    ) -> Self {
+        if database.throughput_measurement.is_none() {
+            database.throughput_measurement = Some(DatabaseThroughputSpec {
+                by: "operations-per-second".to_string(),
+                value: DEFAULT_THROUGHPUT_OPS,
+            });
+        }
+
+        Self {
+            name: Some(database.name.clone()),
+            deployment_type: Some("single-region".to_string()),
+            cloud_providers: vec![SubscriptionSpec {
+                provider: Some(provider.into()),
+                cloud_account_id: None,
+                regions: vec![SubscriptionRegionSpec {
+                    region: region.into(),
+                    ..Default::default()
+                }],
+            }],
+            databases: vec![database],
+            ..Default::default()
+        }
+    }
+
+    /// Check the requested regions against the `/regions` catalog
+    ///
+    /// Returns every requested region that is not offered for its provider;
+    /// an empty list means all regions are valid. A missing provider is
+    /// treated as `AWS`, the API default.
+    #[must_use]
+    pub fn unknown_regions(&self, regions: &Regions) -> Vec<UnknownRegion> {
+        self.cloud_providers
+            .iter()
+            .flat_map(|spec| {
+                let provider = spec.provider.as_ref().map_or("AWS", CloudProvider::as_str);
+                spec.regions
+                    .iter()
+                    .filter(move |r| !regions.contains(provider, &r.region))
+                    .map(move |r| UnknownRegion {
+                        provider: provider.to_string(),
+                        region: r.region.clone(),
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Result of [`SubscriptionHandler::create_simple`]
+#[derive(Debug, Clone)]
+pub struct CreatedSubscription {
+    /// ID of the new subscription
+    pub subscription_id: i32,
+    /// ID of the database created with it
+    pub database_id: i32,
+    /// Final state of the creation task
+    pub task: crate::tasks::TaskStateUpdate,
+}
+
+/// A requested region that the provider does not offer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownRegion {
+    /// Cloud provider the region was requested for
+    pub provider: String,
+    /// Requested region name
+    pub region: String,
+}
+
+impl std::fmt::Display for UnknownRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "region '{}' is not available for provider {}; use GET /regions for the supported list",
+            self.region, self.provider
+        )
+    }
+}
+
 /// Cloud provider, region, and networking details.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionSpec {
     /// Optional. Cloud provider. Default: 'AWS'
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider: Option<String>,
+    pub provider: Option<CloudProvider>,
 
     /// Optional. Cloud account identifier. Default: Redis internal cloud account (Cloud Account ID = 1). Use GET /cloud-accounts to list all available cloud accounts. Note: A subscription on Google Cloud can be created only with Redis internal cloud account.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -112,7 +233,7 @@ pub struct SubscriptionSpec {
 }
 
 /// Object representing a customer managed key (CMK), along with the region it is associated to.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerManagedKey {
     /// Required. Resource name of the customer managed key as defined by the cloud provider.
@@ -124,7 +245,7 @@ pub struct CustomerManagedKey {
 }
 
 /// Optional. Expected read and write throughput for this region.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LocalThroughput {
     /// Specify one of the selected cloud provider regions for the subscription.
@@ -141,7 +262,7 @@ pub struct LocalThroughput {
 }
 
 /// List of databases in the subscription with local throughput details. Default: 1000 read and write ops/sec for each database
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CrdbRegionSpec {
     /// Database name.
@@ -152,8 +273,96 @@ pub struct CrdbRegionSpec {
     pub local_throughput_measurement: Option<LocalThroughput>,
 }
 
+pub use crate::types::PersistentStorageEncryptionType;
+
+/// Grace period before a subscription is deleted after its customer managed
+/// key becomes inaccessible
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum DeletionGracePeriod {
+    /// Only send alerts; never delete the subscription
+    #[serde(rename = "alerts-only")]
+    AlertsOnly,
+    /// Delete immediately
+    #[serde(rename = "immediate")]
+    Immediate,
+    /// Delete after 15 minutes
+    #[serde(rename = "15-minutes")]
+    FifteenMinutes,
+    /// Delete after 30 minutes
+    #[serde(rename = "30-minutes")]
+    ThirtyMinutes,
+    /// Delete after 1 hour
+    #[serde(rename = "1-hour")]
+    OneHour,
+    /// Delete after 4 hours
+    #[serde(rename = "4-hours")]
+    FourHours,
+    /// Delete after 8 hours
+    #[serde(rename = "8-hours")]
+    EightHours,
+    /// Delete after 12 hours
+    #[serde(rename = "12-hours")]
+    TwelveHours,
+    /// Delete after 24 hours
+    #[serde(rename = "24-hours")]
+    TwentyFourHours,
+}
+
+impl DeletionGracePeriod {
+    /// The API value for this grace period (e.g., "15-minutes")
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeletionGracePeriod::AlertsOnly => "alerts-only",
+            DeletionGracePeriod::Immediate => "immediate",
+            DeletionGracePeriod::FifteenMinutes => "15-minutes",
+            DeletionGracePeriod::ThirtyMinutes => "30-minutes",
+            DeletionGracePeriod::OneHour => "1-hour",
+            DeletionGracePeriod::FourHours => "4-hours",
+            DeletionGracePeriod::EightHours => "8-hours",
+            DeletionGracePeriod::TwelveHours => "12-hours",
+            DeletionGracePeriod::TwentyFourHours => "24-hours",
+        }
+    }
+
+    /// Time before deletion, or `None` for `AlertsOnly` (never deleted)
+    #[must_use]
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        let minutes = match self {
+            DeletionGracePeriod::AlertsOnly => return None,
+            DeletionGracePeriod::Immediate => 0,
+            DeletionGracePeriod::FifteenMinutes => 15,
+            DeletionGracePeriod::ThirtyMinutes => 30,
+            DeletionGracePeriod::OneHour => 60,
+            DeletionGracePeriod::FourHours => 4 * 60,
+            DeletionGracePeriod::EightHours => 8 * 60,
+            DeletionGracePeriod::TwelveHours => 12 * 60,
+            DeletionGracePeriod::TwentyFourHours => 24 * 60,
+        };
+        Some(std::time::Duration::from_secs(minutes * 60))
+    }
+}
+
+impl std::fmt::Display for DeletionGracePeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for DeletionGracePeriod {
+    type Err = crate::CloudError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        serde_json::from_value(Value::String(s.to_string())).map_err(|_| {
+            crate::CloudError::BadRequest {
+                message: format!("Unknown deletion grace period: {s}"),
+            }
+        })
+    }
+}
+
 /// Subscription update request message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionUpdateCMKRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -164,21 +373,31 @@ pub struct SubscriptionUpdateCMKRequest {
 
     /// Optional. The grace period for deleting the subscription. If not set, will default to immediate deletion grace period.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_grace_period: Option<String>,
+    pub deletion_grace_period: Option<DeletionGracePeriod>,
 
     /// The customer managed keys (CMK) to use for this subscription. If is active-active subscription, must set a key for each region.
     pub customer_managed_keys: Vec<CustomerManagedKey>,
 }
 
 /// `SubscriptionPricings`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubscriptionPricings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pricing: Option<Vec<SubscriptionPricing>>,
 }
 
+impl SubscriptionPricings {
+    /// Total of the pricing lines per currency
+    ///
+    /// See [`Subscription::price_totals`].
+    #[must_use]
+    pub fn price_totals(&self) -> Vec<(String, f64)> {
+        totals_by_currency(self.pricing.iter().flatten())
+    }
+}
+
 /// Optional. Throughput measurement method.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DatabaseThroughputSpec {
     /// Throughput measurement method. Use 'operations-per-second' for all new databases.
     pub by: String,
@@ -188,7 +407,7 @@ pub struct DatabaseThroughputSpec {
 }
 
 /// Optional. Redis advanced capabilities (also known as modules) to be provisioned in the database. Use GET /database-modules to get a list of available advanced capabilities.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DatabaseModuleSpec {
     /// Redis advanced capability name. Use GET /database-modules for a list of available capabilities.
     pub name: String,
@@ -199,7 +418,7 @@ pub struct DatabaseModuleSpec {
 }
 
 /// Update Pro subscription
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CidrAllowlistUpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -218,7 +437,7 @@ pub struct CidrAllowlistUpdateRequest {
 }
 
 /// `SubscriptionMaintenanceWindowsSpec`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SubscriptionMaintenanceWindowsSpec {
     /// Maintenance window mode: either 'manual' or 'automatic'. Must provide 'windows' if manual.
     pub mode: String,
@@ -229,18 +448,20 @@ pub struct SubscriptionMaintenanceWindowsSpec {
 }
 
 /// `MaintenanceWindowSkipStatus`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MaintenanceWindowSkipStatus {
+    /// Number of maintenance window skips still available
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remaining_skips: Option<i32>,
 
+    /// End of the currently skipped window, if a skip is active
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_skip_end: Option<String>,
 }
 
 /// List of active-active subscription regions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveActiveSubscriptionRegions {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -252,7 +473,7 @@ pub struct ActiveActiveSubscriptionRegions {
 }
 
 /// `SubscriptionPricing`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionPricing {
     /// Database name this pricing applies to
@@ -284,11 +505,157 @@ pub struct SubscriptionPricing {
     pub region: Option<String>,
 }
 
-/// Request structure for creating a new Pro subscription
+impl SubscriptionPricing {
+    /// Price of this line: `quantity * price_per_unit`
+    ///
+    /// `None` if either is missing.
+    #[must_use]
+    pub fn line_total(&self) -> Option<f64> {
+        Some(f64::from(self.quantity?) * self.price_per_unit?)
+    }
+}
+
+/// Sum of the priced lines per currency, in first-seen order
+fn totals_by_currency<'a>(
+    lines: impl IntoIterator<Item = &'a SubscriptionPricing>,
+) -> Vec<(String, f64)> {
+    let mut totals: Vec<(String, f64)> = Vec::new();
+    for line in lines {
+        let Some(total) = line.line_total() else {
+            continue;
+        };
+        let currency = line.price_currency.clone().unwrap_or_default();
+        match totals.iter_mut().find(|(c, _)| *c == currency) {
+            Some((_, sum)) => *sum += total,
+            None => totals.push((currency, total)),
+        }
+    }
+    totals
+}
+
+/// What a dry run would do to a planned resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanAction {
+    /// The resource would be provisioned
+    Create,
+    /// The resource exists and would be resized or reconfigured
+    Modify,
+    /// The resource appears in the plan but is not touched by the request
+    Unchanged,
+}
+
+/// One resource in a [`DeploymentPlan`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedResource {
+    /// What the request would do to this resource
+    pub action: PlanAction,
+    /// Resource type, e.g. `"Shards"` or an instance type
+    pub kind: String,
+    /// Extra detail about the resource type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// Database the resource belongs to; `None` for subscription-level resources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i32>,
+    /// Unit of `quantity`, e.g. `"shards"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+/// Read-only deployment plan produced by a dry run
+///
+/// Built by the `plan_*` methods on [`SubscriptionHandler`] and
+/// [`DatabaseHandler`](crate::databases::DatabaseHandler), which send the
+/// request with `dryRun: true` and decode the plan the task resolves to.
+/// Nothing is provisioned.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentPlan {
+    /// Resources in the plan, in the order the API listed them
+    pub resources: Vec<PlannedResource>,
+    /// The raw pricing lines the plan was decoded from
+    pub pricing: Vec<SubscriptionPricing>,
+}
+
+impl DeploymentPlan {
+    /// Decode a plan from dry-run pricing lines
+    ///
+    /// Lines for `target` (or with no database name) get `action`; lines for
+    /// other databases are [`PlanAction::Unchanged`]. With no `target`, every
+    /// line gets `action`.
+    #[must_use]
+    pub fn from_pricing(
+        pricing: Vec<SubscriptionPricing>,
+        action: PlanAction,
+        target: Option<&str>,
+    ) -> Self {
+        let resources = pricing
+            .iter()
+            .map(|line| {
+                let touched = match (target, line.database_name.as_deref()) {
+                    (Some(target), Some(name)) => name == target,
+                    _ => true,
+                };
+                PlannedResource {
+                    action: if touched {
+                        action
+                    } else {
+                        PlanAction::Unchanged
+                    },
+                    kind: line.r#type.clone().unwrap_or_default(),
+                    details: line.type_details.clone(),
+                    database_name: line.database_name.clone(),
+                    region: line.region.clone(),
+                    quantity: line.quantity,
+                    unit: line.quantity_measurement.clone(),
+                }
+            })
+            .collect();
+        Self { resources, pricing }
+    }
+
+    /// Resources the request would create
+    pub fn created(&self) -> impl Iterator<Item = &PlannedResource> {
+        self.with_action(PlanAction::Create)
+    }
+
+    /// Resources the request would modify
+    pub fn modified(&self) -> impl Iterator<Item = &PlannedResource> {
+        self.with_action(PlanAction::Modify)
+    }
+
+    /// Whether applying the request would create or modify anything
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.resources
+            .iter()
+            .any(|resource| resource.action != PlanAction::Unchanged)
+    }
+
+    /// Total of the plan's pricing lines per currency
+    ///
+    /// See [`Subscription::price_totals`].
+    #[must_use]
+    pub fn price_totals(&self) -> Vec<(String, f64)> {
+        totals_by_currency(&self.pricing)
+    }
+
+    fn with_action(&self, action: PlanAction) -> impl Iterator<Item = &PlannedResource> {
+        self.resources
+            .iter()
+            .filter(move |resource| resource.action == action)
+    }
+}
 ///
 /// Defines configuration for flexible subscriptions including cloud providers,
 /// regions, deployment type, and initial database specifications.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionCreateRequest {
     /// Optional. New subscription name.
@@ -317,7 +684,7 @@ pub struct SubscriptionCreateRequest {
 
     /// Optional. Persistent storage encryption secures data-at-rest for database persistence. You can use 'cloud-provider-managed-key' or 'customer-managed-key'.  Default: 'cloud-provider-managed-key'
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub persistent_storage_encryption_type: Option<String>,
+    pub persistent_storage_encryption_type: Option<PersistentStorageEncryptionType>,
 
     /// Cloud provider, region, and networking details.
     pub cloud_providers: Vec<SubscriptionSpec>,
@@ -334,7 +701,7 @@ pub struct SubscriptionCreateRequest {
 }
 
 /// Configuration regarding customer managed persistent storage encryption
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerManagedKeyAccessDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -356,15 +723,35 @@ pub struct CustomerManagedKeyAccessDetails {
     pub deletion_grace_period_options: Option<Vec<String>>,
 }
 
+impl CustomerManagedKeyAccessDetails {
+    /// Deletion grace periods offered for this subscription, in API order
+    ///
+    /// Values this version of the client does not recognize are skipped.
+    #[must_use]
+    pub fn grace_period_options(&self) -> Vec<DeletionGracePeriod> {
+        self.deletion_grace_period_options
+            .iter()
+            .flatten()
+            .filter_map(|option| option.parse().ok())
+            .collect()
+    }
+
+    /// Returns true if `period` is one of the offered grace periods
+    #[must_use]
+    pub fn supports_grace_period(&self, period: DeletionGracePeriod) -> bool {
+        self.grace_period_options().contains(&period)
+    }
+}
+
 /// One or more database specification(s) to create in this subscription.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionDatabaseSpec {
     /// Name of the database. Database name is limited to 40 characters or less and must include only letters, digits, and hyphens ('-'). It must start with a letter and end with a letter or digit.
     pub name: String,
 
     /// Optional. Database protocol. Only set to 'memcached' if you have a legacy application. Default: 'redis'
-    pub protocol: String,
+    pub protocol: Protocol,
 
     /// Optional. Total memory in GB, including replication and other overhead. You cannot set both datasetSizeInGb and totalMemoryInGb.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -375,12 +762,16 @@ pub struct SubscriptionDatabaseSpec {
     pub dataset_size_in_gb: Option<f64>,
 
     /// Optional. Support Redis [OSS Cluster API](https://redis.io/docs/latest/operate/rc/databases/configuration/clustering/#oss-cluster-api). Default: 'false'
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "supportOSSClusterApi",
+        alias = "supportOssClusterApi",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub support_oss_cluster_api: Option<bool>,
 
     /// Optional. Type and rate of data persistence in persistent storage. Default: 'none'
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_persistence: Option<String>,
+    pub data_persistence: Option<DataPersistence>,
 
     /// Optional. Databases replication. Default: 'true'
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -423,11 +814,15 @@ pub struct SubscriptionDatabaseSpec {
 }
 
 /// Optional. Cloud networking details, per region. Required if creating an Active-Active subscription.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionRegionNetworkingSpec {
     /// Optional. Deployment CIDR mask. Must be a valid CIDR format with a range of 256 IP addresses. Default for single-region subscriptions: If using Redis internal cloud account, 192.168.0.0/24
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "deploymentCIDR",
+        alias = "deploymentCidr",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deployment_cidr: Option<String>,
 
     /// Optional. Enter a VPC identifier that exists in the hosted AWS account. Creates a new VPC if not set. VPC Identifier must be in a valid format (for example: 'vpc-0125be68a4625884ad') and must exist within the hosting account.
@@ -444,7 +839,7 @@ pub struct SubscriptionRegionNetworkingSpec {
 }
 
 /// `RedisVersion`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RedisVersion {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -460,8 +855,79 @@ pub struct RedisVersion {
     pub is_default: Option<bool>,
 }
 
+impl RedisVersion {
+    /// Parsed [`version`](Self::version), if present and well-formed
+    #[must_use]
+    pub fn parsed(&self) -> Option<Version> {
+        self.version.as_deref()?.parse().ok()
+    }
+
+    /// End-of-life date, if reported and well-formed
+    ///
+    /// Accepts a plain date (`2026-02-28`) or an RFC 3339 timestamp.
+    #[must_use]
+    pub fn eol(&self) -> Option<NaiveDate> {
+        let eol_date = self.eol_date.as_deref()?;
+        NaiveDate::parse_from_str(eol_date.get(..10)?, "%Y-%m-%d").ok()
+    }
+
+    /// Lifecycle stage of this version on `today`
+    ///
+    /// A version is near end of life once its EOL date is within `warn_within`.
+    #[must_use]
+    pub fn lifecycle(&self, today: NaiveDate, warn_within: Duration) -> VersionLifecycle {
+        match self.eol() {
+            None => VersionLifecycle::Supported,
+            Some(eol) if eol <= today => VersionLifecycle::Eol,
+            Some(eol) if eol - today <= warn_within => VersionLifecycle::NearEol,
+            Some(_) => VersionLifecycle::Supported,
+        }
+    }
+}
+
+/// Support lifecycle stage of a Redis version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VersionLifecycle {
+    /// No end of life reported, or it is further away than the warning window
+    Supported,
+    /// End of life is within the warning window
+    NearEol,
+    /// End of life date has passed
+    Eol,
+    /// The database's version is not in the list of available versions
+    Unknown,
+}
+
+/// A database and the lifecycle of the Redis version it runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseVersionStatus {
+    /// Subscription the database belongs to
+    pub subscription_id: i32,
+    /// Database ID
+    pub database_id: i32,
+    /// Database name
+    pub database_name: Option<String>,
+    /// Redis version the database runs
+    pub redis_version: Option<String>,
+    /// End-of-life date of that version, if known
+    pub eol: Option<NaiveDate>,
+    /// Lifecycle stage of that version
+    pub lifecycle: VersionLifecycle,
+}
+
+impl DatabaseVersionStatus {
+    /// Returns true if the database runs an EOL or near-EOL version
+    #[must_use]
+    pub fn needs_upgrade(&self) -> bool {
+        matches!(
+            self.lifecycle,
+            VersionLifecycle::Eol | VersionLifecycle::NearEol
+        )
+    }
+}
+
 /// `MaintenanceWindow`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MaintenanceWindow {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -475,12 +941,12 @@ pub struct MaintenanceWindow {
 }
 
 /// Cloud provider details for a subscription
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CloudDetail {
     /// Cloud provider (e.g., "AWS", "GCP", "Azure")
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider: Option<String>,
+    pub provider: Option<CloudProvider>,
 
     /// Cloud account ID (Redis Cloud internal or BYOA)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -500,7 +966,7 @@ pub struct CloudDetail {
 }
 
 /// Region details in a subscription response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionRegion {
     /// Region name (e.g., "us-east-1")
@@ -521,11 +987,15 @@ pub struct SubscriptionRegion {
 }
 
 /// Networking configuration in a subscription region
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionNetworking {
     /// Deployment CIDR
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "deploymentCIDR",
+        alias = "deploymentCidr",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deployment_cidr: Option<String>,
 
     /// VPC ID
@@ -538,7 +1008,7 @@ pub struct SubscriptionNetworking {
 }
 
 /// `RedisLabs` Subscription information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 /// Subscription
 ///
@@ -575,7 +1045,7 @@ pub struct Subscription {
 
     /// Persistent storage encryption type
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub persistent_storage_encryption_type: Option<String>,
+    pub persistent_storage_encryption_type: Option<PersistentStorageEncryptionType>,
 
     /// Deployment type: "single-region" or "active-active"
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -615,15 +1085,47 @@ pub struct Subscription {
 
     /// Timestamp when subscription was created
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<String>,
+    pub created_timestamp: Option<Timestamp>,
 
     /// HATEOAS links for API navigation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
 }
 
+impl Subscription {
+    /// Deletion grace period as a typed value, if set and recognized
+    #[must_use]
+    pub fn grace_period(&self) -> Option<DeletionGracePeriod> {
+        self.deletion_grace_period.as_deref()?.parse().ok()
+    }
+
+    /// Returns true if persistent storage is encrypted with a customer managed key
+    #[must_use]
+    pub fn uses_customer_managed_key(&self) -> bool {
+        self.persistent_storage_encryption_type
+            == Some(PersistentStorageEncryptionType::CustomerManagedKey)
+    }
+
+    /// Regions of every cloud provider the subscription is deployed on
+    pub fn regions(&self) -> impl Iterator<Item = &SubscriptionRegion> {
+        self.cloud_details
+            .iter()
+            .flatten()
+            .flat_map(|detail| detail.regions.iter().flatten())
+    }
+
+    /// Total of the [`pricing`](Self::pricing) lines per currency
+    ///
+    /// Lines without a quantity or unit price are skipped. Prices keep the
+    /// period reported by the API (usually hourly).
+    #[must_use]
+    pub fn price_totals(&self) -> Vec<(String, f64)> {
+        totals_by_currency(self.pricing.iter().flatten())
+    }
+}
+
 /// Maintenance window timeframes if mode is set to 'manual'. Up to 7 maintenance windows can be provided.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MaintenanceWindowSpec {
     /// Starting hour of the maintenance window. Can be between '0' (12 AM in the deployment region's local time) and '23' (11 PM in the deployment region's local time).
@@ -639,7 +1141,7 @@ pub struct MaintenanceWindowSpec {
 /// `RedisLabs` list of subscriptions in current account
 ///
 /// Response from GET /subscriptions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountSubscriptions {
     /// Account ID
@@ -656,7 +1158,7 @@ pub struct AccountSubscriptions {
 }
 
 /// Active active region creation request message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveActiveRegionCreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -671,6 +1173,7 @@ pub struct ActiveActiveRegionCreateRequest {
     pub vpc_id: Option<String>,
 
     /// Deployment CIDR mask. Must be a valid CIDR format with a range of 256 IP addresses.
+    #[serde(rename = "deploymentCIDR", alias = "deploymentCidr")]
     pub deployment_cidr: String,
 
     /// Optional. When 'false': Creates a deployment plan and deploys it, creating any resources required by the plan. When 'true': creates a read-only deployment plan, and does not create any resources. Default: 'false'
@@ -694,15 +1197,86 @@ pub struct ActiveActiveRegionCreateRequest {
 }
 
 /// `RedisVersions`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RedisVersions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redis_versions: Option<Vec<RedisVersion>>,
 }
 
+impl RedisVersions {
+    /// Versions satisfying `requirement`, in API order
+    ///
+    /// Versions that cannot be parsed are skipped.
+    pub fn matching<'a>(
+        &'a self,
+        requirement: &'a RedisVersionReq,
+    ) -> impl Iterator<Item = &'a RedisVersion> {
+        self.redis_versions
+            .iter()
+            .flatten()
+            .filter(|version| version.parsed().is_some_and(|v| requirement.matches(&v)))
+    }
+
+    /// The version new databases get by default
+    #[must_use]
+    pub fn default_version(&self) -> Option<&RedisVersion> {
+        self.redis_versions
+            .iter()
+            .flatten()
+            .find(|version| version.is_default == Some(true))
+    }
+
+    /// The listed version a database running `version` belongs to
+    ///
+    /// Versions are listed by major and minor (`7.2`) while databases report
+    /// a full version (`7.2.4`), so only major and minor are compared.
+    #[must_use]
+    pub fn find(&self, version: &str) -> Option<&RedisVersion> {
+        let version: Version = version.parse().ok()?;
+        self.redis_versions.iter().flatten().find(|listed| {
+            listed
+                .parsed()
+                .is_some_and(|v| (v.major(), v.minor()) == (version.major(), version.minor()))
+        })
+    }
+
+    /// Lifecycle of the Redis version each database runs on `today`
+    ///
+    /// Databases whose version is not listed are reported as
+    /// [`VersionLifecycle::Unknown`].
+    #[must_use]
+    pub fn database_lifecycle(
+        &self,
+        subscription_id: i32,
+        databases: &[Database],
+        today: NaiveDate,
+        warn_within: Duration,
+    ) -> Vec<DatabaseVersionStatus> {
+        databases
+            .iter()
+            .map(|database| {
+                let listed = database
+                    .redis_version
+                    .as_deref()
+                    .and_then(|version| self.find(version));
+                DatabaseVersionStatus {
+                    subscription_id,
+                    database_id: database.database_id,
+                    database_name: database.name.clone(),
+                    redis_version: database.redis_version.clone(),
+                    eol: listed.and_then(RedisVersion::eol),
+                    lifecycle: listed.map_or(VersionLifecycle::Unknown, |listed| {
+                        listed.lifecycle(today, warn_within)
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
 /// Active active region deletion request message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveActiveRegionDeleteRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -720,8 +1294,30 @@ pub struct ActiveActiveRegionDeleteRequest {
     pub command_type: Option<String>,
 }
 
+impl ActiveActiveRegionDeleteRequest {
+    /// Request deletion of the named regions
+    #[must_use]
+    pub fn new<I, S>(regions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            regions: Some(
+                regions
+                    .into_iter()
+                    .map(|region| ActiveActiveRegionToDelete {
+                        region: Some(region.into()),
+                    })
+                    .collect(),
+            ),
+            ..Self::default()
+        }
+    }
+}
+
 /// The names of the regions to delete.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActiveActiveRegionToDelete {
     /// Name of the cloud provider region to delete.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -729,7 +1325,7 @@ pub struct ActiveActiveRegionToDelete {
 }
 
 /// `TaskStateUpdate`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskStateUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -745,7 +1341,7 @@ pub struct TaskStateUpdate {
     pub description: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub response: Option<ProcessorResponse>,
@@ -756,7 +1352,7 @@ pub struct TaskStateUpdate {
 }
 
 /// The cloud provider region or list of regions (Active-Active only) and networking details.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionRegionSpec {
     /// Deployment region as defined by the cloud provider.
@@ -775,7 +1371,7 @@ pub struct SubscriptionRegionSpec {
 }
 
 /// `SubscriptionMaintenanceWindows`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscriptionMaintenanceWindows {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -840,6 +1436,24 @@ impl SubscriptionHandler {
         self.client.get("/subscriptions").await
     }
 
+    /// Find Pro subscriptions by name
+    /// Returns the subscriptions named `name` exactly, plus those whose name
+    /// starts with `name`.
+    ///
+    /// GET /subscriptions
+    pub async fn find_by_name(&self, name: &str) -> Result<NameMatches<Subscription>> {
+        let subscriptions = self
+            .get_all_subscriptions()
+            .await?
+            .subscriptions
+            .unwrap_or_default();
+        Ok(NameMatches::from_items(
+            name,
+            subscriptions,
+            |subscription| subscription.name.as_deref(),
+        ))
+    }
+
     /// Create Pro subscription
     /// Creates a new Redis Cloud Pro subscription.
     ///
@@ -851,25 +1465,201 @@ impl SubscriptionHandler {
         self.client.post("/subscriptions", request).await
     }
 
+    /// Plan Pro subscription creation
+    /// Sends the request with `dryRun: true`, waits for the dry-run task, and
+    /// decodes the deployment plan it produces. Every resource in the plan is
+    /// [`PlanAction::Create`]. Nothing is provisioned.
+    ///
+    /// POST /subscriptions
+    pub async fn plan_create(
+        &self,
+        request: &SubscriptionCreateRequest,
+        options: &TaskWaitOptions,
+    ) -> Result<DeploymentPlan> {
+        let request = SubscriptionCreateRequest {
+            dry_run: Some(true),
+            ..request.clone()
+        };
+        let task = self.create_subscription(&request).await?;
+        let pricing = TasksHandler::new(self.client.clone())
+            .wait_for_resource::<SubscriptionPricings>(task.task_id, options)
+            .await?
+            .pricing
+            .unwrap_or_default();
+        Ok(DeploymentPlan::from_pricing(
+            pricing,
+            PlanAction::Create,
+            None,
+        ))
+    }
+
+    /// Create Pro subscription from a pre-serialized request
+    /// Like [`create_subscription`](Self::create_subscription), with `body`
+    /// holding the JSON of a [`SubscriptionCreateRequest`]. The bytes are sent
+    /// as-is, which keeps peak memory down for subscriptions with many
+    /// databases.
+    ///
+    /// POST /subscriptions
+    pub async fn create_subscription_from_value(
+        &self,
+        body: impl Into<Bytes>,
+    ) -> Result<TaskStateUpdate> {
+        self.client.post_bytes("/subscriptions", body.into()).await
+    }
+
+    /// Create a single-region subscription with one database and wait for it
+    /// Builds the request with [`SubscriptionCreateRequest::single_region`],
+    /// waits for the creation task, and looks up the new database by name.
+    /// A failed task is reported as [`CloudError::TaskFailed`].
+    ///
+    /// POST /subscriptions, GET /tasks/{taskId}, GET /subscriptions/{subscriptionId}/databases
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    /// use redis_cloud::subscriptions::SubscriptionDatabaseSpec;
+    /// use redis_cloud::tasks::TaskWaitOptions;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let database = SubscriptionDatabaseSpec {
+    ///     name: "cache".to_string(),
+    ///     dataset_size_in_gb: Some(1.0),
+    ///     ..Default::default()
+    /// };
+    /// let created = client
+    ///     .subscriptions()
+    ///     .create_simple("AWS", "us-east-1", database, &TaskWaitOptions::default())
+    ///     .await?;
+    /// println!("{}/{}", created.subscription_id, created.database_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_simple(
+        &self,
+        provider: &str,
+        region: &str,
+        database: SubscriptionDatabaseSpec,
+        options: &TaskWaitOptions,
+    ) -> Result<CreatedSubscription> {
+        let name = database.name.clone();
+        let request = SubscriptionCreateRequest::single_region(provider, region, database);
+        let task = self.create_subscription(&request).await?;
+
+        let task_id = task.task_id.ok_or_else(|| CloudError::UnexpectedResponse {
+            message: "Response did not include a task ID".to_string(),
+        })?;
+        let task = TasksHandler::new(self.client.clone())
+            .wait_for_task(&task_id, options)
+            .await?
+            .into_result()?;
+        let subscription_id = task
+            .resource_id()
+            .ok_or_else(|| CloudError::UnexpectedResponse {
+                message: format!("Task {task_id} did not report the new subscription ID"),
+            })?;
+
+        let database_id = DatabaseHandler::new(self.client.clone())
+            .find_by_name(subscription_id, &name)
+            .await?
+            .unique()
+            .map(|database| database.database_id)
+            .ok_or_else(|| CloudError::NotFound {
+                message: format!("Database '{name}' not found in subscription {subscription_id}"),
+            })?;
+
+        Ok(CreatedSubscription {
+            subscription_id,
+            database_id,
+            task,
+        })
+    }
+
+    /// Create Pro subscription after validating its regions
+    /// Fetches the `/regions` catalog and rejects the request with
+    /// `CloudError::BadRequest` if any requested region is not offered by its
+    /// provider, before anything is submitted.
+    ///
+    /// GET /regions, then POST /subscriptions
+    pub async fn create_subscription_validated(
+        &self,
+        request: &SubscriptionCreateRequest,
+    ) -> Result<TaskStateUpdate> {
+        let regions = AccountHandler::new(self.client.clone())
+            .get_supported_regions(None)
+            .await?;
+        let unknown = request.unknown_regions(&regions);
+        if !unknown.is_empty() {
+            let details: Vec<String> = unknown.iter().map(ToString::to_string).collect();
+            return Err(CloudError::BadRequest {
+                message: format!(
+                    "Subscription '{}' requests unsupported regions: {}",
+                    request.name.as_deref().unwrap_or_default(),
+                    details.join("; ")
+                ),
+            });
+        }
+        self.create_subscription(request).await
+    }
+
     /// Get available Redis database versions
     /// Gets a list of all available Redis database versions for Pro subscriptions.
     ///
     /// GET /subscriptions/redis-versions
     pub async fn get_redis_versions(&self, subscription_id: Option<i32>) -> Result<RedisVersions> {
-        let mut query = Vec::new();
-        if let Some(v) = subscription_id {
-            query.push(format!("subscriptionId={v}"));
-        }
-        let query_string = if query.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", query.join("&"))
-        };
+        let query = QueryParams::new().push_opt("subscriptionId", subscription_id);
         self.client
-            .get(&format!("/subscriptions/redis-versions{query_string}"))
+            .get(&format!("/subscriptions/redis-versions{query}"))
             .await
     }
 
+    /// Get the default Redis version for new databases
+    ///
+    /// GET /subscriptions/redis-versions
+    pub async fn get_default_redis_version(
+        &self,
+        subscription_id: Option<i32>,
+    ) -> Result<Option<RedisVersion>> {
+        Ok(self
+            .get_redis_versions(subscription_id)
+            .await?
+            .default_version()
+            .cloned())
+    }
+
+    /// Report the Redis version lifecycle of every database in a subscription
+    ///
+    /// Cross-references the subscription's available versions with its
+    /// databases as of today (UTC). Versions whose end of life is within
+    /// `warn_within` are reported as [`VersionLifecycle::NearEol`]; filter
+    /// with [`DatabaseVersionStatus::needs_upgrade`] to plan an upgrade
+    /// campaign.
+    ///
+    /// GET /subscriptions/redis-versions, GET /subscriptions/{subscriptionId}/databases
+    pub async fn redis_version_lifecycle(
+        &self,
+        subscription_id: i32,
+        warn_within: Duration,
+    ) -> Result<Vec<DatabaseVersionStatus>> {
+        let databases = DatabaseHandler::new(self.client.clone());
+        let (versions, databases) = try_join(
+            self.get_redis_versions(Some(subscription_id)),
+            databases.get_all_databases(subscription_id),
+        )
+        .await?;
+        Ok(versions.database_lifecycle(
+            subscription_id,
+            &databases,
+            Utc::now().date_naive(),
+            warn_within,
+        ))
+    }
+
     /// Delete Pro subscription
     /// Delete the specified Pro subscription. All databases in the subscription must be deleted before deleting it.
     ///
@@ -927,6 +1717,57 @@ impl SubscriptionHandler {
             .await
     }
 
+    /// Update Pro subscription and wait for the result
+    /// Updates the subscription, waits for the update task to finish, and
+    /// re-fetches the subscription so the caller gets both in one call.
+    ///
+    /// PUT /subscriptions/{subscriptionId}
+    pub async fn update_subscription_and_fetch(
+        &self,
+        subscription_id: i32,
+        request: &BaseSubscriptionUpdateRequest,
+        options: &TaskWaitOptions,
+    ) -> Result<Updated<Subscription>> {
+        let task = self.update_subscription(subscription_id, request).await?;
+        TasksHandler::new(self.client.clone())
+            .wait_and_fetch(task.task_id, options, || {
+                self.get_subscription_by_id(subscription_id)
+            })
+            .await
+    }
+
+    /// Update Pro subscription customer managed keys
+    /// Sets the customer managed keys (CMK) used for persistent storage
+    /// encryption, e.g. to rotate to a new key. Active-Active subscriptions
+    /// need one key per region.
+    ///
+    /// PUT /subscriptions/{subscriptionId}/cmks
+    pub async fn update_customer_managed_keys(
+        &self,
+        subscription_id: i32,
+        request: &SubscriptionUpdateCMKRequest,
+    ) -> Result<TaskStateUpdate> {
+        self.client
+            .put(&format!("/subscriptions/{subscription_id}/cmks"), request)
+            .await
+    }
+
+    /// Get Pro subscription customer managed key access details
+    /// Returns the service account, IAM role, and key policy Redis Cloud
+    /// needs to be granted on the customer managed key. `None` if the
+    /// subscription does not report any (e.g. it uses provider-managed keys).
+    ///
+    /// GET /subscriptions/{subscriptionId}
+    pub async fn get_customer_managed_key_access_details(
+        &self,
+        subscription_id: i32,
+    ) -> Result<Option<CustomerManagedKeyAccessDetails>> {
+        Ok(self
+            .get_subscription_by_id(subscription_id)
+            .await?
+            .customer_managed_key_access_details)
+    }
+
     /// Get Pro subscription CIDR allowlist
     /// (Self-hosted AWS subscriptions only) Gets a Pro subscription's CIDR allowlist.
     ///
@@ -983,6 +1824,35 @@ impl SubscriptionHandler {
             .await
     }
 
+    /// Skip the next Pro subscription maintenance window
+    /// Postpones maintenance scheduled for the next window. The number of
+    /// skips left is reported in `skip_status` of
+    /// [`get_subscription_maintenance_windows`](Self::get_subscription_maintenance_windows).
+    ///
+    /// POST /subscriptions/{subscriptionId}/maintenance-windows/skip
+    pub async fn skip_maintenance_window(&self, subscription_id: i32) -> Result<TaskStateUpdate> {
+        self.client
+            .post(
+                &format!("/subscriptions/{subscription_id}/maintenance-windows/skip"),
+                &serde_json::json!({}),
+            )
+            .await
+    }
+
+    /// Cancel a Pro subscription maintenance window skip
+    /// Restores the next maintenance window after it was skipped with
+    /// [`skip_maintenance_window`](Self::skip_maintenance_window).
+    ///
+    /// POST /subscriptions/{subscriptionId}/maintenance-windows/unskip
+    pub async fn unskip_maintenance_window(&self, subscription_id: i32) -> Result<TaskStateUpdate> {
+        self.client
+            .post(
+                &format!("/subscriptions/{subscription_id}/maintenance-windows/unskip"),
+                &serde_json::json!({}),
+            )
+            .await
+    }
+
     /// Get Pro subscription pricing
     /// Gets pricing details for the specified Pro subscription.
     ///
@@ -1005,13 +1875,10 @@ impl SubscriptionHandler {
         subscription_id: i32,
         request: &ActiveActiveRegionDeleteRequest,
     ) -> Result<TaskStateUpdate> {
-        // TODO: DELETE with body not yet supported by client
-        let _ = request; // Suppress unused variable warning
-        let response = self
-            .client
-            .delete_raw(&format!("/subscriptions/{subscription_id}/regions"))
-            .await?;
-        serde_json::from_value(response).map_err(Into::into)
+        let body = serde_json::to_value(request)?;
+        self.client
+            .delete_with_body(&format!("/subscriptions/{subscription_id}/regions"), body)
+            .await
     }
 
     /// Get regions in an Active-Active subscription
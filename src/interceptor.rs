@@ -0,0 +1,90 @@
+//! Request interception hooks
+//!
+//! Interceptors observe and modify every HTTP request the client sends,
+//! including raw helpers such as `get_raw` and `delete_raw`, and observe every
+//! response before it is processed. Typical uses are audit logging, metrics,
+//! and injecting correlation IDs.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use redis_cloud::{CloudClient, RequestInterceptor, Result};
+//!
+//! struct CorrelationId(String);
+//!
+//! impl RequestInterceptor for CorrelationId {
+//!     fn before_request(&self, request: &mut reqwest::Request) -> Result<()> {
+//!         request.headers_mut().insert(
+//!             "x-correlation-id",
+//!             reqwest::header::HeaderValue::from_str(&self.0).unwrap(),
+//!         );
+//!         Ok(())
+//!     }
+//!
+//!     fn after_response(&self, method: &reqwest::Method, response: &reqwest::Response) {
+//!         println!("{} {} -> {}", method, response.url(), response.status());
+//!     }
+//! }
+//!
+//! let client = CloudClient::builder()
+//!     .api_key("your-api-key")
+//!     .api_secret("your-api-secret")
+//!     .interceptor(CorrelationId("abc-123".to_string()))
+//!     .build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::Result;
+use std::fmt;
+use std::sync::Arc;
+
+/// Hooks that run around every HTTP request made by `CloudClient`
+///
+/// Both hooks have no-op defaults, so implementors only override what they
+/// need. Interceptors run in registration order and are invoked once per
+/// attempt, so a request retried because the resource was busy is seen by
+/// the interceptor each time it is sent.
+pub trait RequestInterceptor: Send + Sync {
+    /// Called before the request is sent
+    ///
+    /// The request can be modified in place (headers, URL, body). Returning
+    /// an error aborts the request and surfaces the error to the caller.
+    fn before_request(&self, request: &mut reqwest::Request) -> Result<()> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// Called after a response is received, before its body is read
+    fn after_response(&self, method: &reqwest::Method, response: &reqwest::Response) {
+        let _ = (method, response);
+    }
+}
+
+/// Ordered list of registered interceptors
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn RequestInterceptor>>);
+
+impl Interceptors {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn RequestInterceptor>) {
+        self.0.push(interceptor);
+    }
+
+    pub(crate) fn before_request(&self, request: &mut reqwest::Request) -> Result<()> {
+        for interceptor in &self.0 {
+            interceptor.before_request(request)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn after_response(&self, method: &reqwest::Method, response: &reqwest::Response) {
+        for interceptor in &self.0 {
+            interceptor.after_response(method, response);
+        }
+    }
+}
+
+impl fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Interceptors({})", self.0.len())
+    }
+}
@@ -5,14 +5,14 @@
 //!
 //! A comprehensive Rust client for the Redis Cloud REST API, providing full access to
 //! subscription management, database operations, billing, monitoring, and advanced features
-//! like VPC peering, SSO/SAML, and Private Service Connect.
+//! like VPC peering, Transit Gateway, and Private Service Connect.
 //!
 //! ## Features
 //!
 //! - **Subscription Management**: Create, update, delete subscriptions across AWS, GCP, Azure
 //! - **Database Operations**: Full CRUD operations, backups, imports, metrics
 //! - **Advanced Networking**: VPC peering, Transit Gateway, Private Service Connect
-//! - **Security & Access**: ACLs, SSO/SAML integration, API key management
+//! - **Security & Access**: ACLs, account users and roles, API key management
 //! - **Monitoring & Billing**: Comprehensive metrics, logs, billing and payment management
 //! - **Enterprise Features**: Active-Active databases (CRDB), fixed/essentials plans
 //!
@@ -38,6 +38,13 @@
 //! }
 //! ```
 //!
+//! Applications touching many API areas can glob-import
+//! [`prelude`] instead of listing each handler:
+//!
+//! ```rust
+//! use redis_cloud::prelude::*;
+//! ```
+//!
 //! ## Core Usage Patterns
 //!
 //! ### Client Creation
@@ -178,10 +185,13 @@
 //! # }
 //! ```
 //!
-//! #### SSO/SAML Management
+//! #### SSO Adoption
+//! SAML single sign-on is configured in the Redis Cloud console; the public
+//! API has no SSO or SAML endpoints. What the API does report is how each
+//! user signs in, which is enough to audit SSO adoption:
+//!
 //! ```rust,no_run
-//! use redis_cloud::{CloudClient, AccountHandler};
-//! use serde_json::json;
+//! use redis_cloud::{CloudClient, UserHandler};
 //!
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -190,14 +200,10 @@
 //!     .api_secret("secret")
 //!     .build()?;
 //!
-//! let sso_handler = AccountHandler::new(client.clone());
-//!
-//! // Configure SSO using raw API
-//! let sso_config = json!({
-//!     "enabled": true,
-//!     "auto_provision": true
-//! });
-//! let config = client.put_raw("/sso", sso_config).await?;
+//! let summary = UserHandler::new(client).get_security_summary().await?;
+//! for user in &summary.local_users {
+//!     println!("{:?} still signs in with a password", user.email);
+//! }
 //! # Ok(())
 //! # }
 //! ```
@@ -266,6 +272,20 @@
 //! | [`CloudAccountHandler`] | Cloud providers | AWS, GCP, Azure account integration |
 //! | [`TaskHandler`] | Async operations | track long-running operations |
 //!
+//! ## Async Runtimes
+//!
+//! By default the client uses Tokio for its timers (`runtime-tokio` feature).
+//! To embed it under async-std or smol, disable default features; without
+//! `runtime-tokio` the timers use `futures-timer`:
+//!
+//! ```toml
+//! redis-cloud = { version = "0.9", default-features = false }
+//! ```
+//!
+//! The HTTP transport is still reqwest, whose connection handling needs a
+//! Tokio reactor, so wrap calls with a compatibility layer such as
+//! `async_compat::Compat` when running on another executor.
+//!
 //! ## Authentication
 //!
 //! Redis Cloud uses API key authentication with two required headers:
@@ -279,22 +299,50 @@
 //! - `REDIS_CLOUD_API_SECRET`
 //! - Optional: set a custom base URL via the builder for non‑prod/test environments (defaults to `https://api.redislabs.com/v1`).
 
+pub mod cache;
 pub mod client;
+pub mod clock;
 pub mod error;
+pub mod interceptor;
+pub mod limiter;
+pub mod prelude;
+mod query;
+mod runtime;
+pub mod version;
 
 #[cfg(test)]
 mod lib_tests;
 
 // Re-export client types
-pub use client::{CloudClient, CloudClientBuilder};
+pub use client::{
+    CloudClient, CloudClientBuilder, CredentialCheck, DownloadProgress, RateLimitBackoff,
+    RequestOptions,
+};
+
+// Re-export the buffer type taken by the pre-serialized body variants
+// (`CloudClient::post_bytes`, `*_from_value` handler methods)
+pub use bytes::Bytes;
 
 // Re-export error types
-pub use error::{CloudError, Result};
+pub use error::{ApiErrorBody, CloudError, ErrorKind, Result};
+
+// Re-export request interception hooks
+pub use interceptor::RequestInterceptor;
+
+// Re-export concurrency and rate limiter metrics
+pub use limiter::{ConcurrencyStats, LimitsSnapshot, RateLimitStats};
+
+// Re-export Redis version comparison
+pub use version::RedisVersionReq;
 
 // Re-export Tower integration when feature is enabled
 #[cfg(feature = "tower-integration")]
 pub use client::tower_support;
 
+// Blocking client facade - only available with blocking feature
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 // Test support module - only available with test-support feature
 #[cfg(feature = "test-support")]
 pub mod testing;
@@ -308,15 +356,20 @@ pub mod acl;
 pub mod cloud_accounts;
 pub mod connectivity;
 pub mod cost_report;
+pub mod dashboard;
 pub mod fixed;
 pub mod flexible;
+pub mod tag_inventory;
 pub mod tasks;
 pub mod users;
 
 // Backward compatibility module aliases
+#[cfg(not(feature = "no-deprecated"))]
 pub use fixed::databases as fixed_databases;
+#[cfg(not(feature = "no-deprecated"))]
 pub use fixed::subscriptions as fixed_subscriptions;
 pub use flexible::databases;
+pub use flexible::metrics;
 pub use flexible::subscriptions;
 
 // Re-export handlers with standard naming
@@ -332,23 +385,30 @@ pub use connectivity::vpc_peering::VpcPeeringHandler;
 // Connectivity types
 pub use connectivity::{PrincipalType, PrivateLinkAddPrincipalRequest, PrivateLinkCreateRequest};
 // Legacy connectivity export for backward compatibility
-pub use connectivity::ConnectivityHandler;
+pub use connectivity::{ConnectivityHandler, ConnectivityStatus};
 
 // Fixed plan handlers
 pub use fixed::databases::FixedDatabaseHandler;
 pub use fixed::subscriptions::FixedSubscriptionHandler;
 // Legacy exports for backward compatibility
+#[cfg(not(feature = "no-deprecated"))]
 pub use fixed::databases::FixedDatabaseHandler as FixedDatabasesHandler;
+#[cfg(not(feature = "no-deprecated"))]
 pub use fixed::subscriptions::FixedSubscriptionHandler as FixedSubscriptionsHandler;
 
 // Flexible plan handlers (pay-as-you-go)
 pub use flexible::databases::DatabaseHandler;
+pub use flexible::metrics::MetricsHandler;
 pub use flexible::subscriptions::SubscriptionHandler;
 // Legacy exports for backward compatibility
+#[cfg(not(feature = "no-deprecated"))]
 pub use flexible::databases::DatabaseHandler as DatabasesHandler;
+#[cfg(not(feature = "no-deprecated"))]
 pub use flexible::subscriptions::SubscriptionHandler as SubscriptionsHandler;
 
 pub use cost_report::CostReportHandler;
+#[cfg(feature = "cost-parsing")]
+pub use cost_report::{CostLineItem, CostReportRow, parse_cost_report, parse_cost_report_rows};
 pub use cost_report::{CostReportCreateRequest, CostReportFormat, SubscriptionType, Tag};
 pub use tasks::TasksHandler as TaskHandler;
 pub use users::UsersHandler as UserHandler;
@@ -86,6 +86,36 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_cloud_client_conflict_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/acl/users"))
+            .respond_with(
+                ResponseTemplate::new(409)
+                    .set_body_json(serde_json::json!({"error": "ACL_USER_NAME_ALREADY_EXISTS"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+        let result: Result<serde_json::Value> =
+            client.post("/acl/users", &serde_json::json!({})).await;
+
+        match result.unwrap_err() {
+            CloudError::Conflict { message } => {
+                assert!(message.contains("ACL_USER_NAME_ALREADY_EXISTS"));
+            }
+            err => panic!("Expected Conflict error, got: {:?}", err),
+        }
+    }
+
     #[test]
     fn test_cloud_error_display() {
         let err = CloudError::AuthenticationFailed {
@@ -101,6 +131,104 @@ mod tests {
             message: "Bad request".to_string(),
         };
         assert_eq!(err.to_string(), "API error (400): Bad request");
+
+        let err = CloudError::Conflict {
+            message: "Name already exists".to_string(),
+        };
+        assert_eq!(err.to_string(), "Conflict (409): Name already exists");
+    }
+
+    #[test]
+    fn test_cloud_error_kind() {
+        use crate::ErrorKind;
+
+        let cases = [
+            (
+                CloudError::AuthenticationFailed {
+                    message: "Bad creds".to_string(),
+                },
+                ErrorKind::Auth,
+            ),
+            (
+                CloudError::NotFound {
+                    message: "Not found".to_string(),
+                },
+                ErrorKind::NotFound,
+            ),
+            (
+                CloudError::RateLimited {
+                    message: "Too many requests".to_string(),
+                },
+                ErrorKind::Quota,
+            ),
+            (
+                CloudError::BadRequest {
+                    message: r#"{"type":"SUBSCRIPTION_DATABASES_LIMIT_EXCEEDED","status":"400 BAD_REQUEST"}"#
+                        .to_string(),
+                },
+                ErrorKind::Quota,
+            ),
+            (
+                CloudError::BadRequest {
+                    message: r#"{"type":"DATABASE_NAME_ALREADY_EXISTS","status":"400 BAD_REQUEST"}"#
+                        .to_string(),
+                },
+                ErrorKind::Validation,
+            ),
+            (
+                CloudError::Configuration {
+                    message: "API key is required".to_string(),
+                },
+                ErrorKind::Validation,
+            ),
+            (
+                CloudError::ResourceBusy {
+                    message: "Subscription is pending".to_string(),
+                    retry_after: std::time::Duration::from_secs(5),
+                },
+                ErrorKind::Transient,
+            ),
+            (
+                CloudError::ConnectionError("DNS failed".to_string()),
+                ErrorKind::Transient,
+            ),
+            (
+                CloudError::TaskFailed {
+                    task_id: "task-1".to_string(),
+                    error: Some("INVALID_CIDR_RANGE".to_string()),
+                    additional_info: None,
+                },
+                ErrorKind::TaskFailure,
+            ),
+            (
+                CloudError::ApiError {
+                    code: 502,
+                    message: "Bad gateway".to_string(),
+                },
+                ErrorKind::Transient,
+            ),
+            (
+                CloudError::ApiError {
+                    code: 418,
+                    message: "Teapot".to_string(),
+                },
+                ErrorKind::Unknown,
+            ),
+            (
+                CloudError::JsonError("expected value".to_string()),
+                ErrorKind::Unknown,
+            ),
+            (
+                CloudError::UnexpectedResponse {
+                    message: "Response did not include a task ID".to_string(),
+                },
+                ErrorKind::Unknown,
+            ),
+        ];
+
+        for (error, kind) in cases {
+            assert_eq!(error.kind(), kind, "{error:?}");
+        }
     }
 
     #[test]
@@ -219,4 +347,717 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_busy_rejection_without_retry_is_bad_request() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/subscriptions/123/databases"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "SUBSCRIPTION_NOT_ACTIVE",
+                "description": "Subscription is still pending"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+        let result: Result<serde_json::Value> = client
+            .post("/subscriptions/123/databases", &serde_json::json!({}))
+            .await;
+
+        // Busy rejections are only reclassified when retries are enabled
+        let err = result.unwrap_err();
+        assert!(matches!(err, CloudError::BadRequest { .. }));
+        assert!(err.error_body().unwrap().is_busy());
+    }
+
+    #[tokio::test]
+    async fn test_bad_request_is_not_resource_busy() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "INVALID_NAME"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .retry_on_busy(3)
+            .build()
+            .unwrap();
+        let result: Result<serde_json::Value> = client.post("/test", &serde_json::json!({})).await;
+
+        assert!(matches!(result, Err(CloudError::BadRequest { .. })));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_busy() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/subscriptions/123/databases/456"))
+            .respond_with(
+                ResponseTemplate::new(409)
+                    .insert_header("Retry-After", "0")
+                    .set_body_json(serde_json::json!({
+                        "error": "DATABASE_IN_TRANSITION",
+                        "description": "Database is in transition state"
+                    })),
+            )
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/subscriptions/123/databases/456"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(serde_json::json!({"taskId": "t-1"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .retry_on_busy(2)
+            .build()
+            .unwrap();
+        let result: Result<serde_json::Value> = client
+            .put("/subscriptions/123/databases/456", &serde_json::json!({}))
+            .await;
+
+        assert_eq!(result.unwrap()["taskId"], "t-1");
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_busy_exhausted() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/subscriptions/123"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .insert_header("Retry-After", "0")
+                    .set_body_json(serde_json::json!({
+                        "error": "GENERAL_ERROR",
+                        "description": "Subscription deletion is in progress"
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .retry_on_busy(1)
+            .build()
+            .unwrap();
+        let result = client.delete("/subscriptions/123").await;
+
+        match result.unwrap_err() {
+            CloudError::ResourceBusy { retry_after, .. } => {
+                assert_eq!(retry_after, std::time::Duration::ZERO);
+            }
+            err => panic!("Expected ResourceBusy error, got: {:?}", err),
+        }
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_is_busy_message() {
+        use crate::error::is_busy_message;
+
+        assert!(is_busy_message(r#"{"error": "SUBSCRIPTION_PENDING"}"#));
+        assert!(is_busy_message(
+            r#"{"type": "GENERAL_ERROR", "description": "Database is in draft state"}"#
+        ));
+        assert!(!is_busy_message(r#"{"error": "INVALID_DATABASE_NAME"}"#));
+        // Validation errors that merely mention a state are not busy
+        assert!(!is_busy_message(
+            r#"{"error": "INVALID_FIELD", "description": "pendingChanges must be a boolean"}"#
+        ));
+        assert!(!is_busy_message(
+            r#"{"error": "INVALID_TRANSITION", "description": "Cannot transition to the requested plan"}"#
+        ));
+        // Unstructured bodies are never classified
+        assert!(!is_busy_message("Subscription is pending"));
+    }
+
+    #[tokio::test]
+    async fn test_request_interceptor() {
+        use crate::RequestInterceptor;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use wiremock::matchers::header;
+
+        struct Correlation {
+            responses: Arc<AtomicUsize>,
+        }
+
+        impl RequestInterceptor for Correlation {
+            fn before_request(&self, request: &mut reqwest::Request) -> Result<()> {
+                request.headers_mut().insert(
+                    "x-correlation-id",
+                    reqwest::header::HeaderValue::from_static("corr-1"),
+                );
+                Ok(())
+            }
+
+            fn after_response(&self, method: &reqwest::Method, response: &reqwest::Response) {
+                assert_eq!(method, reqwest::Method::DELETE);
+                assert!(response.status().is_success());
+                self.responses.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/subscriptions/1"))
+            .and(header("x-correlation-id", "corr-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&mock_server)
+            .await;
+
+        let responses = Arc::new(AtomicUsize::new(0));
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .interceptor(Correlation {
+                responses: responses.clone(),
+            })
+            .build()
+            .unwrap();
+
+        client.delete_raw("/subscriptions/1").await.unwrap();
+        assert_eq!(responses.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_interceptor_can_abort() {
+        use crate::RequestInterceptor;
+
+        struct Deny;
+
+        impl RequestInterceptor for Deny {
+            fn before_request(&self, _request: &mut reqwest::Request) -> Result<()> {
+                Err(CloudError::Forbidden {
+                    message: "blocked by policy".to_string(),
+                })
+            }
+        }
+
+        let mock_server = MockServer::start().await;
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .interceptor(Deny)
+            .build()
+            .unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/subscriptions").await;
+        assert!(matches!(result, Err(CloudError::Forbidden { .. })));
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_with_http_client() {
+        use wiremock::matchers::header;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/subscriptions"))
+            .and(header("x-proxy-auth", "token"))
+            .and(header("x-api-key", "test_key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"ok": true})))
+            .mount(&mock_server)
+            .await;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "x-proxy-auth",
+            reqwest::header::HeaderValue::from_static("token"),
+        );
+        let http = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .with_http_client(http)
+            .build()
+            .unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/subscriptions").await;
+        assert_eq!(result.unwrap()["ok"], true);
+    }
+
+    #[test]
+    fn test_builder_tls_options() {
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .add_root_certificate(&include_bytes!("../tests/fixtures/test-ca.pem")[..])
+            .min_tls_version(reqwest::tls::Version::TLS_1_2)
+            .danger_accept_invalid_certs(true)
+            .build();
+
+        assert!(client.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_http2_prior_knowledge_and_keep_alive() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/tasks"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .http2_prior_knowledge()
+            .http2_keep_alive(
+                std::time::Duration::from_secs(30),
+                std::time::Duration::from_secs(10),
+            )
+            .build()
+            .unwrap();
+
+        // With prior knowledge the client only speaks h2c, so the request
+        // succeeding shows the connection was negotiated as HTTP/2
+        let result: Result<serde_json::Value> = client.get("/tasks").await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_verify_credentials() {
+        use crate::CredentialCheck;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "account": {"id": 1001, "name": "Redis Labs"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let check = client.verify_credentials().await.unwrap();
+        assert_eq!(
+            check,
+            CredentialCheck::Ok {
+                account_id: Some(1001)
+            }
+        );
+        assert!(check.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_verify_credentials_failures() {
+        use crate::CredentialCheck;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(401)
+                    .set_body_json(serde_json::json!({"error": "Invalid API key"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            client.verify_credentials().await.unwrap(),
+            CredentialCheck::BadCredentials { .. }
+        ));
+
+        // Nothing listens on port 1
+        let unreachable = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url("http://127.0.0.1:1")
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            unreachable.verify_credentials().await.unwrap(),
+            CredentialCheck::NetworkIssue { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_error_body_parsing() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/subscriptions/999"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "type": "SUBSCRIPTION_NOT_FOUND",
+                "status": "404 NOT_FOUND",
+                "description": "Subscription was not found",
+                "timestamp": "2024-01-15T10:00:00Z",
+                "traceId": "abc-123"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/plain"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("bad input"))
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let err = client
+            .get::<serde_json::Value>("/subscriptions/999")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CloudError::NotFound { .. }));
+        let body = err.error_body().unwrap();
+        assert_eq!(body.error_code.as_deref(), Some("SUBSCRIPTION_NOT_FOUND"));
+        assert_eq!(
+            body.description.as_deref(),
+            Some("Subscription was not found")
+        );
+        assert_eq!(body.status.as_deref(), Some("404 NOT_FOUND"));
+        assert_eq!(body.timestamp.as_deref(), Some("2024-01-15T10:00:00Z"));
+        assert_eq!(body.trace_id.as_deref(), Some("abc-123"));
+
+        let err = client.get::<serde_json::Value>("/plain").await.unwrap_err();
+        assert!(err.error_body().is_none());
+        assert_eq!(err.raw_body(), Some("bad input"));
+    }
+
+    #[test]
+    fn test_error_body_field_variants() {
+        use crate::ApiErrorBody;
+
+        let body = ApiErrorBody::parse(r#"{"error": "Invalid API credentials"}"#).unwrap();
+        assert_eq!(body.description.as_deref(), Some("Invalid API credentials"));
+        assert_eq!(body.error_code, None);
+
+        let body = ApiErrorBody::parse(
+            r#"{"error": "Bad Request", "message": "Invalid memory limit", "status": 400}"#,
+        )
+        .unwrap();
+        assert_eq!(body.error_code.as_deref(), Some("Bad Request"));
+        assert_eq!(body.description.as_deref(), Some("Invalid memory limit"));
+        assert_eq!(body.status.as_deref(), Some("400"));
+
+        assert!(ApiErrorBody::parse("not json").is_none());
+        assert!(ApiErrorBody::parse(r#"{"unrelated": true}"#).is_none());
+        assert!(ApiErrorBody::parse("[1, 2]").is_none());
+    }
+
+    #[test]
+    fn test_builder_configuration_errors() {
+        let err = CloudClient::builder()
+            .api_secret("test_secret")
+            .build()
+            .err()
+            .unwrap();
+        assert!(
+            matches!(&err, CloudError::Configuration { message } if message == "API key is required")
+        );
+        assert!(!err.is_retryable());
+
+        let err = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .user_agent("bad\nagent")
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, CloudError::Configuration { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_requests() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"ok": true}))
+                    .set_delay(std::time::Duration::from_millis(100)),
+            )
+            .expect(6)
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .max_concurrent_requests(2)
+            .build()
+            .unwrap();
+
+        let requests = (0..6).map(|_| {
+            let client = client.clone();
+            async move { client.get::<serde_json::Value>("/slow").await }
+        });
+        for result in futures::future::join_all(requests).await {
+            result.unwrap();
+        }
+
+        let stats = client.concurrency_stats().unwrap();
+        assert_eq!(stats.max_in_flight, 2);
+        assert_eq!(stats.in_flight, 0);
+        assert_eq!(stats.waiting, 0);
+        assert_eq!(stats.total_acquired, 6);
+        // Later requests had to queue behind at least one full round trip
+        assert!(stats.max_wait >= std::time::Duration::from_millis(100));
+        assert!(stats.average_wait() <= stats.max_wait);
+    }
+
+    #[tokio::test]
+    async fn test_permit_held_until_body_read() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/body"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("payload"))
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .max_concurrent_requests(2)
+            .build()
+            .unwrap();
+
+        let url = format!("{}/body", mock_server.uri());
+        let response = client.send(client.client.get(&url)).await.unwrap();
+        assert_eq!(client.concurrency_stats().unwrap().in_flight, 1);
+        assert_eq!(response.content_length(), Some(7));
+        assert_eq!(response.url().as_str(), url);
+
+        assert_eq!(response.text().await.unwrap(), "payload");
+        assert_eq!(client.concurrency_stats().unwrap().in_flight, 0);
+
+        // Dropping an unread response releases the permit too
+        let response = client.send(client.client.get(&url)).await.unwrap();
+        drop(response);
+        assert_eq!(client.concurrency_stats().unwrap().in_flight, 0);
+    }
+
+    #[test]
+    fn test_max_concurrent_requests_validation() {
+        let err = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .max_concurrent_requests(0)
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, CloudError::Configuration { .. }));
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .build()
+            .unwrap();
+        assert!(client.concurrency_stats().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_shared_across_clones() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/fast"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(4)
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .rate_limit(20, 2)
+            .max_concurrent_requests(4)
+            .build()
+            .unwrap();
+
+        // Two requests through a clone drain the burst; two through another
+        // clone have to wait for refills from the same bucket
+        let first = client.clone();
+        let second = client.clone();
+        for _ in 0..2 {
+            first.get::<serde_json::Value>("/fast").await.unwrap();
+        }
+        for _ in 0..2 {
+            second.get::<serde_json::Value>("/fast").await.unwrap();
+        }
+
+        let snapshot = client.limits_snapshot();
+        assert_eq!(snapshot.shared_handles, 3);
+        assert_eq!(snapshot.concurrency.unwrap().total_acquired, 4);
+        let rate = snapshot.rate.unwrap();
+        assert_eq!((rate.requests_per_second, rate.burst), (20, 2));
+        assert_eq!(rate.total_acquired, 4);
+        assert_eq!(rate.total_throttled, 2);
+        assert!(rate.total_wait >= std::time::Duration::from_millis(40));
+        assert_eq!(
+            second.limits_snapshot().rate.unwrap().total_throttled,
+            rate.total_throttled
+        );
+
+        drop((first, second));
+        assert_eq!(client.limits_snapshot().shared_handles, 1);
+
+        let err = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .rate_limit(0, 1)
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, CloudError::Configuration { .. }));
+        assert_eq!(
+            CloudClient::builder()
+                .api_key("test_key")
+                .api_secret("test_secret")
+                .build()
+                .unwrap()
+                .limits_snapshot(),
+            crate::LimitsSnapshot::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_serves_allowlisted_gets() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/regions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "regions": [{"id": 1, "name": "us-east-1", "provider": "AWS"}]
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/subscriptions"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"subscriptions": []})),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(mock_server.uri())
+            .response_cache(crate::cache::ResponseCacheConfig::catalog(
+                std::time::Duration::from_secs(60),
+            ))
+            .build()
+            .unwrap();
+
+        // Second call (from a clone) is served from the cache
+        let regions = client.account().get_supported_regions(None).await.unwrap();
+        let again = client
+            .clone()
+            .account()
+            .get_supported_regions(None)
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&regions).unwrap(),
+            serde_json::to_value(&again).unwrap()
+        );
+
+        // Paths outside the allowlist always reach the API
+        let _: serde_json::Value = client.get("/subscriptions").await.unwrap();
+        let _: serde_json::Value = client.get("/subscriptions").await.unwrap();
+
+        // Clearing forces a refetch
+        client.clear_response_cache();
+        client.account().get_supported_regions(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_with_options_base_url() {
+        let default_server = MockServer::start().await;
+        let preview_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/subscriptions"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"host": "preview"})),
+            )
+            .expect(1)
+            .mount(&preview_server)
+            .await;
+
+        let client = CloudClient::builder()
+            .api_key("test_key")
+            .api_secret("test_secret")
+            .base_url(default_server.uri())
+            .build()
+            .unwrap();
+        let preview = client.with_options(
+            crate::RequestOptions::new().base_url(format!("{}/v2", preview_server.uri())),
+        );
+
+        let result: serde_json::Value = preview.get("/subscriptions").await.unwrap();
+        assert_eq!(result["host"], "preview");
+        assert_eq!(client.base_url(), default_server.uri());
+        assert!(default_server.received_requests().await.unwrap().is_empty());
+    }
 }
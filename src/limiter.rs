@@ -0,0 +1,308 @@
+//! Client-wide concurrency and rate limiting
+//!
+//! When enabled with [`CloudClientBuilder::max_concurrent_requests`], every
+//! request made through a `CloudClient` (and all of its clones and handlers)
+//! takes a permit from a shared semaphore before it is sent and keeps it until
+//! the response body has been read or dropped. Requests beyond the limit
+//! queue until a permit is released, which keeps wide fan-outs from tripping
+//! the API's rate limits. Queue wait times are recorded and exposed
+//! through [`CloudClient::concurrency_stats`].
+//!
+//! [`CloudClientBuilder::rate_limit`] adds a token bucket in front of that:
+//! each request takes a token, tokens refill at a fixed rate up to a burst
+//! size, and requests wait when the bucket is empty. Like the semaphore, the
+//! bucket lives behind an `Arc` and is shared by every clone of the client.
+//! [`CloudClient::limits_snapshot`] reports both.
+//!
+//! [`CloudClientBuilder::max_concurrent_requests`]: crate::CloudClientBuilder::max_concurrent_requests
+//! [`CloudClientBuilder::rate_limit`]: crate::CloudClientBuilder::rate_limit
+//! [`CloudClient::concurrency_stats`]: crate::CloudClient::concurrency_stats
+//! [`CloudClient::limits_snapshot`]: crate::CloudClient::limits_snapshot
+
+use crate::clock::Clock;
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::trace;
+
+/// Snapshot of the concurrency limiter's state and queue metrics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConcurrencyStats {
+    /// Configured maximum number of in-flight requests
+    pub max_in_flight: usize,
+    /// Requests currently holding a permit
+    pub in_flight: usize,
+    /// Requests currently queued for a permit
+    pub waiting: usize,
+    /// Total number of permits handed out
+    pub total_acquired: u64,
+    /// Total time requests spent queued for a permit
+    pub total_wait: Duration,
+    /// Longest time a single request spent queued
+    pub max_wait: Duration,
+}
+
+impl ConcurrencyStats {
+    /// Average time a request spent queued for a permit
+    #[must_use]
+    pub fn average_wait(&self) -> Duration {
+        if self.total_acquired == 0 {
+            return Duration::ZERO;
+        }
+        let nanos = self.total_wait.as_nanos() / u128::from(self.total_acquired);
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
+
+/// Semaphore shared by every clone of a client
+#[derive(Debug)]
+pub(crate) struct ConcurrencyLimiter {
+    semaphore: Arc<Semaphore>,
+    max_in_flight: usize,
+    waiting: AtomicUsize,
+    total_acquired: AtomicU64,
+    total_wait_nanos: AtomicU64,
+    max_wait_nanos: AtomicU64,
+    clock: Arc<dyn Clock>,
+}
+
+impl ConcurrencyLimiter {
+    pub(crate) fn new(max_in_flight: usize, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_in_flight)),
+            max_in_flight,
+            waiting: AtomicUsize::new(0),
+            total_acquired: AtomicU64::new(0),
+            total_wait_nanos: AtomicU64::new(0),
+            max_wait_nanos: AtomicU64::new(0),
+            clock,
+        }
+    }
+
+    /// Wait for a permit; the request may be sent while the permit is held
+    pub(crate) async fn acquire(&self) -> OwnedSemaphorePermit {
+        let started = self.clock.now();
+        self.waiting.fetch_add(1, Ordering::Relaxed);
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .expect("concurrency semaphore is never closed");
+        self.waiting.fetch_sub(1, Ordering::Relaxed);
+
+        let waited = self.clock.now().saturating_duration_since(started);
+        let nanos = u64::try_from(waited.as_nanos()).unwrap_or(u64::MAX);
+        self.total_acquired.fetch_add(1, Ordering::Relaxed);
+        self.total_wait_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_wait_nanos.fetch_max(nanos, Ordering::Relaxed);
+        trace!("Acquired request permit after {waited:?}");
+
+        permit
+    }
+
+    pub(crate) fn stats(&self) -> ConcurrencyStats {
+        ConcurrencyStats {
+            max_in_flight: self.max_in_flight,
+            in_flight: self.max_in_flight - self.semaphore.available_permits(),
+            waiting: self.waiting.load(Ordering::Relaxed),
+            total_acquired: self.total_acquired.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(self.total_wait_nanos.load(Ordering::Relaxed)),
+            max_wait: Duration::from_nanos(self.max_wait_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Keep `permit` until the body of `response` has been read or dropped
+///
+/// The request only leaves the in-flight count once its body is consumed, so
+/// slow downloads count against the limit for as long as they run.
+pub(crate) fn hold_until_read(
+    response: reqwest::Response,
+    permit: OwnedSemaphorePermit,
+) -> reqwest::Response {
+    use reqwest::ResponseBuilderExt;
+
+    let url = response.url().clone();
+    let (parts, body) = http::Response::from(response).into_parts();
+    let mut builder = http::Response::builder()
+        .status(parts.status)
+        .version(parts.version)
+        .url(url);
+    if let Some(headers) = builder.headers_mut() {
+        *headers = parts.headers;
+    }
+    if let Some(extensions) = builder.extensions_mut() {
+        extensions.extend(parts.extensions);
+    }
+    let body = reqwest::Body::wrap(PermitBody {
+        inner: body,
+        permit: Some(permit),
+    });
+    builder
+        .body(body)
+        .expect("parts of a received response are valid")
+        .into()
+}
+
+/// Response body that releases its concurrency permit once fully read
+struct PermitBody {
+    inner: reqwest::Body,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Body for PermitBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, reqwest::Error>>> {
+        let this = self.get_mut();
+        let frame = Pin::new(&mut this.inner).poll_frame(cx);
+        if matches!(frame, Poll::Ready(None | Some(Err(_)))) {
+            this.permit = None;
+        }
+        frame
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Snapshot of the rate limiter's token bucket
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimitStats {
+    /// Tokens added to the bucket per second
+    pub requests_per_second: u32,
+    /// Maximum number of tokens the bucket holds
+    pub burst: u32,
+    /// Whole tokens available right now
+    pub available: u32,
+    /// Total number of tokens handed out
+    pub total_acquired: u64,
+    /// Requests that had to wait for a token
+    pub total_throttled: u64,
+    /// Total time requests spent waiting for a token
+    pub total_wait: Duration,
+}
+
+/// Client-wide limiter state, as seen from any clone of the client
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LimitsSnapshot {
+    /// Concurrency limiter metrics, if a limit is configured
+    pub concurrency: Option<ConcurrencyStats>,
+    /// Token bucket metrics, if a rate limit is configured
+    pub rate: Option<RateLimitStats>,
+    /// Number of client handles (clones, handlers, option views) sharing
+    /// this limiter state, or 0 if no limiter is configured
+    pub shared_handles: usize,
+}
+
+/// Token bucket shared by every clone of a client
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    requests_per_second: u32,
+    burst: u32,
+    bucket: Mutex<Bucket>,
+    total_acquired: AtomicU64,
+    total_throttled: AtomicU64,
+    total_wait_nanos: AtomicU64,
+    clock: Arc<dyn Clock>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(requests_per_second: u32, burst: u32, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            requests_per_second,
+            burst,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(burst),
+                refilled_at: clock.now(),
+            }),
+            total_acquired: AtomicU64::new(0),
+            total_throttled: AtomicU64::new(0),
+            total_wait_nanos: AtomicU64::new(0),
+            clock,
+        }
+    }
+
+    /// Lock the bucket and add the tokens accrued since the last refill
+    fn refilled(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        let mut bucket = self
+            .bucket
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let now = self.clock.now();
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens = (bucket.tokens
+            + elapsed.as_secs_f64() * f64::from(self.requests_per_second))
+        .min(f64::from(self.burst));
+        bucket.refilled_at = now;
+        bucket
+    }
+
+    /// Wait until a token is available and take it
+    pub(crate) async fn acquire(&self) {
+        let started = self.clock.now();
+        let mut throttled = false;
+        loop {
+            let wait = {
+                let mut bucket = self.refilled();
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    None
+                } else {
+                    Some(Duration::from_secs_f64(
+                        (1.0 - bucket.tokens) / f64::from(self.requests_per_second),
+                    ))
+                }
+            };
+            match wait {
+                Some(wait) => {
+                    throttled = true;
+                    trace!("Rate limited, waiting {wait:?} for a token");
+                    self.clock.sleep(wait).await;
+                }
+                None => break,
+            }
+        }
+
+        self.total_acquired.fetch_add(1, Ordering::Relaxed);
+        if throttled {
+            let waited = self.clock.now().saturating_duration_since(started);
+            let nanos = u64::try_from(waited.as_nanos()).unwrap_or(u64::MAX);
+            self.total_throttled.fetch_add(1, Ordering::Relaxed);
+            self.total_wait_nanos.fetch_add(nanos, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn stats(&self) -> RateLimitStats {
+        // Truncation is intended: only whole tokens can be spent
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let available = self.refilled().tokens.floor() as u32;
+        RateLimitStats {
+            requests_per_second: self.requests_per_second,
+            burst: self.burst,
+            available,
+            total_acquired: self.total_acquired.load(Ordering::Relaxed),
+            total_throttled: self.total_throttled.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(self.total_wait_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Commonly used types, for glob import
+//!
+//! Brings the client, every handler, the most common request and response
+//! models, and the [`Result`] alias into scope with a single `use`:
+//!
+//! ```no_run
+//! use redis_cloud::prelude::*;
+//!
+//! # async fn example() -> Result<()> {
+//! let client = CloudClient::builder()
+//!     .api_key("your-api-key")
+//!     .api_secret("your-api-secret")
+//!     .build()?;
+//!
+//! let subscriptions = SubscriptionHandler::new(client.clone())
+//!     .get_all_subscriptions()
+//!     .await?;
+//! for subscription in subscriptions.subscriptions.unwrap_or_default() {
+//!     if let Some(id) = subscription.id {
+//!         let databases: Vec<Database> = DatabaseHandler::new(client.clone())
+//!             .get_all_databases(id)
+//!             .await?;
+//!         println!("{id}: {} databases", databases.len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Several modules define their own `TaskStateUpdate`; it is left out to
+//! avoid ambiguity. Import it from the module whose handler returns it.
+
+pub use crate::client::{CloudClient, CloudClientBuilder, RequestOptions};
+pub use crate::error::{CloudError, Result};
+
+pub use crate::account::AccountHandler;
+pub use crate::acl::AclHandler;
+pub use crate::cloud_accounts::CloudAccountsHandler as CloudAccountHandler;
+pub use crate::connectivity::{
+    ConnectivityHandler, PrivateLinkHandler, PscHandler, TransitGatewayHandler, VpcPeeringHandler,
+};
+pub use crate::cost_report::{CostReportCreateRequest, CostReportFormat, CostReportHandler};
+pub use crate::fixed::databases::{FixedDatabase, FixedDatabaseCreateRequest};
+pub use crate::fixed::subscriptions::{FixedSubscription, FixedSubscriptionCreateRequest};
+pub use crate::fixed::{FixedDatabaseHandler, FixedSubscriptionHandler};
+pub use crate::flexible::databases::{Database, DatabaseCreateRequest, DatabaseUpdateRequest};
+pub use crate::flexible::subscriptions::{Subscription, SubscriptionCreateRequest};
+pub use crate::flexible::{DatabaseHandler, MetricsHandler, SubscriptionHandler};
+pub use crate::tasks::{TaskWaitOptions, TasksHandler as TaskHandler, Updated};
+pub use crate::types::{CloudProvider, Section};
+pub use crate::users::UsersHandler as UserHandler;
+pub use crate::version::RedisVersionReq;
@@ -0,0 +1,93 @@
+//! Query string and path segment encoding for handler URLs
+//!
+//! Handlers build request paths by hand; values such as region names, tag
+//! keys, or task IDs can contain characters (`/`, `&`, spaces, ...) that must
+//! be percent-encoded to reach the API intact. [`QueryParams`] collects
+//! optional query parameters and [`segment`] encodes a single path segment.
+
+use std::fmt::{self, Display, Write};
+
+/// Query parameters appended to a request path
+///
+/// Renders as an empty string when no parameter was set, otherwise as
+/// `?key=value&...` with every value percent-encoded.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct QueryParams {
+    pairs: Vec<(&'static str, String)>,
+}
+
+impl QueryParams {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `key=value`
+    pub(crate) fn push(mut self, key: &'static str, value: impl Display) -> Self {
+        self.pairs.push((key, value.to_string()));
+        self
+    }
+
+    /// Add `key=value` if `value` is set
+    pub(crate) fn push_opt(self, key: &'static str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.push(key, value),
+            None => self,
+        }
+    }
+}
+
+impl Display for QueryParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.pairs.iter().enumerate() {
+            f.write_char(if i == 0 { '?' } else { '&' })?;
+            write!(f, "{key}={}", Encoded(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// Percent-encode `value` for use as a single path segment
+pub(crate) fn segment(value: &str) -> String {
+    Encoded(value).to_string()
+}
+
+/// Percent-encodes everything but RFC 3986 unreserved characters
+struct Encoded<'a>(&'a str);
+
+impl Display for Encoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                f.write_char(char::from(byte))?;
+            } else {
+                write!(f, "%{byte:02X}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_params() {
+        assert_eq!(QueryParams::new().to_string(), "");
+        assert_eq!(
+            QueryParams::new()
+                .push("offset", 10)
+                .push_opt("limit", None::<u32>)
+                .push_opt("regionName", Some("us east/1&x=y"))
+                .to_string(),
+            "?offset=10&regionName=us%20east%2F1%26x%3Dy"
+        );
+    }
+
+    #[test]
+    fn test_segment() {
+        assert_eq!(segment("team-a_1.x~"), "team-a_1.x~");
+        assert_eq!(segment("cost/center"), "cost%2Fcenter");
+        assert_eq!(segment("équipe"), "%C3%A9quipe");
+    }
+}
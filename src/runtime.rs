@@ -0,0 +1,20 @@
+//! Async runtime abstraction
+//!
+//! The client only needs a timer from the async runtime (for busy retries and
+//! task polling). With the default `runtime-tokio` feature Tokio's timer is
+//! used; without it the timer comes from `futures-timer`, which works under
+//! any executor.
+
+use std::time::Duration;
+
+/// Wait for `duration` without blocking the executor
+#[cfg(feature = "runtime-tokio")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Wait for `duration` without blocking the executor
+#[cfg(not(feature = "runtime-tokio"))]
+pub(crate) async fn sleep(duration: Duration) {
+    futures_timer::Delay::new(duration).await;
+}
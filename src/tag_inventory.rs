@@ -0,0 +1,263 @@
+//! Account-wide tag inventory
+//!
+//! [`CloudClient::tag_inventory`] collects the tags of every Pro and
+//! Essentials database in the account and indexes them by key and value, so
+//! governance reports can answer "which databases carry `team=payments`?" or
+//! "which values are in use for `env`?" without walking subscriptions by
+//! hand.
+//!
+//! Pro and Essentials databases are scanned concurrently, as are the
+//! subscriptions of each, subject to the client's concurrency limit if one
+//! is set.
+//!
+//! For a single lookup, [`CloudClient::find_databases_by_tag`] returns the
+//! databases carrying one `key=value` pair; [`TagInventory::by_resource`]
+//! gives the reverse view, each database with all of its tags.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use redis_cloud::CloudClient;
+//!
+//! # async fn example() -> redis_cloud::Result<()> {
+//! let client = CloudClient::builder()
+//!     .api_key("your-api-key")
+//!     .api_secret("your-api-secret")
+//!     .build()?;
+//!
+//! let inventory = client.tag_inventory().await?;
+//! for (value, resources) in inventory.values("team") {
+//!     println!("team={value}: {} databases", resources.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CloudClient, Result};
+use futures_util::TryStreamExt;
+use futures_util::future::{try_join, try_join_all};
+use std::collections::BTreeMap;
+
+/// Kind of database a tag was found on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DatabaseKind {
+    /// Pro (flexible) database
+    Pro,
+    /// Essentials (fixed) database
+    Essentials,
+}
+
+/// A database carrying a tag
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaggedResource {
+    /// Pro or Essentials
+    pub kind: DatabaseKind,
+    /// Subscription the database belongs to
+    pub subscription_id: i32,
+    /// Database ID
+    pub database_id: i32,
+    /// Database name
+    pub database_name: Option<String>,
+}
+
+/// Databases indexed by tag key, then tag value
+///
+/// Keys and values are kept in sorted order; resources under one value are
+/// listed in the order they were found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagInventory {
+    /// Tag key to tag value to the databases carrying that pair
+    pub index: BTreeMap<String, BTreeMap<String, Vec<TaggedResource>>>,
+}
+
+impl TagInventory {
+    /// Record that `resource` carries the tag `key=value`
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+        resource: TaggedResource,
+    ) {
+        self.index
+            .entry(key.into())
+            .or_default()
+            .entry(value.into())
+            .or_default()
+            .push(resource);
+    }
+
+    /// Tag keys in use
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.index.keys().map(String::as_str)
+    }
+
+    /// Values in use for `key`, with the databases carrying each
+    pub fn values(&self, key: &str) -> impl Iterator<Item = (&str, &[TaggedResource])> {
+        self.index
+            .get(key)
+            .into_iter()
+            .flatten()
+            .map(|(value, resources)| (value.as_str(), resources.as_slice()))
+    }
+
+    /// Databases tagged `key=value`
+    #[must_use]
+    pub fn resources(&self, key: &str, value: &str) -> &[TaggedResource] {
+        self.index
+            .get(key)
+            .and_then(|values| values.get(value))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Tags of each database, keyed by database
+    ///
+    /// The reverse of the key/value index, e.g. for chargeback reports that
+    /// list every database with its `team` and `cost-center` tags.
+    #[must_use]
+    pub fn by_resource(&self) -> BTreeMap<&TaggedResource, BTreeMap<&str, &str>> {
+        let mut resources: BTreeMap<&TaggedResource, BTreeMap<&str, &str>> = BTreeMap::new();
+        for (key, values) in &self.index {
+            for (value, tagged) in values {
+                for resource in tagged {
+                    resources
+                        .entry(resource)
+                        .or_default()
+                        .insert(key.as_str(), value.as_str());
+                }
+            }
+        }
+        resources
+    }
+
+    /// Returns true if no tags were found
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+}
+
+impl CloudClient {
+    /// Index the tags of every Pro and Essentials database in the account
+    ///
+    /// Fails if any subscription, database list, or tag list cannot be
+    /// fetched, so the inventory is never silently incomplete.
+    ///
+    /// GET /subscriptions, GET /subscriptions/{subscriptionId}/databases,
+    /// GET /subscriptions/{subscriptionId}/databases/{databaseId}/tags,
+    /// GET /fixed/subscriptions, GET /fixed/subscriptions/{subscriptionId}/databases,
+    /// GET /fixed/subscriptions/{subscriptionId}/databases/{databaseId}/tags
+    pub async fn tag_inventory(&self) -> Result<TagInventory> {
+        let (pro, essentials) = try_join(self.pro_tags(), self.essentials_tags()).await?;
+
+        let mut inventory = TagInventory::default();
+        for (resource, tags) in pro.into_iter().chain(essentials) {
+            for (key, value) in tags {
+                inventory.insert(key, value, resource.clone());
+            }
+        }
+        Ok(inventory)
+    }
+
+    /// Find the Pro and Essentials databases tagged `key=value`
+    ///
+    /// Builds the full [`tag_inventory`](Self::tag_inventory); when looking
+    /// up several tags, build the inventory once and use
+    /// [`TagInventory::resources`] instead.
+    pub async fn find_databases_by_tag(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<Vec<TaggedResource>> {
+        Ok(self
+            .tag_inventory()
+            .await?
+            .index
+            .remove(key)
+            .and_then(|mut values| values.remove(value))
+            .unwrap_or_default())
+    }
+
+    /// Tags of every Pro database
+    async fn pro_tags(&self) -> Result<Vec<(TaggedResource, Vec<(String, String)>)>> {
+        let databases = self.databases();
+        let subscriptions = self
+            .subscriptions()
+            .get_all_subscriptions()
+            .await?
+            .subscriptions
+            .unwrap_or_default();
+
+        let per_subscription = try_join_all(
+            subscriptions
+                .iter()
+                .filter_map(|subscription| subscription.id)
+                .map(|subscription_id| {
+                    let databases = &databases;
+                    async move {
+                        let found = databases.get_all_databases(subscription_id).await?;
+                        try_join_all(found.into_iter().map(|database| async move {
+                            let tags = databases
+                                .get_tags(subscription_id, database.database_id)
+                                .await?;
+                            let resource = TaggedResource {
+                                kind: DatabaseKind::Pro,
+                                subscription_id,
+                                database_id: database.database_id,
+                                database_name: database.name,
+                            };
+                            let pairs = tags
+                                .tags
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter_map(|tag| Some((tag.key?, tag.value?)))
+                                .collect();
+                            Ok::<_, crate::CloudError>((resource, pairs))
+                        }))
+                        .await
+                    }
+                }),
+        )
+        .await?;
+
+        Ok(per_subscription.into_iter().flatten().collect())
+    }
+
+    /// Tags of every Essentials database
+    async fn essentials_tags(&self) -> Result<Vec<(TaggedResource, Vec<(String, String)>)>> {
+        let databases = self.fixed_databases();
+        let subscriptions = self
+            .fixed_subscriptions()
+            .list()
+            .await?
+            .subscriptions
+            .unwrap_or_default();
+
+        let per_subscription = try_join_all(
+            subscriptions
+                .iter()
+                .filter_map(|subscription| subscription.id)
+                .map(|subscription_id| {
+                    databases
+                        .stream_tags(subscription_id)
+                        .map_ok(|tagged| {
+                            let resource = TaggedResource {
+                                kind: DatabaseKind::Essentials,
+                                subscription_id: tagged.subscription_id,
+                                database_id: tagged.database.database_id.unwrap_or_default(),
+                                database_name: tagged.database.name,
+                            };
+                            let pairs = tagged
+                                .tags
+                                .into_iter()
+                                .filter_map(|tag| Some((tag.key?, tag.value?)))
+                                .collect();
+                            (resource, pairs)
+                        })
+                        .try_collect::<Vec<_>>()
+                }),
+        )
+        .await?;
+
+        Ok(per_subscription.into_iter().flatten().collect())
+    }
+}
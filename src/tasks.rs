@@ -55,9 +55,16 @@
 //! # }
 //! ```
 
-use crate::types::{Link, ProcessorResponse};
-use crate::{CloudClient, Result};
+use crate::query;
+use crate::types::{Link, ProcessorResponse, Timestamp};
+use crate::{CloudClient, CloudError, Result};
+use async_stream::try_stream;
+use futures_core::Stream;
+use futures_util::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::time::Duration;
 
 // ============================================================================
 // Models
@@ -66,7 +73,7 @@ use serde::{Deserialize, Serialize};
 /// Task state update
 ///
 /// Represents the state and result of an asynchronous task
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskStateUpdate {
     /// Unique task identifier
@@ -87,7 +94,7 @@ pub struct TaskStateUpdate {
 
     /// Timestamp of last task update
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
 
     /// Task completion percentage (0-100)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,6 +109,263 @@ pub struct TaskStateUpdate {
     pub links: Option<Vec<Link>>,
 }
 
+impl TaskStateUpdate {
+    /// Returns true if the task finished successfully
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.status.as_deref() == Some(TASK_STATUS_COMPLETED)
+    }
+
+    /// Returns true if the task finished with an error
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        self.status.as_deref() == Some(TASK_STATUS_ERROR)
+    }
+
+    /// Returns true if the task has reached a final state
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        self.is_completed() || self.is_failed()
+    }
+
+    /// Convert a failed task into [`CloudError::TaskFailed`]
+    ///
+    /// Returns the task unchanged unless its status is `processing-error`,
+    /// in which case the error and additional info from its response are
+    /// carried by the returned error.
+    pub fn into_result(self) -> Result<Self> {
+        if !self.is_failed() {
+            return Ok(self);
+        }
+        let (error, additional_info) = self
+            .response
+            .map(|response| (response.error, response.additional_info))
+            .unwrap_or_default();
+        Err(CloudError::TaskFailed {
+            task_id: self.task_id.unwrap_or_default(),
+            error,
+            additional_info,
+        })
+    }
+}
+
+/// Task status reported once processing has finished successfully
+pub const TASK_STATUS_COMPLETED: &str = "processing-completed";
+
+/// Task status reported once processing has failed
+pub const TASK_STATUS_ERROR: &str = "processing-error";
+
+/// Number of tasks [`TasksHandler::wait_for_all`] polls at the same time
+pub const DEFAULT_WAIT_CONCURRENCY: usize = 8;
+
+/// Polling configuration for waiting on tasks
+#[derive(Debug, Clone, Copy)]
+pub struct TaskWaitOptions {
+    /// Delay between status checks
+    pub poll_interval: Duration,
+    /// Maximum time to wait before giving up
+    pub timeout: Duration,
+}
+
+impl Default for TaskWaitOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(600),
+        }
+    }
+}
+
+impl TaskWaitOptions {
+    /// Set the delay between status checks
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the maximum time to wait
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// A finished update task together with the re-fetched resource
+///
+/// The resource is fetched after the task reaches a final state, so it
+/// reflects the outcome of the update. A task that ends in
+/// `processing-error` is reported as [`CloudError::TaskFailed`] instead.
+#[derive(Debug, Clone)]
+pub struct Updated<T> {
+    /// Final state of the update task
+    pub task: TaskStateUpdate,
+    /// The resource as it is after the task finished
+    pub resource: T,
+}
+
+/// Outcome of waiting on one task of a batch
+#[derive(Debug, Clone)]
+pub struct TaskOutcome {
+    /// ID of the task
+    pub task_id: String,
+    /// Final state of the task, or why it could not be obtained
+    ///
+    /// A task that ended in `processing-error` is reported as
+    /// [`CloudError::TaskFailed`].
+    pub result: Result<TaskStateUpdate>,
+}
+
+impl TaskOutcome {
+    /// Returns true if the task completed successfully
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns true if the task failed, timed out, or could not be polled
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        self.result.is_err()
+    }
+}
+
+/// Outcomes of [`TasksHandler::wait_for_all`], in the order the IDs were given
+#[derive(Debug, Clone)]
+pub struct TaskBatch {
+    /// One outcome per task
+    pub outcomes: Vec<TaskOutcome>,
+}
+
+impl TaskBatch {
+    /// Returns true if every task completed successfully
+    #[must_use]
+    pub fn all_completed(&self) -> bool {
+        self.outcomes.iter().all(TaskOutcome::is_completed)
+    }
+
+    /// Tasks that completed successfully
+    pub fn completed(&self) -> impl Iterator<Item = &TaskStateUpdate> {
+        self.outcomes
+            .iter()
+            .filter_map(|outcome| outcome.result.as_ref().ok())
+    }
+
+    /// Tasks that failed, with the error for each
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &CloudError)> {
+        self.outcomes.iter().filter_map(|outcome| {
+            outcome
+                .result
+                .as_ref()
+                .err()
+                .map(|error| (outcome.task_id.as_str(), error))
+        })
+    }
+}
+
+// ============================================================================
+// Task results
+// ============================================================================
+
+/// Accessors for the outcome carried by a task response
+///
+/// Every create, update, and delete call returns a task whose result is
+/// nested in `response`. This trait reads it without unwrapping the options
+/// by hand and is implemented for all `TaskStateUpdate` types in the crate,
+/// so it works the same on flexible, fixed, and account-level tasks.
+///
+/// # Example
+///
+/// ```no_run
+/// use redis_cloud::CloudClient;
+/// use redis_cloud::tasks::{TaskResultExt, TaskWaitOptions};
+///
+/// # async fn example() -> redis_cloud::Result<()> {
+/// let client = CloudClient::builder()
+///     .api_key("your-api-key")
+///     .api_secret("your-api-secret")
+///     .build()?;
+///
+/// let task = client
+///     .tasks()
+///     .wait_for_task("task-id", &TaskWaitOptions::default())
+///     .await?;
+/// match task.error() {
+///     Some(error) => println!("Failed: {error}"),
+///     None => println!("Created resource {:?}", task.resource_id()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait TaskResultExt {
+    /// The processor response of the task, if any
+    fn processor_response(&self) -> Option<&ProcessorResponse>;
+
+    /// ID of the resource the task created or modified
+    ///
+    /// Falls back to the `id` field of the returned resource when the
+    /// response does not carry `resourceId`.
+    fn resource_id(&self) -> Option<i32> {
+        let response = self.processor_response()?;
+        response.resource_id.or_else(|| {
+            response
+                .resource
+                .as_ref()?
+                .get("id")?
+                .as_i64()
+                .and_then(|id| i32::try_from(id).ok())
+        })
+    }
+
+    /// ID of the secondary resource, such as the database of a new subscription
+    fn additional_resource_id(&self) -> Option<i32> {
+        self.processor_response()?.additional_resource_id
+    }
+
+    /// Error reported by a failed task
+    fn error(&self) -> Option<&str> {
+        self.processor_response()?.error.as_deref()
+    }
+
+    /// Decode the resource returned by the task into `T`
+    ///
+    /// A task without a resource decodes from an empty object, so `T` with
+    /// all-optional fields yields its default.
+    fn resource<T: DeserializeOwned>(&self) -> Result<T> {
+        let resource = self
+            .processor_response()
+            .and_then(|response| response.resource.clone())
+            .unwrap_or_default();
+        serde_json::from_value(serde_json::Value::Object(resource.into_iter().collect()))
+            .map_err(Into::into)
+    }
+}
+
+macro_rules! impl_task_result_ext {
+    ($($task:ty),+ $(,)?) => {
+        $(
+            impl TaskResultExt for $task {
+                fn processor_response(&self) -> Option<&ProcessorResponse> {
+                    self.response.as_ref()
+                }
+            }
+        )+
+    };
+}
+
+impl_task_result_ext!(
+    TaskStateUpdate,
+    crate::types::TaskStateUpdate,
+    crate::acl::TaskStateUpdate,
+    crate::cloud_accounts::TaskStateUpdate,
+    crate::users::TaskStateUpdate,
+    crate::flexible::databases::TaskStateUpdate,
+    crate::flexible::subscriptions::TaskStateUpdate,
+    crate::fixed::databases::TaskStateUpdate,
+    crate::fixed::subscriptions::TaskStateUpdate,
+);
+
 // ============================================================================
 // Handler
 // ============================================================================
@@ -159,6 +423,269 @@ impl TasksHandler {
     /// # }
     /// ```
     pub async fn get_task_by_id(&self, task_id: String) -> Result<TaskStateUpdate> {
+        let task_id = query::segment(&task_id);
         self.client.get(&format!("/tasks/{task_id}")).await
     }
+
+    /// Wait for a task to finish
+    /// Polls the task until it reaches `processing-completed` or
+    /// `processing-error` and returns the final state. Inspect
+    /// [`TaskStateUpdate::is_failed`] to detect failures, or call
+    /// [`TaskStateUpdate::into_result`] to turn them into errors.
+    ///
+    /// GET /tasks/{taskId}
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    /// use redis_cloud::tasks::TaskWaitOptions;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let options = TaskWaitOptions::default().timeout(Duration::from_secs(300));
+    /// let task = client.tasks().wait_for_task("task-id", &options).await?;
+    /// println!("Task finished: {:?}", task.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_task(
+        &self,
+        task_id: &str,
+        options: &TaskWaitOptions,
+    ) -> Result<TaskStateUpdate> {
+        let clock = &self.client.clock;
+        let started = clock.now();
+        loop {
+            let task = self.get_task_by_id(task_id.to_string()).await?;
+            if task.is_terminal() {
+                return Ok(task);
+            }
+            let elapsed = clock.now().saturating_duration_since(started);
+            if elapsed + options.poll_interval > options.timeout {
+                return Err(CloudError::TaskTimeout {
+                    task_id: task_id.to_string(),
+                });
+            }
+            clock.sleep(options.poll_interval).await;
+        }
+    }
+
+    /// Wait for several tasks to finish
+    ///
+    /// Polls up to [`DEFAULT_WAIT_CONCURRENCY`] tasks at a time, each with
+    /// the given options, and reports every task's outcome instead of
+    /// stopping at the first failure.
+    ///
+    /// GET /tasks/{taskId}
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    /// use redis_cloud::tasks::TaskWaitOptions;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let task_ids = ["task-1", "task-2", "task-3"];
+    /// let batch = client
+    ///     .tasks()
+    ///     .wait_for_all(&task_ids, &TaskWaitOptions::default())
+    ///     .await;
+    /// for (task_id, error) in batch.failed() {
+    ///     eprintln!("{task_id}: {error}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_all<S: AsRef<str>>(
+        &self,
+        task_ids: &[S],
+        options: &TaskWaitOptions,
+    ) -> TaskBatch {
+        self.wait_for_all_with_concurrency(task_ids, options, DEFAULT_WAIT_CONCURRENCY)
+            .await
+    }
+
+    /// Wait for several tasks to finish, polling at most `concurrency` at a time
+    ///
+    /// Like [`wait_for_all`](Self::wait_for_all), with a custom bound. A
+    /// `concurrency` of 0 is treated as 1.
+    pub async fn wait_for_all_with_concurrency<S: AsRef<str>>(
+        &self,
+        task_ids: &[S],
+        options: &TaskWaitOptions,
+        concurrency: usize,
+    ) -> TaskBatch {
+        let outcomes = stream::iter(task_ids)
+            .map(|task_id| async move {
+                let task_id = task_id.as_ref();
+                TaskOutcome {
+                    task_id: task_id.to_string(),
+                    result: self
+                        .wait_for_task(task_id, options)
+                        .await
+                        .and_then(TaskStateUpdate::into_result),
+                }
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+        TaskBatch { outcomes }
+    }
+
+    /// Watch a task as a stream of state updates
+    ///
+    /// Polls the task every `poll_interval` and yields each observed state
+    /// that differs from the previous one, starting with the first. The
+    /// stream ends after yielding a terminal state; a failed task is yielded
+    /// like any other state. If the task is still running after `timeout`,
+    /// the stream ends with [`CloudError::TaskTimeout`].
+    ///
+    /// GET /tasks/{taskId}
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    /// use redis_cloud::tasks::TaskWaitOptions;
+    /// use futures::StreamExt;
+    /// use std::pin::pin;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let tasks = client.tasks();
+    /// let options = TaskWaitOptions::default();
+    /// let mut updates = pin!(tasks.watch("task-id", &options));
+    /// while let Some(task) = updates.next().await {
+    ///     let task = task?;
+    ///     println!("{:?} {:?}%", task.status, task.progress);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch(
+        &self,
+        task_id: &str,
+        options: &TaskWaitOptions,
+    ) -> impl Stream<Item = Result<TaskStateUpdate>> + '_ {
+        let task_id = task_id.to_string();
+        let options = *options;
+        try_stream! {
+            let clock = &self.client.clock;
+            let started = clock.now();
+            let mut last: Option<TaskStateUpdate> = None;
+            loop {
+                let task = self.get_task_by_id(task_id.clone()).await?;
+                let terminal = task.is_terminal();
+                if last.as_ref() != Some(&task) {
+                    last = Some(task.clone());
+                    yield task;
+                }
+                if terminal {
+                    break;
+                }
+                let elapsed = clock.now().saturating_duration_since(started);
+                if elapsed + options.poll_interval > options.timeout {
+                    Err(CloudError::TaskTimeout {
+                        task_id: task_id.clone(),
+                    })?;
+                }
+                clock.sleep(options.poll_interval).await;
+            }
+        }
+    }
+
+    /// Wait for an update task to finish, then re-fetch the resource
+    /// Combines [`wait_for_task`](Self::wait_for_task) with the follow-up GET
+    /// so callers get both the task result and the fresh resource.
+    ///
+    /// `task_id` is the ID returned by the update call; a missing ID is
+    /// reported as an error since there is nothing to wait on. A failed task
+    /// is reported as [`CloudError::TaskFailed`] without fetching the resource.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use redis_cloud::CloudClient;
+    /// use redis_cloud::databases::DatabaseUpdateRequest;
+    /// use redis_cloud::tasks::TaskWaitOptions;
+    ///
+    /// # async fn example() -> redis_cloud::Result<()> {
+    /// let client = CloudClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .api_secret("your-api-secret")
+    ///     .build()?;
+    ///
+    /// let databases = client.databases();
+    /// let request = DatabaseUpdateRequest {
+    ///     enable_tls: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let task = databases.update_database(123, 456, &request).await?;
+    ///
+    /// let updated = client
+    ///     .tasks()
+    ///     .wait_and_fetch(task.task_id, &TaskWaitOptions::default(), || {
+    ///         databases.get_subscription_database_by_id(123, 456)
+    ///     })
+    ///     .await?;
+    /// println!("{:?} -> {:?}", updated.task.status, updated.resource.enable_tls);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_and_fetch<T, F, Fut>(
+        &self,
+        task_id: Option<String>,
+        options: &TaskWaitOptions,
+        fetch: F,
+    ) -> Result<Updated<T>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let task_id = task_id.ok_or_else(|| CloudError::UnexpectedResponse {
+            message: "Update response did not include a task ID".to_string(),
+        })?;
+
+        let task = self.wait_for_task(&task_id, options).await?.into_result()?;
+        let resource = fetch().await?;
+        Ok(Updated { task, resource })
+    }
+
+    /// Wait for a task and deserialize the resource it produced
+    /// Several read endpoints (VPC peerings, Transit Gateway attachments, PSC)
+    /// and dry runs answer with a task whose `response.resource` holds the
+    /// actual data. This waits for the task and decodes that map into `T`.
+    ///
+    /// A missing task ID is reported as [`CloudError::UnexpectedResponse`] and a task that ends in
+    /// `processing-error` as [`CloudError::TaskFailed`]; a completed task without a resource decodes
+    /// from an empty object.
+    pub async fn wait_for_resource<T: DeserializeOwned>(
+        &self,
+        task_id: Option<String>,
+        options: &TaskWaitOptions,
+    ) -> Result<T> {
+        let task_id = task_id.ok_or_else(|| CloudError::UnexpectedResponse {
+            message: "Response did not include a task ID".to_string(),
+        })?;
+
+        self.wait_for_task(&task_id, options)
+            .await?
+            .into_result()?
+            .resource()
+    }
 }
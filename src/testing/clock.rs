@@ -0,0 +1,202 @@
+//! Controllable clock for deterministic tests
+
+use crate::clock::{Clock, Sleep};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A [`Clock`] that only moves when told to
+///
+/// `sleep` completes immediately and advances the clock by the requested
+/// duration, so retry and polling loops run through their timeouts without
+/// real delays. Every sleep is recorded and can be inspected afterwards.
+/// Clones share the same time.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redis_cloud::CloudClient;
+/// use redis_cloud::tasks::TaskWaitOptions;
+/// use redis_cloud::testing::{MockCloudServer, TestClock};
+/// use std::time::Duration;
+///
+/// #[tokio::test]
+/// async fn task_wait_times_out() {
+///     let server = MockCloudServer::start().await;
+///     // ... mock GET /tasks/{id} to stay in "processing-in-progress"
+///
+///     let clock = TestClock::new();
+///     let client = CloudClient::builder()
+///         .api_key("test-key")
+///         .api_secret("test-secret")
+///         .base_url(server.uri())
+///         .clock(clock.clone())
+///         .build()
+///         .unwrap();
+///
+///     let options = TaskWaitOptions::default()
+///         .timeout(Duration::from_secs(60))
+///         .poll_interval(Duration::from_secs(10));
+///     let err = client.tasks().wait_for_task("task-1", &options).await.unwrap_err();
+///
+///     assert!(matches!(err, redis_cloud::CloudError::TaskTimeout { .. }));
+///     assert_eq!(clock.sleeps().len(), 6);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TestClock {
+    start: Instant,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    elapsed: Duration,
+    sleeps: Vec<Duration>,
+}
+
+impl TestClock {
+    /// A clock starting at the current instant
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            state: Arc::default(),
+        }
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, duration: Duration) {
+        self.state().elapsed += duration;
+    }
+
+    /// Total time the clock has moved since it was created
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.state().elapsed
+    }
+
+    /// Durations passed to `sleep`, in call order
+    #[must_use]
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.state().sleeps.clone()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        self.start + self.state().elapsed
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        let mut state = self.state();
+        state.elapsed += duration;
+        state.sleeps.push(duration);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sleep_advances_without_waiting() {
+        let clock = TestClock::new();
+        let before = clock.now();
+
+        clock.sleep(Duration::from_secs(3600)).await;
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(clock.now() - before, Duration::from_secs(3605));
+        assert_eq!(clock.elapsed(), Duration::from_secs(3605));
+        assert_eq!(clock.sleeps(), vec![Duration::from_secs(3600)]);
+    }
+
+    #[tokio::test]
+    async fn test_task_wait_timeout_without_real_delay() {
+        use crate::CloudClient;
+        use crate::tasks::TaskWaitOptions;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/tasks/task-slow"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "taskId": "task-slow",
+                "status": "processing-in-progress"
+            })))
+            .mount(&server)
+            .await;
+
+        let clock = TestClock::new();
+        let client = CloudClient::builder()
+            .api_key("test-key")
+            .api_secret("test-secret")
+            .base_url(server.uri())
+            .clock(clock.clone())
+            .build()
+            .unwrap();
+
+        let options = TaskWaitOptions::default()
+            .poll_interval(Duration::from_secs(10))
+            .timeout(Duration::from_secs(60));
+        let result = client.tasks().wait_for_task("task-slow", &options).await;
+
+        assert!(matches!(result, Err(crate::CloudError::TaskTimeout { .. })));
+        assert_eq!(clock.sleeps(), vec![Duration::from_secs(10); 6]);
+        assert_eq!(clock.elapsed(), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_backoff_exhausted() {
+        use crate::{CloudClient, RateLimitBackoff};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/subscriptions"))
+            .respond_with(ResponseTemplate::new(429).set_body_string("Too many requests"))
+            .mount(&server)
+            .await;
+
+        let clock = TestClock::new();
+        let client = CloudClient::builder()
+            .api_key("test-key")
+            .api_secret("test-secret")
+            .base_url(server.uri())
+            .retry_on_rate_limit(
+                RateLimitBackoff::default()
+                    .initial_delay(Duration::from_secs(1))
+                    .max_delay(Duration::from_secs(4))
+                    .max_elapsed(Duration::from_secs(10)),
+            )
+            .clock(clock.clone())
+            .build()
+            .unwrap();
+        let result: crate::Result<serde_json::Value> = client.get("/subscriptions").await;
+
+        assert!(matches!(result, Err(crate::CloudError::RateLimited { .. })));
+        // 1 + 2 + 4 fits the 10s budget; another 4s wait would not
+        assert_eq!(
+            clock.sleeps(),
+            vec![
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(4)
+            ]
+        );
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+}
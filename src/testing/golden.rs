@@ -0,0 +1,188 @@
+//! Golden JSON samples and serde round-trip checks
+//!
+//! The samples are taken from the examples in the Redis Cloud `OpenAPI`
+//! specification, trimmed to the fields the models cover. Each one is the
+//! exact wire format the API uses, so deserializing a sample into its model
+//! and serializing it back must reproduce every field. A field that comes
+//! back missing or changed means the model's serde naming has drifted from
+//! the API (for example `supportOssClusterApi` vs `supportOSSClusterApi`).
+//!
+//! The same checks can be run against your own captured responses:
+//!
+//! ```rust,ignore
+//! use redis_cloud::fixed::databases::FixedDatabase;
+//! use redis_cloud::testing::golden;
+//!
+//! #[test]
+//! fn fixed_database_matches_wire_format() {
+//!     let database: FixedDatabase = golden::assert_round_trip(&golden::fixed_database());
+//!     assert_eq!(database.database_id, Some(51324587));
+//! }
+//! ```
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Sample `Subscription` (Pro) response
+#[must_use]
+pub fn subscription() -> Value {
+    parse(include_str!("golden/subscription.json"))
+}
+
+/// Sample `Database` (Pro) response
+#[must_use]
+pub fn database() -> Value {
+    parse(include_str!("golden/database.json"))
+}
+
+/// Sample `FixedSubscription` (Essentials) response
+#[must_use]
+pub fn fixed_subscription() -> Value {
+    parse(include_str!("golden/fixed_subscription.json"))
+}
+
+/// Sample `FixedDatabase` (Essentials) response
+#[must_use]
+pub fn fixed_database() -> Value {
+    parse(include_str!("golden/fixed_database.json"))
+}
+
+/// Sample `RootAccount` response
+#[must_use]
+pub fn account() -> Value {
+    parse(include_str!("golden/account.json"))
+}
+
+/// Sample `AccountUser` response
+#[must_use]
+pub fn user() -> Value {
+    parse(include_str!("golden/user.json"))
+}
+
+/// Sample `CloudAccount` response
+#[must_use]
+pub fn cloud_account() -> Value {
+    parse(include_str!("golden/cloud_account.json"))
+}
+
+/// Sample `TaskStateUpdate` response
+#[must_use]
+pub fn task() -> Value {
+    parse(include_str!("golden/task.json"))
+}
+
+fn parse(sample: &str) -> Value {
+    serde_json::from_str(sample).expect("golden samples are valid JSON")
+}
+
+/// Deserialize `sample` into `T` and check that serializing it reproduces the sample
+///
+/// Returns the deserialized model, or a description of every field that
+/// failed to deserialize, was dropped, or changed value. Fields present in
+/// the serialized output but absent from the sample are ignored.
+pub fn check_round_trip<T>(sample: &Value) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let model: T = serde_path_to_error::deserialize(sample.clone())
+        .map_err(|e| format!("failed to deserialize `{}`: {}", e.path(), e.inner()))?;
+
+    let output =
+        serde_json::to_value(&model).map_err(|e| format!("failed to serialize model: {e}"))?;
+
+    let mut mismatches = Vec::new();
+    compare(sample, &output, "$", &mut mismatches);
+
+    if mismatches.is_empty() {
+        Ok(model)
+    } else {
+        Err(mismatches.join("\n"))
+    }
+}
+
+/// Like [`check_round_trip`], but panics with the list of mismatches
+pub fn assert_round_trip<T>(sample: &Value) -> T
+where
+    T: Serialize + DeserializeOwned,
+{
+    match check_round_trip(sample) {
+        Ok(model) => model,
+        Err(mismatches) => panic!(
+            "{} does not round-trip:\n{mismatches}",
+            std::any::type_name::<T>()
+        ),
+    }
+}
+
+fn compare(expected: &Value, actual: &Value, path: &str, mismatches: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let field_path = format!("{path}.{key}");
+                match actual.get(key) {
+                    Some(actual_value) => compare(value, actual_value, &field_path, mismatches),
+                    None => mismatches.push(format!("{field_path}: dropped by the model")),
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                compare(expected, actual, &format!("{path}[{index}]"), mismatches);
+            }
+        }
+        // Integers in samples may come back as floats (e.g. `2` vs `2.0`)
+        (Value::Number(expected), Value::Number(actual))
+            if expected.as_f64() == actual.as_f64() => {}
+        _ if expected == actual => {}
+        _ => mismatches.push(format!("{path}: expected {expected}, got {actual}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::RootAccount;
+    use crate::cloud_accounts::CloudAccount;
+    use crate::fixed::databases::FixedDatabase;
+    use crate::fixed::subscriptions::FixedSubscription;
+    use crate::flexible::databases::Database;
+    use crate::flexible::subscriptions::Subscription;
+    use crate::tasks::TaskStateUpdate;
+    use crate::users::AccountUser;
+
+    #[test]
+    fn test_samples_round_trip() {
+        assert_round_trip::<Subscription>(&subscription());
+        assert_round_trip::<Database>(&database());
+        assert_round_trip::<FixedSubscription>(&fixed_subscription());
+        assert_round_trip::<FixedDatabase>(&fixed_database());
+        assert_round_trip::<RootAccount>(&account());
+        assert_round_trip::<AccountUser>(&user());
+        assert_round_trip::<CloudAccount>(&cloud_account());
+        assert_round_trip::<TaskStateUpdate>(&task());
+    }
+
+    #[test]
+    fn test_dropped_field_is_reported() {
+        let mut sample = database();
+        let value = sample
+            .as_object_mut()
+            .unwrap()
+            .remove("supportOSSClusterApi")
+            .unwrap();
+        sample["supportOssClusterAPI"] = value;
+
+        let err = check_round_trip::<Database>(&sample).unwrap_err();
+        assert_eq!(err, "$.supportOssClusterAPI: dropped by the model");
+    }
+
+    #[test]
+    fn test_type_mismatch_is_reported() {
+        let mut sample = fixed_subscription();
+        sample["planId"] = Value::String("98276".to_string());
+
+        let err = check_round_trip::<FixedSubscription>(&sample).unwrap_err();
+        assert!(err.contains("planId"));
+    }
+}
@@ -9,6 +9,10 @@
 //! - **MockCloudServer**: A pre-configured mock server that mimics the Redis Cloud API
 //! - **Fixtures**: Builder-pattern fixtures for common response types
 //! - **Response helpers**: Convenience functions for creating HTTP responses
+//! - **TestClock**: A manually driven clock for testing retry and polling timeouts
+//! - **Sample account**: [`MockCloudServer::with_sample_account`] mounts a realistic
+//!   account topology in one line, with its IDs listed in [`sample`]
+//! - **Golden samples**: API wire-format samples and serde round-trip checks ([`golden`])
 //!
 //! # Quick Start
 //!
@@ -35,6 +39,31 @@
 //! }
 //! ```
 //!
+//! # Sample Account
+//!
+//! For demos and tests that need a realistic account rather than a single
+//! endpoint, start from the preset and refer to its IDs through [`sample`]:
+//!
+//! ```rust,ignore
+//! use redis_cloud::testing::{MockCloudServer, sample};
+//!
+//! #[tokio::test]
+//! async fn test_dashboard() {
+//!     let server = MockCloudServer::with_sample_account().await;
+//!     let client = server.client();
+//!
+//!     let dashboard = client.dashboard().await.unwrap();
+//!     assert_eq!(dashboard.database_count(), 5);
+//!
+//!     let databases = client
+//!         .databases()
+//!         .get_all_databases(sample::PRODUCTION_SUBSCRIPTION_ID)
+//!         .await
+//!         .unwrap();
+//!     assert_eq!(databases.len(), sample::PRODUCTION_DATABASES.len());
+//! }
+//! ```
+//!
 //! # Custom Mocking
 //!
 //! For scenarios not covered by the convenience methods, you can access
@@ -59,11 +88,15 @@
 //! }
 //! ```
 
+mod clock;
 mod fixtures;
+pub mod golden;
 mod responses;
+pub mod sample;
 mod server;
 
 // Re-export main types
+pub use clock::TestClock;
 pub use fixtures::{
     AccountFixture, DatabaseFixture, SubscriptionFixture, TaskFixture, UserFixture,
 };
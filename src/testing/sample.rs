@@ -0,0 +1,52 @@
+//! Identifiers of the sample account mounted by [`MockCloudServer::with_sample_account`]
+//!
+//! The sample account has two Pro subscriptions:
+//!
+//! | Subscription | ID | Provider | Databases |
+//! |--------------|----|----------|-----------|
+//! | `production` | [`PRODUCTION_SUBSCRIPTION_ID`] | AWS `us-east-1` | `orders`, `sessions`, `cache` |
+//! | `staging` | [`STAGING_SUBSCRIPTION_ID`] | GCP `us-central1` | `orders-staging`, `sessions-staging` |
+//!
+//! plus one VPC peering on the production subscription, an owner and a
+//! member user, and three tasks: two completed and one failed.
+//!
+//! [`MockCloudServer::with_sample_account`]: super::MockCloudServer::with_sample_account
+
+/// Account ID
+pub const ACCOUNT_ID: i32 = 12345;
+
+/// Account name
+pub const ACCOUNT_NAME: &str = "Sample Account";
+
+/// ID of the `production` subscription
+pub const PRODUCTION_SUBSCRIPTION_ID: i32 = 1001;
+
+/// ID of the `staging` subscription
+pub const STAGING_SUBSCRIPTION_ID: i32 = 1002;
+
+/// Databases of the `production` subscription as `(id, name)`
+pub const PRODUCTION_DATABASES: [(i32, &str); 3] = [(1, "orders"), (2, "sessions"), (3, "cache")];
+
+/// Databases of the `staging` subscription as `(id, name)`
+pub const STAGING_DATABASES: [(i32, &str); 2] = [(4, "orders-staging"), (5, "sessions-staging")];
+
+/// ID of the VPC peering on the `production` subscription
+pub const PEERING_ID: i32 = 501;
+
+/// Task returned by `GET /subscriptions/{PRODUCTION_SUBSCRIPTION_ID}/peerings`
+pub const PEERINGS_TASK_ID: &str = "sample-peerings-task";
+
+/// Completed task that created the `production` subscription
+pub const SUBSCRIPTION_CREATE_TASK_ID: &str = "sample-subscription-create";
+
+/// Completed task that created the `orders` database
+pub const DATABASE_CREATE_TASK_ID: &str = "sample-database-create";
+
+/// Failed task of a rejected database update
+pub const FAILED_TASK_ID: &str = "sample-database-update-failed";
+
+/// ID of the account owner
+pub const OWNER_USER_ID: i32 = 201;
+
+/// ID of the member user
+pub const MEMBER_USER_ID: i32 = 202;
@@ -5,6 +5,10 @@ use wiremock::matchers::{header, method, path, path_regex};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use crate::CloudClient;
+use crate::testing::fixtures::{
+    AccountFixture, DatabaseFixture, SubscriptionFixture, TaskFixture, UserFixture,
+};
+use crate::testing::sample;
 
 /// A mock server configured for Redis Cloud API testing
 ///
@@ -24,6 +28,155 @@ impl MockCloudServer {
         }
     }
 
+    /// Start a mock server preloaded with a sample account
+    ///
+    /// Mounts a coherent account topology: two Pro subscriptions with five
+    /// databases between them, a VPC peering, users, and tasks. See
+    /// [`sample`](crate::testing::sample) for the IDs and names used, so tests
+    /// can refer to them without repeating literals.
+    ///
+    /// Further mocks can be mounted on top, for example to add error cases.
+    pub async fn with_sample_account() -> Self {
+        let server = Self::start().await;
+        server.mount_sample_account().await;
+        server
+    }
+
+    /// Mount the sample account described in [`sample`](crate::testing::sample)
+    async fn mount_sample_account(&self) {
+        self.mock_account(
+            AccountFixture::new(sample::ACCOUNT_ID, sample::ACCOUNT_NAME)
+                .marketplace_status("active")
+                .build(),
+        )
+        .await;
+
+        let subscriptions = [
+            (
+                sample::PRODUCTION_SUBSCRIPTION_ID,
+                "production",
+                "AWS",
+                "us-east-1",
+                &sample::PRODUCTION_DATABASES[..],
+            ),
+            (
+                sample::STAGING_SUBSCRIPTION_ID,
+                "staging",
+                "GCP",
+                "us-central1",
+                &sample::STAGING_DATABASES[..],
+            ),
+        ];
+
+        let mut listed = Vec::new();
+        for (subscription_id, name, provider, region, databases) in subscriptions {
+            let subscription = SubscriptionFixture::new(subscription_id, name)
+                .payment_method_type("credit-card")
+                .cloud_provider(provider)
+                .region(region)
+                .build();
+            self.mock_subscription_get(subscription_id, subscription.clone())
+                .await;
+            listed.push(subscription);
+
+            let mut built = Vec::new();
+            for &(database_id, database_name) in databases {
+                let database = DatabaseFixture::new(database_id, database_name)
+                    .protocol("redis")
+                    .data_persistence("aof-every-1-second")
+                    .replication(true)
+                    .throughput("operations-per-second", 25_000)
+                    .public_endpoint(format!(
+                        "redis-{database_id}.c{subscription_id}.{region}.cloud.redislabs.com:{}",
+                        12000 + database_id
+                    ))
+                    .build();
+                self.mock_database_get(subscription_id, database_id, database.clone())
+                    .await;
+                built.push(database);
+            }
+            self.mock_databases_list(subscription_id, built).await;
+        }
+        self.mock_subscriptions_list(listed).await;
+
+        let peerings_task = TaskFixture::completed(sample::PEERINGS_TASK_ID, sample::PEERING_ID)
+            .command_type("vpcPeeringGetRequest")
+            .build();
+        let mut resolved = peerings_task.clone();
+        resolved["response"]["resource"] = json!({
+            "peerings": [{
+                "vpcPeeringId": sample::PEERING_ID,
+                "status": "active",
+                "awsAccountId": "123456789012",
+                "awsPeeringUid": "pcx-0sample",
+                "vpcUid": "vpc-0sample",
+                "vpcCidr": "10.0.0.0/24"
+            }]
+        });
+        self.mock_path(
+            "GET",
+            &format!(
+                "/subscriptions/{}/peerings",
+                sample::PRODUCTION_SUBSCRIPTION_ID
+            ),
+            ResponseTemplate::new(202).set_body_json(json!({
+                "taskId": sample::PEERINGS_TASK_ID,
+                "commandType": "vpcPeeringGetRequest",
+                "status": "received"
+            })),
+        )
+        .await;
+
+        let tasks = [
+            TaskFixture::completed(
+                sample::SUBSCRIPTION_CREATE_TASK_ID,
+                sample::PRODUCTION_SUBSCRIPTION_ID,
+            )
+            .command_type("subscriptionCreateRequest")
+            .build(),
+            TaskFixture::completed(
+                sample::DATABASE_CREATE_TASK_ID,
+                sample::PRODUCTION_DATABASES[0].0,
+            )
+            .command_type("databaseCreateRequest")
+            .build(),
+            TaskFixture::failed(sample::FAILED_TASK_ID, "Database memory limit exceeded")
+                .command_type("databaseUpdateRequest")
+                .build(),
+            resolved,
+        ];
+        for task in &tasks {
+            let task_id = task["taskId"].as_str().unwrap_or_default();
+            self.mock_task_get(task_id, task.clone()).await;
+        }
+        self.mock_tasks_list(tasks.to_vec()).await;
+
+        let users = [
+            (
+                sample::OWNER_USER_ID,
+                "owner@example.com",
+                "Account Owner",
+                "owner",
+            ),
+            (
+                sample::MEMBER_USER_ID,
+                "member@example.com",
+                "Team Member",
+                "member",
+            ),
+        ];
+        let mut listed = Vec::new();
+        for (user_id, email, name, role) in users {
+            let user = UserFixture::new(user_id, email)
+                .name(name)
+                .role(role)
+                .build();
+            self.mock_user_get(user_id, user.clone()).await;
+            listed.push(user);
+        }
+        self.mock_users_list(listed).await;
+    }
+
     /// Get the base URI of the mock server
     pub fn uri(&self) -> String {
         self.server.uri()
@@ -389,7 +542,6 @@ impl MockCloudServer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testing::fixtures::{DatabaseFixture, SubscriptionFixture, TaskFixture};
     use crate::{AccountHandler, DatabaseHandler, SubscriptionHandler, TaskHandler};
 
     #[tokio::test]
@@ -511,4 +663,50 @@ mod tests {
         assert_eq!(result[0].task_id, Some("task-1".to_string()));
         assert_eq!(result[1].task_id, Some("task-2".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_with_sample_account() {
+        let server = MockCloudServer::with_sample_account().await;
+        let client = server.client();
+
+        let dashboard = client.dashboard().await.unwrap();
+        assert_eq!(dashboard.subscriptions.len(), 2);
+        assert_eq!(dashboard.database_count(), 5);
+        assert!(!dashboard.has_failures());
+
+        let database = DatabaseHandler::new(client.clone())
+            .get_subscription_database_by_id(
+                sample::STAGING_SUBSCRIPTION_ID,
+                sample::STAGING_DATABASES[1].0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            database.name.as_deref(),
+            Some(sample::STAGING_DATABASES[1].1)
+        );
+
+        let peerings = client
+            .vpc_peering()
+            .list(
+                sample::PRODUCTION_SUBSCRIPTION_ID,
+                &crate::tasks::TaskWaitOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(peerings.len(), 1);
+        assert_eq!(peerings[0].id, Some(sample::PEERING_ID));
+
+        let failed = TaskHandler::new(client.clone())
+            .get_task_by_id(sample::FAILED_TASK_ID.to_string())
+            .await
+            .unwrap();
+        assert_eq!(failed.status.as_deref(), Some("processing-error"));
+
+        let account = AccountHandler::new(client)
+            .get_current_account()
+            .await
+            .unwrap();
+        assert_eq!(account.account.unwrap().id, Some(sample::ACCOUNT_ID));
+    }
 }
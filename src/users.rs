@@ -14,13 +14,18 @@
 //!
 //! - **Owner**: Full administrative access to all resources
 //! - **Manager**: Can manage subscriptions and databases
+//! - **Member**: Can manage databases
 //! - **Viewer**: Read-only access to resources
 //! - **Billing Admin**: Access to billing and payment information
-//! - **Custom Roles**: Organization-specific roles with custom permissions
+//!
+//! Roles are typed as [`UserRole`](crate::types::UserRole).
+//!
+//! Users are invited from the Redis Cloud console; the public API has no
+//! invitation endpoint, so this module manages existing users only.
 //!
 //! # Key Features
 //!
-//! - **User Lifecycle**: Create, update, delete, and invite users
+//! - **User Lifecycle**: Look up, update, and delete users
 //! - **Role Management**: Assign and modify user roles
 //! - **Password Policies**: Enforce password complexity and rotation
 //! - **MFA Support**: Two-factor authentication configuration
@@ -49,8 +54,8 @@
 //! # }
 //! ```
 
-use crate::types::{Link, ProcessorResponse};
-use crate::{CloudClient, Result};
+use crate::types::{Link, NameMatches, ProcessorResponse, Timestamp, UserRole};
+use crate::{CloudClient, CloudError, Result};
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -58,7 +63,7 @@ use serde::{Deserialize, Serialize};
 // ============================================================================
 
 /// User update request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountUserUpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,8 +80,26 @@ pub struct AccountUserUpdateRequest {
     pub command_type: Option<String>,
 }
 
+impl AccountUserUpdateRequest {
+    /// Update request keeping or changing the user's `name`
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Also change the user's account role
+    #[must_use]
+    pub fn with_role(mut self, role: UserRole) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+}
+
 /// Account users response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountUsers {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub account: Option<i32>,
@@ -91,7 +114,7 @@ pub struct AccountUsers {
 }
 
 /// User options information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountUserOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -108,7 +131,7 @@ pub struct AccountUserOptions {
 }
 
 /// Task state update response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskStateUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -124,7 +147,7 @@ pub struct TaskStateUpdate {
     pub description: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub response: Option<ProcessorResponse>,
@@ -135,7 +158,7 @@ pub struct TaskStateUpdate {
 }
 
 /// User information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountUser {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -167,14 +190,129 @@ pub struct AccountUser {
     pub links: Option<Vec<Link>>,
 }
 
+impl AccountUser {
+    /// The user's account role, if reported
+    #[must_use]
+    pub fn user_role(&self) -> Option<UserRole> {
+        self.role.as_deref().map(UserRole::from)
+    }
+
+    /// Returns true if the user has multi-factor authentication turned on
+    #[must_use]
+    pub fn mfa_enabled(&self) -> bool {
+        self.options
+            .as_ref()
+            .and_then(|options| options.mfa_enabled)
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the user signs in with a Redis Cloud password rather
+    /// than through SSO or a social login
+    #[must_use]
+    pub fn is_local(&self) -> bool {
+        self.user_type_in(&["local"])
+    }
+
+    /// Returns true if the user is known to sign in through SAML SSO
+    #[must_use]
+    pub fn is_sso(&self) -> bool {
+        self.user_type_in(SSO_USER_TYPES)
+    }
+
+    /// Returns true if the user's `userType` is missing or not one this
+    /// client recognizes, so their sign-in method cannot be determined
+    #[must_use]
+    pub fn has_unknown_user_type(&self) -> bool {
+        !self.is_local() && !self.is_sso() && !self.user_type_in(SOCIAL_USER_TYPES)
+    }
+
+    fn user_type_in(&self, types: &[&str]) -> bool {
+        self.user_type.as_deref().is_some_and(|user_type| {
+            types
+                .iter()
+                .any(|expected| user_type.eq_ignore_ascii_case(expected))
+        })
+    }
+}
+
+/// `userType` values of users signing in through SAML SSO
+const SSO_USER_TYPES: &[&str] = &["saml", "sso"];
+
+/// `userType` values of users signing in with a social login
+const SOCIAL_USER_TYPES: &[&str] = &["google", "github"];
+
+/// Sign-in security of the account's users
+///
+/// The API has no account-wide setting for enforcing SSO or requiring MFA;
+/// both are configured in the console. This summary is built from the
+/// per-user data the API does expose, so compliance checks can assert that
+/// every user has MFA enabled and signs in through SSO. Only users whose
+/// `userType` identifies SAML SSO count as using SSO; local and social logins
+/// do not, and users with an unrecognized type are listed separately.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserSecuritySummary {
+    /// Total number of users
+    pub total_users: usize,
+    /// Users without multi-factor authentication
+    pub without_mfa: Vec<AccountUser>,
+    /// Users not known to sign in through SSO
+    pub without_sso: Vec<AccountUser>,
+    /// Users signing in with a local password instead of SSO
+    pub local_users: Vec<AccountUser>,
+    /// Users whose sign-in method cannot be determined from `userType`
+    pub unknown_user_type: Vec<AccountUser>,
+}
+
+impl UserSecuritySummary {
+    /// Summarize a list of users
+    #[must_use]
+    pub fn from_users(users: &[AccountUser]) -> Self {
+        Self {
+            total_users: users.len(),
+            without_mfa: users
+                .iter()
+                .filter(|user| !user.mfa_enabled())
+                .cloned()
+                .collect(),
+            without_sso: users
+                .iter()
+                .filter(|user| !user.is_sso())
+                .cloned()
+                .collect(),
+            local_users: users
+                .iter()
+                .filter(|user| user.is_local())
+                .cloned()
+                .collect(),
+            unknown_user_type: users
+                .iter()
+                .filter(|user| user.has_unknown_user_type())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Returns true if every user has MFA enabled
+    #[must_use]
+    pub fn all_users_have_mfa(&self) -> bool {
+        self.without_mfa.is_empty()
+    }
+
+    /// Returns true if every user is known to sign in through SSO
+    #[must_use]
+    pub fn all_users_use_sso(&self) -> bool {
+        self.without_sso.is_empty()
+    }
+}
+
 // ============================================================================
 // Handler
 // ============================================================================
 
 /// Handler for user management operations
 ///
-/// Manages user accounts, roles, permissions, invitations,
-/// and authentication settings including MFA configuration.
+/// Lists, updates, and deletes account users, changes their roles, and
+/// summarizes their MFA and SSO sign-in settings.
 pub struct UsersHandler {
     client: CloudClient,
 }
@@ -222,4 +360,60 @@ impl UsersHandler {
     ) -> Result<TaskStateUpdate> {
         self.client.put(&format!("/users/{user_id}"), request).await
     }
+
+    /// Find a user by email address
+    /// Emails are compared case-insensitively. Returns `None` if no user has
+    /// the address.
+    ///
+    /// GET /users
+    pub async fn find_by_email(&self, email: &str) -> Result<Option<AccountUser>> {
+        let users = self.get_all_users().await?.users.unwrap_or_default();
+        Ok(users.into_iter().find(|user| {
+            user.email
+                .as_deref()
+                .is_some_and(|candidate| candidate.trim().eq_ignore_ascii_case(email.trim()))
+        }))
+    }
+
+    /// Find users by name
+    /// Names are not unique; see [`NameMatches`].
+    ///
+    /// GET /users
+    pub async fn find_by_name(&self, name: &str) -> Result<NameMatches<AccountUser>> {
+        let users = self.get_all_users().await?.users.unwrap_or_default();
+        Ok(NameMatches::from_items(name, users, |user| {
+            user.name.as_deref()
+        }))
+    }
+
+    /// Change a user's account role
+    /// Fetches the user to keep their name, then updates the role. Returns
+    /// `None` without calling the API if the user already has `role`.
+    ///
+    /// GET /users/{userId}, then PUT /users/{userId}
+    pub async fn change_role(
+        &self,
+        user_id: i32,
+        role: UserRole,
+    ) -> Result<Option<TaskStateUpdate>> {
+        let user = self.get_user_by_id(user_id).await?;
+        if user.user_role().as_ref() == Some(&role) {
+            return Ok(None);
+        }
+        let name = user.name.ok_or_else(|| CloudError::UnexpectedResponse {
+            message: format!("User {user_id} was returned without a name"),
+        })?;
+        let request = AccountUserUpdateRequest::new(name).with_role(role);
+        self.update_user(user_id, &request).await.map(Some)
+    }
+
+    /// Summarize MFA and SSO usage across all users
+    /// Lists the account's users and reports which of them lack MFA or are not
+    /// known to sign in through SSO. See [`UserSecuritySummary`].
+    ///
+    /// GET /users
+    pub async fn get_security_summary(&self) -> Result<UserSecuritySummary> {
+        let users = self.get_all_users().await?.users.unwrap_or_default();
+        Ok(UserSecuritySummary::from_users(&users))
+    }
 }
@@ -0,0 +1,334 @@
+//! Redis version parsing and comparison
+//!
+//! The API reports Redis versions as loose strings: `"7.2"`, `"7.4.0"`,
+//! `"7.4-preview"`. [`Version`] parses them into an ordered value and
+//! [`RedisVersionReq`] expresses constraints such as "7.2 or later", so
+//! upgrade decisions don't rely on string comparison (where `"10.0" < "7.2"`).
+//!
+//! # Example
+//!
+//! ```
+//! use redis_cloud::version::{RedisVersionReq, Version};
+//!
+//! let req: RedisVersionReq = ">=7.2, <8".parse().unwrap();
+//! assert!(req.matches(&"7.4".parse::<Version>().unwrap()));
+//! assert!(!req.matches(&"6.2.6".parse::<Version>().unwrap()));
+//!
+//! // Previews sort before the release they precede
+//! let preview: Version = "7.4-preview".parse().unwrap();
+//! assert!(preview.is_preview());
+//! assert!(preview < "7.4".parse().unwrap());
+//! assert!(preview > "7.2".parse().unwrap());
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// A version string that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid Redis version requirement or version: '{0}'")]
+pub struct ParseVersionError(String);
+
+/// A parsed Redis version
+///
+/// Missing components count as zero, so `7.2` and `7.2.0` are equal. A
+/// pre-release suffix (`-preview`, `-rc1`, ...) sorts before the release with
+/// the same numbers.
+#[derive(Debug, Clone)]
+pub struct Version {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    pre: Option<String>,
+}
+
+impl Version {
+    /// Version with the given components and no pre-release suffix
+    #[must_use]
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre: None,
+        }
+    }
+
+    /// Major version
+    #[must_use]
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// Minor version
+    #[must_use]
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    /// Patch version, zero if not given
+    #[must_use]
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+
+    /// Pre-release suffix without the leading `-`, e.g. `preview`
+    #[must_use]
+    pub fn pre(&self) -> Option<&str> {
+        self.pre.as_deref()
+    }
+
+    /// Returns true if the version has a pre-release suffix
+    #[must_use]
+    pub fn is_preview(&self) -> bool {
+        self.pre.is_some()
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseVersionError(s.to_string());
+        let trimmed = s.trim();
+        let trimmed = trimmed
+            .strip_prefix('v')
+            .or_else(|| trimmed.strip_prefix('V'))
+            .unwrap_or(trimmed);
+        let (numbers, pre) = match trimmed.split_once('-') {
+            Some((numbers, pre)) if !pre.is_empty() => (numbers, Some(pre.to_string())),
+            Some(_) => return Err(invalid()),
+            None => (trimmed, None),
+        };
+
+        let mut parts = numbers.split('.').map(|part| part.parse::<u32>());
+        let major = parts.next().and_then(|p| p.ok()).ok_or_else(invalid)?;
+        let minor = parts.next().transpose().map_err(|_| invalid())?;
+        let patch = parts.next().transpose().map_err(|_| invalid())?;
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            major,
+            minor: minor.unwrap_or(0),
+            patch: patch.unwrap_or(0),
+            pre,
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.patch != 0 {
+            write!(f, ".{}", self.patch)?;
+        }
+        if let Some(pre) = &self.pre {
+            write!(f, "-{pre}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+/// Comparison applied by one [`RedisVersionReq`] comparator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// `=`
+    Exact,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEq,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEq,
+}
+
+impl Op {
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+        }
+    }
+}
+
+/// A requirement on a Redis version, such as `>=7.2` or `>=7.2, <8`
+///
+/// All comparators must hold. A bare version (`7.2`) means `=7.2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedisVersionReq {
+    comparators: Vec<(Op, Version)>,
+}
+
+impl RedisVersionReq {
+    /// Requirement that holds for `version` and anything later
+    #[must_use]
+    pub fn at_least(version: Version) -> Self {
+        Self {
+            comparators: vec![(Op::GreaterEq, version)],
+        }
+    }
+
+    /// Requirement that holds for versions earlier than `version`
+    #[must_use]
+    pub fn below(version: Version) -> Self {
+        Self {
+            comparators: vec![(Op::Less, version)],
+        }
+    }
+
+    /// Returns true if `version` satisfies every comparator
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators.iter().all(|(op, bound)| match op {
+            Op::Exact => version == bound,
+            Op::Greater => version > bound,
+            Op::GreaterEq => version >= bound,
+            Op::Less => version < bound,
+            Op::LessEq => version <= bound,
+        })
+    }
+
+    /// Parse `version` and check it against the requirement
+    ///
+    /// Unparseable versions never match.
+    #[must_use]
+    pub fn matches_str(&self, version: &str) -> bool {
+        version.parse().is_ok_and(|version| self.matches(&version))
+    }
+
+    /// The comparators making up the requirement
+    #[must_use]
+    pub fn comparators(&self) -> &[(Op, Version)] {
+        &self.comparators
+    }
+}
+
+impl FromStr for RedisVersionReq {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let comparators = s
+            .split(',')
+            .map(|part| {
+                let part = part.trim();
+                let (op, version) = [
+                    (">=", Op::GreaterEq),
+                    ("<=", Op::LessEq),
+                    (">", Op::Greater),
+                    ("<", Op::Less),
+                    ("=", Op::Exact),
+                ]
+                .into_iter()
+                .find_map(|(symbol, op)| part.strip_prefix(symbol).map(|rest| (op, rest)))
+                .unwrap_or((Op::Exact, part));
+                version
+                    .parse()
+                    .map(|version| (op, version))
+                    .map_err(|_| ParseVersionError(s.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { comparators })
+    }
+}
+
+impl fmt::Display for RedisVersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (op, version)) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}{version}", op.symbol())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(s: &str) -> Version {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_normalize() {
+        assert_eq!(v("7.2"), v("7.2.0"));
+        assert_eq!(v("v7.4.1").patch(), 1);
+        assert_eq!(v(" 8 ").to_string(), "8.0");
+        assert_eq!(v("7.4-preview").pre(), Some("preview"));
+        assert_eq!(v("7.4-preview").to_string(), "7.4-preview");
+
+        for bad in ["", "seven", "7.x", "7.2.1.0", "7.2-", "-preview"] {
+            assert!(bad.parse::<Version>().is_err(), "{bad:?} should not parse");
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(v("10.0") > v("7.2"));
+        assert!(v("7.2.4") > v("7.2"));
+        assert!(v("7.4-preview") < v("7.4"));
+        assert!(v("7.4-preview") > v("7.2.6"));
+
+        let mut versions = [v("7.4"), v("6.2.6"), v("7.4-preview"), v("7.2")];
+        versions.sort();
+        let sorted: Vec<_> = versions.iter().map(ToString::to_string).collect();
+        assert_eq!(sorted, ["6.2.6", "7.2", "7.4-preview", "7.4"]);
+    }
+
+    #[test]
+    fn test_requirements() {
+        let req: RedisVersionReq = ">=7.2, <8".parse().unwrap();
+        assert!(req.matches(&v("7.2")));
+        assert!(req.matches(&v("7.4-preview")));
+        assert!(!req.matches(&v("8.0")));
+        assert!(!req.matches(&v("6.2")));
+        assert_eq!(req.to_string(), ">=7.2, <8.0");
+
+        assert!(
+            "7.2"
+                .parse::<RedisVersionReq>()
+                .unwrap()
+                .matches_str("7.2.0")
+        );
+        assert!(RedisVersionReq::at_least(Version::new(7, 2, 0)).matches_str("7.4"));
+        assert!(!RedisVersionReq::below(Version::new(7, 2, 0)).matches_str("not-a-version"));
+        assert!(">=".parse::<RedisVersionReq>().is_err());
+    }
+}
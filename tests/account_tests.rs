@@ -1,3 +1,5 @@
+use chrono::NaiveDate;
+use redis_cloud::account::PaymentMethods;
 use redis_cloud::{AccountHandler, CloudClient};
 use serde_json::json;
 use wiremock::matchers::{header, method, path, query_param};
@@ -130,6 +132,64 @@ async fn test_get_supported_database_modules() {
     assert!(result.modules.is_some());
 }
 
+#[tokio::test]
+async fn test_get_database_modules() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/database-modules"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "modules": [
+                {
+                    "name": "RedisJSON",
+                    "capabilityName": "JSON",
+                    "description": "Native JSON Data Type for Redis",
+                    "version": "2.8.4",
+                    "parameters": []
+                },
+                {
+                    "name": "RediSearch",
+                    "capabilityName": "Search and query",
+                    "description": "Search and query engine",
+                    "parameters": [
+                        {
+                            "name": "number-of-documents",
+                            "description": "Expected number of documents the database module will be indexing",
+                            "type": "integer",
+                            "defaultValue": 1000000,
+                            "required": false
+                        }
+                    ]
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = AccountHandler::new(client);
+    let modules = handler.get_database_modules().await.unwrap();
+
+    assert_eq!(modules.len(), 2);
+    assert_eq!(modules[0].name.as_deref(), Some("RedisJSON"));
+    assert_eq!(modules[0].version.as_deref(), Some("2.8.4"));
+    assert!(modules[1].matches("search and query"));
+
+    let parameter = modules[1].parameter("number-of-documents").unwrap();
+    assert_eq!(parameter.r#type.as_deref(), Some("integer"));
+    assert_eq!(parameter.default_value, Some(1_000_000));
+    assert_eq!(parameter.required, Some(false));
+    assert!(modules[1].parameter("missing").is_none());
+}
+
 #[tokio::test]
 async fn test_get_supported_regions() {
     let mock_server = MockServer::start().await;
@@ -205,6 +265,33 @@ async fn test_get_account_payment_methods() {
     assert!(result.account_id.is_some());
 }
 
+#[test]
+fn test_payment_methods_expiring_within() {
+    let methods: PaymentMethods = serde_json::from_value(json!({
+        "accountId": 123,
+        "paymentMethods": [
+            {"id": 1, "expirationMonth": 12, "expirationYear": 2024},
+            {"id": 2, "expirationMonth": 2, "expirationYear": 2025},
+            {"id": 3, "expirationMonth": 6, "expirationYear": 2026},
+            {"id": 4}
+        ]
+    }))
+    .unwrap();
+
+    let today = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+    let expiring: Vec<i32> = methods
+        .expiring_within(3, today)
+        .iter()
+        .filter_map(|pm| pm.id)
+        .collect();
+    assert_eq!(expiring, vec![1, 2]);
+
+    let card = &methods.payment_methods.as_ref().unwrap()[1];
+    assert_eq!(card.months_until_expiry(today), Some(1));
+    assert!(card.expires_within(1, today));
+    assert!(!card.expires_within(0, today));
+}
+
 #[tokio::test]
 async fn test_get_account_system_logs() {
     let mock_server = MockServer::start().await;
@@ -727,3 +814,195 @@ async fn test_current_account_with_full_response() {
     let owner = key.owner.unwrap();
     assert_eq!(owner.email, Some("test@example.com".to_string()));
 }
+
+#[tokio::test]
+async fn test_stream_system_logs() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/logs"))
+        .and(query_param("offset", "0"))
+        .and(query_param("limit", "2"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "entries": [
+                {
+                    "id": 101,
+                    "time": "2024-03-01T10:00:00Z",
+                    "originator": "ops@example.com",
+                    "type": "Subscription",
+                    "description": "Subscription created"
+                },
+                {
+                    "id": 100,
+                    "time": "2024-02-29T09:00:00Z",
+                    "originator": "ci-key",
+                    "apiKeyName": "ci-key",
+                    "type": "Database",
+                    "description": "Database updated"
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/logs"))
+        .and(query_param("offset", "2"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "entries": [
+                {
+                    "id": 99,
+                    "time": "2024-02-28T08:00:00Z",
+                    "originator": "ops@example.com",
+                    "type": "Account",
+                    "description": "User invited"
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = AccountHandler::new(client);
+
+    use futures::StreamExt;
+    use std::pin::pin;
+    let mut entries = Vec::new();
+    let mut stream = pin!(handler.stream_system_logs_with_page_size(2));
+    while let Some(result) = stream.next().await {
+        entries.push(result.unwrap());
+    }
+
+    let ids: Vec<_> = entries.iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![Some(101), Some(100), Some(99)]);
+    assert_eq!(entries[1].api_key_name.as_deref(), Some("ci-key"));
+    assert_eq!(entries[2].r#type.as_deref(), Some("Account"));
+}
+
+#[tokio::test]
+async fn test_stream_session_logs_empty() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/session-logs"))
+        .and(query_param("offset", "0"))
+        .and(query_param("limit", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"entries": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = AccountHandler::new(client);
+
+    use futures::StreamExt;
+    use std::pin::pin;
+    let mut stream = pin!(handler.stream_session_logs());
+    assert!(stream.next().await.is_none());
+}
+
+async fn mock_system_log_history() -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/logs"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "entries": [
+                {"id": 4, "time": "2024-03-02T00:00:00Z", "description": "Out of range"},
+                {"id": 3, "time": "2024-03-01T23:59:59Z", "description": "Database deleted"},
+                {"id": 2, "description": "No timestamp"},
+                {"id": 1, "time": "2024-03-01T00:00:00Z", "description": "Database created"},
+                {"id": 0, "time": "2024-02-29T23:59:59Z", "description": "Out of range"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn day(date: &str) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::parse_from_rfc3339(date).unwrap().to_utc()
+}
+
+#[tokio::test]
+async fn test_export_system_logs() {
+    let mock_server = mock_system_log_history().await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let mut output = Vec::new();
+    let written = AccountHandler::new(client)
+        .export_system_logs(
+            day("2024-03-01T00:00:00Z"),
+            day("2024-03-02T00:00:00Z"),
+            &mut output,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(written, 2);
+    let lines: Vec<serde_json::Value> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["id"], 3);
+    assert_eq!(lines[1]["description"], "Database created");
+}
+
+#[cfg(feature = "gzip")]
+#[tokio::test]
+async fn test_export_system_logs_gzip() {
+    use std::io::Read;
+
+    let mock_server = mock_system_log_history().await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let mut output = Vec::new();
+    let written = AccountHandler::new(client)
+        .export_system_logs_gzip(
+            day("2024-03-01T00:00:00Z"),
+            day("2024-03-02T00:00:00Z"),
+            &mut output,
+        )
+        .await
+        .unwrap();
+    assert_eq!(written, 2);
+
+    let mut decompressed = String::new();
+    flate2::read::GzDecoder::new(output.as_slice())
+        .read_to_string(&mut decompressed)
+        .unwrap();
+    assert_eq!(decompressed.lines().count(), 2);
+    assert!(decompressed.starts_with(r#"{"id":3,"#));
+}
@@ -1,6 +1,13 @@
-use redis_cloud::{AclHandler, CloudClient};
+use redis_cloud::acl::{
+    AclChange, AclEntity, AclRedisRuleCreateRequest, AclRoleCreateRequest, AclRoleDatabaseSpec,
+    AclRule, AclSpec, AclSyncOptions, AclUserCreateRequest, FullAccess, ReadOnly, ReadWriteNoAdmin,
+    RuleTemplate,
+};
+use redis_cloud::tasks::TaskWaitOptions;
+use redis_cloud::{AclHandler, CloudClient, CloudError};
 use serde_json::json;
-use wiremock::matchers::{header, method, path};
+use std::time::Duration;
+use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -70,7 +77,7 @@ async fn test_create_redis_rule() {
     let request = redis_cloud::acl::AclRedisRuleCreateRequest {
         name: "test-rule".to_string(),
         redis_rule: "+get +set".to_string(),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_redis_rule(&request).await.unwrap();
@@ -172,7 +179,7 @@ async fn test_create_user() {
         name: "test-user".to_string(),
         role: "test-role".to_string(),
         password: "test-password".to_string(),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_user(&request).await.unwrap();
@@ -241,7 +248,7 @@ async fn test_update_redis_rule() {
         redis_rule_id: Some(123),
         name: "updated-rule".to_string(),
         redis_rule: "+get +set +del".to_string(),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.update_redis_rule(123, &request).await.unwrap();
@@ -289,7 +296,7 @@ async fn test_create_role() {
                 regions: Some(vec!["us-east-1".to_string()]),
             }],
         }],
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_role(&request).await.unwrap();
@@ -362,11 +369,11 @@ async fn test_update_role() {
             databases: vec![redis_cloud::acl::AclRoleDatabaseSpec {
                 subscription_id: 101,
                 database_id: 201,
-                regions: None,
+                ..Default::default()
             }],
         }]),
         role_id: Some(789),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.update_role(789, &request).await.unwrap();
@@ -374,6 +381,50 @@ async fn test_update_role() {
     assert_eq!(result.command_type, Some("UPDATE_ROLE".to_string()));
 }
 
+#[tokio::test]
+async fn test_update_role_from_value() {
+    let mock_server = MockServer::start().await;
+
+    let body = json!({
+        "name": "bulk-role",
+        "redisRules": [{
+            "ruleName": "Read-Only",
+            "databases": (1..=500)
+                .map(|id| json!({"subscriptionId": 101, "databaseId": id}))
+                .collect::<Vec<_>>()
+        }]
+    });
+
+    Mock::given(method("PUT"))
+        .and(path("/acl/roles/789"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(header("content-type", "application/json"))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-update-role-789",
+            "commandType": "UPDATE_ROLE",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let bytes = serde_json::to_vec(&body).unwrap();
+    let result = AclHandler::new(client)
+        .update_role_from_value(789, bytes)
+        .await
+        .unwrap();
+    assert_eq!(result.task_id.as_deref(), Some("task-update-role-789"));
+}
+
 #[tokio::test]
 async fn test_get_all_users() {
     let mock_server = MockServer::start().await;
@@ -478,7 +529,7 @@ async fn test_update_user() {
         user_id: Some(456),
         role: Some("updated-role".to_string()),
         password: Some("new-password".to_string()),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.update_acl_user(456, &request).await.unwrap();
@@ -568,10 +619,10 @@ async fn test_create_role_without_regions() {
             databases: vec![redis_cloud::acl::AclRoleDatabaseSpec {
                 subscription_id: 300,
                 database_id: 400,
-                regions: None,
+                ..Default::default()
             }],
         }],
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_role(&request).await.unwrap();
@@ -677,7 +728,7 @@ async fn test_task_state_update_with_error() {
     let request = redis_cloud::acl::AclRedisRuleCreateRequest {
         name: "invalid-rule".to_string(),
         redis_rule: "+invalid".to_string(),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_redis_rule(&request).await.unwrap();
@@ -786,7 +837,7 @@ async fn test_error_handling_500() {
         name: "test-user".to_string(),
         role: "test-role".to_string(),
         password: "test-password".to_string(),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_user(&request).await;
@@ -846,10 +897,640 @@ async fn test_create_user_with_full_response() {
     assert_eq!(result.task_id, Some("task-789".to_string()));
     assert_eq!(result.command_type, Some("CREATE_USER".to_string()));
     assert_eq!(result.status, Some("processing".to_string()));
-    assert_eq!(result.timestamp, Some("2024-01-01T16:00:00Z".to_string()));
+    assert_eq!(
+        serde_json::to_value(result.timestamp).unwrap(),
+        json!("2024-01-01T16:00:00Z")
+    );
     assert!(result.links.is_some());
 
     let response = result.response.unwrap();
     assert_eq!(response.resource_id, Some(999));
     assert_eq!(response.additional_resource_id, Some(888));
 }
+
+#[test]
+fn test_rule_templates() {
+    assert_eq!(ReadOnly::all_keys().to_string(), "+@read ~*");
+    assert_eq!(ReadOnly::on_prefix("cache:").to_string(), "+@read ~cache:*");
+    assert_eq!(
+        ReadOnly::on_prefix("cache:*").to_string(),
+        "+@read ~cache:*"
+    );
+    assert_eq!(
+        ReadWriteNoAdmin::on_prefix("session:").to_string(),
+        "+@all -@dangerous ~session:*"
+    );
+    assert_eq!(
+        FullAccess::on_pattern("user:{*}").to_string(),
+        "+@all ~user:{*}"
+    );
+}
+
+#[tokio::test]
+async fn test_create_redis_rule_from_template() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/acl/redisRules"))
+        .and(body_json(json!({
+            "name": "cache-readers",
+            "redisRule": "+@read ~cache:*"
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-rule",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let request = ReadOnly::on_prefix("cache:").create_request("cache-readers");
+    let task = AclHandler::new(client)
+        .create_redis_rule(&request)
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-rule"));
+}
+
+#[test]
+fn test_acl_rule_builder() {
+    let rule = AclRule::builder()
+        .read()
+        .deny_category("@dangerous")
+        .allow_command("client|setname")
+        .key_prefix("cache:")
+        .channel_pattern("events:*")
+        .build()
+        .unwrap();
+    assert_eq!(
+        rule.to_string(),
+        "+@read -@dangerous +client|setname ~cache:* &events:*"
+    );
+
+    // No key selector means all keys
+    let rule = AclRule::builder().write().build().unwrap();
+    assert_eq!(rule.as_str(), "+@write ~*");
+
+    // Templates produce valid rules
+    assert!(AclRule::parse(&ReadWriteNoAdmin::all_keys().to_string()).is_ok());
+    assert_eq!(
+        "+@all   ~*".parse::<AclRule>().unwrap().as_str(),
+        "+@all ~*"
+    );
+
+    for bad in [
+        "",
+        "~*",
+        "-@all ~*",
+        "+@ ~*",
+        "+get ~",
+        "+@read cache:*",
+        "+g et! ~*",
+    ] {
+        assert!(
+            matches!(AclRule::parse(bad), Err(CloudError::BadRequest { .. })),
+            "{bad:?} should be rejected"
+        );
+    }
+    assert!(AclRule::builder().key_prefix("cache:").build().is_err());
+}
+
+#[test]
+fn test_acl_request_builders() {
+    let rule = AclRedisRuleCreateRequest::builder()
+        .name("cache-readers")
+        .rule(ReadOnly::on_prefix("cache:"))
+        .build()
+        .unwrap();
+    assert_eq!(rule.redis_rule, "+@read ~cache:*");
+    assert!(
+        AclRedisRuleCreateRequest::builder()
+            .name("broken")
+            .rule("read all")
+            .build()
+            .is_err()
+    );
+    assert!(
+        AclRedisRuleCreateRequest::builder()
+            .rule("+@read ~*")
+            .build()
+            .is_err()
+    );
+
+    let role = AclRoleCreateRequest::builder()
+        .name("cache-role")
+        .rule_on("cache-readers", 123, 456)
+        .rule(
+            "cache-readers",
+            [AclRoleDatabaseSpec::new(123, 789).regions(["us-east-1", "eu-west-1"])],
+        )
+        .rule_on("Full-Access", 123, 999)
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&role).unwrap(),
+        json!({
+            "name": "cache-role",
+            "redisRules": [
+                {
+                    "ruleName": "cache-readers",
+                    "databases": [
+                        {"subscriptionId": 123, "databaseId": 456},
+                        {"subscriptionId": 123, "databaseId": 789, "regions": ["us-east-1", "eu-west-1"]}
+                    ]
+                },
+                {
+                    "ruleName": "Full-Access",
+                    "databases": [{"subscriptionId": 123, "databaseId": 999}]
+                }
+            ]
+        })
+    );
+    assert!(
+        AclRoleCreateRequest::builder()
+            .name("empty")
+            .build()
+            .is_err()
+    );
+    assert!(
+        AclRoleCreateRequest::builder()
+            .name("no-dbs")
+            .rule("cache-readers", [])
+            .build()
+            .is_err()
+    );
+
+    let user = AclUserCreateRequest::builder()
+        .name("app")
+        .role("cache-role")
+        .password("s3cret-Passw0rd")
+        .build()
+        .unwrap();
+    assert_eq!(user.role, "cache-role");
+    let err = AclUserCreateRequest::builder()
+        .name("app")
+        .role("cache-role")
+        .password("")
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, CloudError::BadRequest { message } if message == "password is required"));
+}
+
+#[tokio::test]
+async fn test_lookup_by_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [
+                {"id": 1, "name": "app", "role": "cache-role"},
+                {"id": 2, "name": "app-admin", "role": "admin"},
+                {"id": 3, "name": "dup", "role": "a"},
+                {"id": 4, "name": "dup", "role": "b"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "roles": [
+                {"id": 10, "name": "cache-role", "status": "active"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/redisRules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "redisRules": [
+                {"id": 20, "name": "Read-Only", "acl": "+@read ~*", "isDefault": true},
+                {"id": 21, "name": "cache-readers", "acl": "+@read ~cache:*"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = AclHandler::new(client);
+
+    let users = handler.find_users_by_name("app").await.unwrap();
+    assert_eq!(users.unique().and_then(|user| user.id), Some(1));
+    assert_eq!(users.prefix[0].id, Some(2));
+    let roles = handler.find_roles_by_name("cache-role").await.unwrap();
+    assert_eq!(roles.unique().and_then(|role| role.id), Some(10));
+    let rule = handler
+        .find_redis_rules_by_name("cache-readers")
+        .await
+        .unwrap()
+        .into_unique("Redis ACL rule", "cache-readers")
+        .unwrap();
+    assert_eq!(rule.acl.as_deref(), Some("+@read ~cache:*"));
+
+    let users = handler.find_users_by_name("dup").await.unwrap();
+    assert_eq!(users.exact.len(), 2);
+    assert!(users.unique().is_none());
+    let err = users.into_unique("ACL user", "dup").unwrap_err();
+    assert!(matches!(err, CloudError::BadRequest { .. }));
+    let roles = handler.find_roles_by_name("cache").await.unwrap();
+    assert!(roles.exact.is_empty());
+    let err = roles.into_unique("ACL role", "cache").unwrap_err();
+    assert!(matches!(err, CloudError::BadRequest { message } if message.contains("'cache'")));
+}
+
+#[tokio::test]
+async fn test_sync() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/redisRules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "redisRules": [
+                {"id": 20, "name": "Read-Only", "acl": "+@read ~*", "isDefault": true},
+                {"id": 21, "name": "cache-readers", "acl": "+@read  ~cache:*"},
+                {"id": 22, "name": "legacy", "acl": "+@all ~legacy:*"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "roles": [
+                {
+                    "id": 10,
+                    "name": "cache-role",
+                    "redisRules": [
+                        {
+                            "ruleId": 21,
+                            "ruleName": "cache-readers",
+                            "databases": [{"subscriptionId": 123, "databaseId": 456}]
+                        }
+                    ]
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [
+                {"id": 1, "name": "app", "role": "cache-role"},
+                {"id": 2, "name": "retired", "role": "cache-role"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let task = ResponseTemplate::new(202).set_body_json(json!({
+        "taskId": "task-acl",
+        "status": "received"
+    }));
+    Mock::given(method("POST"))
+        .and(path("/acl/redisRules"))
+        .and(body_json(
+            json!({"name": "writers", "redisRule": "+@write ~*"}),
+        ))
+        .respond_with(task.clone())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/acl/roles/10"))
+        .respond_with(task.clone())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/acl/users"))
+        .respond_with(task.clone())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/acl/users/2"))
+        .respond_with(task.clone())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/acl/redisRules/22"))
+        .respond_with(task)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-acl"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-acl",
+            "status": "processing-completed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = AclHandler::new(client);
+
+    let spec = AclSpec {
+        redis_rules: vec![
+            ReadOnly::on_prefix("cache:").create_request("cache-readers"),
+            AclRule::builder()
+                .write()
+                .build()
+                .unwrap()
+                .create_request("writers"),
+        ],
+        roles: vec![
+            AclRoleCreateRequest::builder()
+                .name("cache-role")
+                .rule_on("cache-readers", 123, 456)
+                .rule_on("writers", 123, 456)
+                .build()
+                .unwrap(),
+        ],
+        users: vec![
+            AclUserCreateRequest::builder()
+                .name("app")
+                .role("cache-role")
+                .password("unused")
+                .build()
+                .unwrap(),
+            AclUserCreateRequest::builder()
+                .name("svc")
+                .role("cache-role")
+                .password("s3cret-Passw0rd")
+                .build()
+                .unwrap(),
+        ],
+    };
+
+    // Without confirm nothing is submitted; the mocks' expectations would fail otherwise
+    let plan = handler
+        .sync(
+            &spec,
+            &AclSyncOptions {
+                prune: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(!plan.applied);
+    assert_eq!(plan.changes.len(), 5);
+
+    let options = AclSyncOptions {
+        confirm: true,
+        prune: true,
+        wait: TaskWaitOptions::default().poll_interval(Duration::from_millis(10)),
+    };
+    let report = handler.sync(&spec, &options).await.unwrap();
+    assert!(report.applied);
+    assert_eq!(
+        report.changes,
+        vec![
+            AclChange::Create {
+                entity: AclEntity::RedisRule,
+                name: "writers".to_string()
+            },
+            AclChange::Update {
+                entity: AclEntity::Role,
+                id: 10,
+                name: "cache-role".to_string()
+            },
+            AclChange::Create {
+                entity: AclEntity::User,
+                name: "svc".to_string()
+            },
+            AclChange::Delete {
+                entity: AclEntity::User,
+                id: 2,
+                name: "retired".to_string()
+            },
+            AclChange::Delete {
+                entity: AclEntity::RedisRule,
+                id: 22,
+                name: "legacy".to_string()
+            },
+        ]
+    );
+    assert_eq!(plan.changes, report.changes);
+
+    let duplicate = AclSpec {
+        users: vec![spec.users[0].clone(), spec.users[0].clone()],
+        ..Default::default()
+    };
+    let err = handler.sync(&duplicate, &options).await.unwrap_err();
+    assert!(matches!(err, CloudError::BadRequest { .. }));
+}
+
+#[tokio::test]
+async fn test_grant_and_revoke_role_on_database() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "roles": [
+                {
+                    "id": 10,
+                    "name": "cache-role",
+                    "redisRules": [
+                        {
+                            "ruleId": 21,
+                            "ruleName": "cache-readers",
+                            "databases": [
+                                {"subscriptionId": 123, "databaseId": 456, "databaseName": "cache"},
+                                {"subscriptionId": 123, "databaseId": 789, "regions": ["us-east-1"]}
+                            ]
+                        }
+                    ]
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/acl/roles/10"))
+        .and(body_json(json!({
+            "name": "cache-role",
+            "redisRules": [
+                {
+                    "ruleName": "cache-readers",
+                    "databases": [
+                        {"subscriptionId": 123, "databaseId": 456},
+                        {"subscriptionId": 123, "databaseId": 789, "regions": ["us-east-1"]}
+                    ]
+                },
+                {
+                    "ruleName": "writers",
+                    "databases": [{"subscriptionId": 123, "databaseId": 456}]
+                }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-grant",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/acl/roles/10"))
+        .and(body_json(json!({
+            "name": "cache-role",
+            "redisRules": [
+                {
+                    "ruleName": "cache-readers",
+                    "databases": [
+                        {"subscriptionId": 123, "databaseId": 789, "regions": ["us-east-1"]}
+                    ]
+                }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-revoke",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = AclHandler::new(client);
+
+    let task = handler
+        .grant_role_on_database("cache-role", 123, 456, "writers")
+        .await
+        .unwrap();
+    assert_eq!(task.unwrap().task_id.as_deref(), Some("task-grant"));
+
+    // Already granted: nothing to submit
+    let task = handler
+        .grant_role_on_database("cache-role", 123, 456, "cache-readers")
+        .await
+        .unwrap();
+    assert!(task.is_none());
+
+    let task = handler
+        .revoke_role_on_database("cache-role", 123, 456, "cache-readers")
+        .await
+        .unwrap();
+    assert_eq!(task.unwrap().task_id.as_deref(), Some("task-revoke"));
+
+    let task = handler
+        .revoke_role_on_database("cache-role", 123, 999, "cache-readers")
+        .await
+        .unwrap();
+    assert!(task.is_none());
+
+    let err = handler
+        .grant_role_on_database("missing-role", 123, 456, "writers")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CloudError::BadRequest { .. }));
+}
+
+#[tokio::test]
+async fn test_revoke_role_on_database_edits_only_one_rule() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/acl/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "roles": [
+                {
+                    "id": 10,
+                    "name": "cache-role",
+                    "redisRules": [
+                        {"ruleName": "idle", "databases": []},
+                        {
+                            "ruleName": "cache-readers",
+                            "databases": [{"subscriptionId": 123, "databaseId": 456}]
+                        }
+                    ]
+                },
+                {
+                    "id": 11,
+                    "name": "broken-role",
+                    "redisRules": [
+                        {"ruleName": "cache-readers", "databases": [{"databaseId": 456}]}
+                    ]
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/acl/roles/10"))
+        .and(body_json(json!({
+            "name": "cache-role",
+            "redisRules": [{"ruleName": "idle", "databases": []}]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-revoke",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/acl/roles/11"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({"taskId": "t"})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = AclHandler::new(client);
+
+    // The emptied rule is dropped; the already empty "idle" rule is kept
+    let task = handler
+        .revoke_role_on_database("cache-role", 123, 456, "cache-readers")
+        .await
+        .unwrap();
+    assert_eq!(task.unwrap().task_id.as_deref(), Some("task-revoke"));
+
+    // An association without a subscription ID cannot be sent back intact
+    let err = handler
+        .revoke_role_on_database("broken-role", 123, 456, "cache-readers")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CloudError::UnexpectedResponse { .. }));
+}
@@ -0,0 +1,133 @@
+//! Tests for the blocking client facade
+//!
+//! The mock server is driven by its own runtime; the blocking client is used
+//! from plain synchronous code, the way non-async tools call it.
+
+#![cfg(feature = "blocking")]
+
+use redis_cloud::tasks::TaskWaitOptions;
+use redis_cloud::{CloudClient, CredentialCheck};
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn start_mock_server(runtime: &tokio::runtime::Runtime) -> MockServer {
+    runtime.block_on(async {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(header("x-api-key", "test-key"))
+            .and(header("x-api-secret-key", "test-secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "account": {"id": 1001, "name": "Redis Labs"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/subscriptions"))
+            .and(header("x-api-key", "test-key"))
+            .and(header("x-api-secret-key", "test-secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accountId": 1001,
+                "subscriptions": [{"id": 123, "name": "Production", "status": "active"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        mock_server
+    })
+}
+
+#[test]
+fn test_blocking_handler_call() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mock_server = start_mock_server(&runtime);
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build_blocking()
+        .unwrap();
+
+    let subscriptions = client
+        .subscriptions()
+        .call(|h| h.get_all_subscriptions())
+        .unwrap();
+    assert_eq!(subscriptions.account_id, Some(1001));
+
+    let root = client.account().call(|h| h.get_current_account()).unwrap();
+    assert_eq!(root.account.unwrap().id, Some(1001));
+}
+
+#[test]
+fn test_blocking_raw_and_verify() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mock_server = start_mock_server(&runtime);
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build_blocking()
+        .unwrap();
+
+    let raw = client.get_raw("/subscriptions").unwrap();
+    assert_eq!(raw["subscriptions"][0]["id"], 123);
+
+    assert_eq!(
+        client.verify_credentials().unwrap(),
+        CredentialCheck::Ok {
+            account_id: Some(1001)
+        }
+    );
+}
+
+#[test]
+fn test_blocking_core_methods() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mock_server = start_mock_server(&runtime);
+    runtime.block_on(async {
+        Mock::given(method("GET"))
+            .and(path("/subscriptions/123/databases/456"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "databaseId": 456,
+                "name": "cache"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/tasks/task-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": "task-1",
+                "status": "processing-completed"
+            })))
+            .mount(&mock_server)
+            .await;
+    });
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build_blocking()
+        .unwrap();
+
+    let subscriptions = client.subscriptions().get_all_subscriptions().unwrap();
+    assert_eq!(subscriptions.subscriptions.unwrap()[0].id, Some(123));
+
+    let database = client
+        .databases()
+        .get_subscription_database_by_id(123, 456)
+        .unwrap();
+    assert_eq!(database.name.as_deref(), Some("cache"));
+
+    let task = client
+        .tasks()
+        .wait_for_task("task-1", &TaskWaitOptions::default())
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-1"));
+}
@@ -1,6 +1,14 @@
-use redis_cloud::{CloudClient, ConnectivityHandler};
+use redis_cloud::connectivity::{
+    ActiveActivePscEndpointCreateRequest, ActiveActiveVpcPeeringCreateRequest, Cidr,
+    PrivateServiceConnectEndpoints, PscEndpointStatus, PscServiceStatus, TgwUpdateCidrsRequest,
+};
+use redis_cloud::tasks::TaskWaitOptions;
+use redis_cloud::{
+    CloudClient, CloudError, ConnectivityHandler, PscHandler, TransitGatewayHandler,
+    VpcPeeringHandler,
+};
 use serde_json::json;
-use wiremock::matchers::{header, method, path};
+use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -59,7 +67,7 @@ async fn test_create_vpc_peering() {
         .unwrap();
 
     let handler = ConnectivityHandler::new(client);
-    let request = redis_cloud::connectivity::VpcPeeringCreateBaseRequest {
+    let request = redis_cloud::connectivity::VpcPeeringCreateRequest {
         provider: Some("AWS".to_string()),
         command_type: None,
         ..Default::default()
@@ -220,11 +228,9 @@ async fn test_update_vpc_peering() {
 
     let handler = ConnectivityHandler::new(client);
     let request = redis_cloud::connectivity::VpcPeeringUpdateAwsRequest {
-        subscription_id: None,
-        vpc_peering_id: None,
         vpc_cidr: Some("10.0.0.0/16".to_string()),
         vpc_cidrs: Some(vec!["10.0.0.0/16".to_string()]),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -265,7 +271,7 @@ async fn test_create_vpc_peering_gcp() {
         .unwrap();
 
     let handler = ConnectivityHandler::new(client);
-    let request = redis_cloud::connectivity::VpcPeeringCreateBaseRequest {
+    let request = redis_cloud::connectivity::VpcPeeringCreateRequest {
         provider: Some("GCP".to_string()),
         command_type: Some("CREATE_VPC_PEERING".to_string()),
         gcp_project_id: Some("my-gcp-project".to_string()),
@@ -310,7 +316,7 @@ async fn test_create_vpc_peering_azure() {
     let handler = ConnectivityHandler::new(client);
     // Note: Azure VNet peering uses VPC peering API with Azure-specific fields
     // that would be passed in the request body via VpcPeeringCreateRequest
-    let request = redis_cloud::connectivity::VpcPeeringCreateBaseRequest {
+    let request = redis_cloud::connectivity::VpcPeeringCreateRequest {
         provider: Some("Azure".to_string()),
         command_type: None,
         // Azure-specific fields would need to be added to VpcPeeringCreateRequest
@@ -326,6 +332,7 @@ async fn test_create_vpc_peering_azure() {
     assert_eq!(result.command_type, Some("CREATE_VPC_PEERING".to_string()));
 }
 
+#[cfg(not(feature = "no-deprecated"))]
 #[tokio::test]
 async fn test_update_psc_service() {
     let mock_server = MockServer::start().await;
@@ -442,6 +449,7 @@ async fn test_create_tgw() {
     );
 }
 
+#[cfg(not(feature = "no-deprecated"))]
 #[tokio::test]
 async fn test_update_tgw() {
     let mock_server = MockServer::start().await;
@@ -476,7 +484,7 @@ async fn test_update_tgw() {
                 cidr_address: Some("192.168.0.0/16".to_string()),
             },
         ]),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -549,7 +557,7 @@ async fn test_task_response_with_error() {
         .unwrap();
 
     let handler = ConnectivityHandler::new(client);
-    let request = redis_cloud::connectivity::VpcPeeringCreateBaseRequest {
+    let request = redis_cloud::connectivity::VpcPeeringCreateRequest {
         provider: Some("AWS".to_string()),
         command_type: None,
         ..Default::default()
@@ -694,3 +702,600 @@ async fn test_error_handling_500() {
         _ => panic!("Expected InternalServerError error"),
     }
 }
+
+#[tokio::test]
+async fn test_status_reports_unconfigured_sections() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/peerings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-peerings",
+            "status": "processing-completed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/transitGateways"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("internal error"))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/private-service-connect"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "PSC_SERVICE_NOT_FOUND",
+            "description": "Private Service Connect is not configured"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/private-service-connect/endpoints"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/private-link"))
+        .respond_with(ResponseTemplate::new(412))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let status = ConnectivityHandler::new(client).status(123).await;
+
+    assert_eq!(status.subscription_id, 123);
+    assert_eq!(
+        status.vpc_peering.available().unwrap().task_id.as_deref(),
+        Some("task-peerings")
+    );
+    assert!(status.psc_service.is_not_configured());
+    assert!(status.psc_endpoints.is_not_configured());
+    assert!(status.private_link.is_not_configured());
+    assert!(matches!(
+        status.transit_gateway.error(),
+        Some(CloudError::InternalServerError { .. })
+    ));
+    assert!(status.has_failures());
+    assert!(matches!(status.private_link.into_result(), Ok(None)));
+}
+
+#[tokio::test]
+async fn test_list_vpc_peerings() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/peerings"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-get-peering",
+            "commandType": "vpcPeeringGetRequest",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-get-peering"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-get-peering",
+            "status": "processing-completed",
+            "response": {
+                "resourceId": 123,
+                "resource": {
+                    "peerings": [{
+                        "vpcPeeringId": 10,
+                        "status": "active",
+                        "awsAccountId": "123456789012",
+                        "vpcUid": "vpc-0abc",
+                        "vpcCidrs": [{"vpcCidr": "10.0.0.0/16", "active": "active"}]
+                    }]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let peerings = VpcPeeringHandler::new(client)
+        .list(123, &TaskWaitOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(peerings.len(), 1);
+    assert_eq!(peerings[0].id, Some(10));
+    assert_eq!(peerings[0].status.as_deref(), Some("active"));
+    assert_eq!(peerings[0].aws_account_id.as_deref(), Some("123456789012"));
+    assert_eq!(peerings[0].vpc_id.as_deref(), Some("vpc-0abc"));
+    let cidrs = peerings[0].vpc_cidrs.as_ref().unwrap();
+    assert_eq!(cidrs[0].vpc_cidr.as_deref(), Some("10.0.0.0/16"));
+}
+
+#[tokio::test]
+async fn test_active_active_vpc_peering() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/regions/peerings"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({
+            "provider": "AWS",
+            "sourceRegion": "us-east-1",
+            "destinationRegion": "us-west-2",
+            "awsAccountId": "123456789012",
+            "vpcId": "vpc-0abc",
+            "vpcCidrs": ["10.0.0.0/16"]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-aa-peering",
+            "commandType": "vpcPeeringCreateRequest",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/regions/peerings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-aa-list",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-aa-list"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-aa-list",
+            "status": "processing-completed",
+            "response": {
+                "resource": {
+                    "subscriptionId": 123,
+                    "regions": [{
+                        "id": 1,
+                        "region": "us-east-1",
+                        "vpcPeerings": [{"id": 7, "status": "active", "vpcUid": "vpc-0abc"}]
+                    }]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = VpcPeeringHandler::new(client);
+
+    let request = ActiveActiveVpcPeeringCreateRequest {
+        provider: Some("AWS".to_string()),
+        source_region: "us-east-1".to_string(),
+        destination_region: Some("us-west-2".to_string()),
+        aws_account_id: Some("123456789012".to_string()),
+        vpc_id: Some("vpc-0abc".to_string()),
+        vpc_cidrs: Some(vec!["10.0.0.0/16".to_string()]),
+        ..Default::default()
+    };
+    let task = handler.create_active_active(123, &request).await.unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-aa-peering"));
+
+    let regions = handler
+        .list_active_active(123, &TaskWaitOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].source_region.as_deref(), Some("us-east-1"));
+    let peerings = regions[0].vpc_peerings.as_ref().unwrap();
+    assert_eq!(peerings[0].id, Some(7));
+    assert_eq!(peerings[0].vpc_id.as_deref(), Some("vpc-0abc"));
+}
+
+#[tokio::test]
+async fn test_active_active_tgw_attachment() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/subscriptions/123/regions/2/transitGateways/tgw-0abc/attachment",
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-tgw-create",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/subscriptions/123/regions/2/transitGateways/tgw-0abc/attachment",
+        ))
+        .and(body_json(
+            json!({"cidrs": [{"cidrAddress": "10.1.0.0/24"}]}),
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-tgw-cidrs",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/subscriptions/123/regions/2/transitGateways/tgw-0abc/attachment",
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-tgw-delete",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = TransitGatewayHandler::new(client);
+
+    let task = handler
+        .create_attachment_active_active(123, 2, "tgw-0abc")
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-tgw-create"));
+
+    let request = TgwUpdateCidrsRequest {
+        cidrs: Some(vec![Cidr {
+            cidr_address: Some("10.1.0.0/24".to_string()),
+        }]),
+        command_type: None,
+    };
+    let task = handler
+        .update_attachment_cidrs_active_active(123, 2, "tgw-0abc", &request)
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-tgw-cidrs"));
+
+    handler
+        .delete_attachment_active_active(123, 2, "tgw-0abc")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_active_active_psc_endpoint() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/subscriptions/123/regions/2/private-service-connect/40",
+        ))
+        .and(body_json(json!({
+            "gcpProjectId": "my-gcp-project",
+            "gcpVpcName": "my-vpc",
+            "gcpVpcSubnetName": "my-vpc-subnet",
+            "endpointConnectionName": "redis-psc"
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-psc-endpoint",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/regions/2/private-service-connect"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-psc-get",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = PscHandler::new(client);
+
+    let task = handler.get_service_active_active(123, 2).await.unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-psc-get"));
+
+    let request = ActiveActivePscEndpointCreateRequest {
+        gcp_project_id: "my-gcp-project".to_string(),
+        gcp_vpc_name: "my-vpc".to_string(),
+        gcp_vpc_subnet_name: "my-vpc-subnet".to_string(),
+        endpoint_connection_name: "redis-psc".to_string(),
+    };
+    let task = handler
+        .create_endpoint_active_active(123, 2, 40, &request)
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-psc-endpoint"));
+}
+
+#[tokio::test]
+async fn test_tgw_invitations() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/transitGateways/invitations"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-invitations",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-invitations"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-invitations",
+            "status": "processing-completed",
+            "response": {
+                "resource": {
+                    "invitations": [
+                        {
+                            "id": 55,
+                            "name": "tgw-share",
+                            "resourceShareUid": "rs-0abc",
+                            "awsAccountId": "123456789012",
+                            "status": "pending",
+                            "sharedDate": "2024-05-01T10:00:00Z"
+                        },
+                        {"id": 56, "status": "accepted"}
+                    ]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/subscriptions/123/transitGateways/invitations/55/accept",
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-accept",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/subscriptions/123/transitGateways/invitations/56/reject",
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-reject",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = TransitGatewayHandler::new(client);
+
+    let invitations = handler
+        .get_invitations(123, &TaskWaitOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(invitations.len(), 2);
+    assert_eq!(
+        invitations[0].resource_share_uid.as_deref(),
+        Some("rs-0abc")
+    );
+
+    let pending: Vec<_> = invitations.iter().filter(|i| i.is_pending()).collect();
+    assert_eq!(pending.len(), 1);
+    let task = handler
+        .accept_invitation(123, pending[0].id.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-accept"));
+
+    let task = handler.reject_invitation(123, 56).await.unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-reject"));
+}
+
+#[tokio::test]
+async fn test_list_psc_endpoints_and_creation_scripts() {
+    let mock_server = MockServer::start().await;
+
+    for (route, task_id) in [
+        (
+            "/subscriptions/123/private-service-connect",
+            "task-psc-service",
+        ),
+        (
+            "/subscriptions/123/private-service-connect/40",
+            "task-psc-endpoints",
+        ),
+        (
+            "/subscriptions/123/private-service-connect/40/endpoints/7/creationScripts",
+            "task-psc-script",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .and(header("x-api-key", "test-key"))
+            .and(header("x-api-secret-key", "test-secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": "received"
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let resources = [
+        (
+            "task-psc-service",
+            json!({"id": 40, "connectionHostName": "psc.example.com", "status": "active"}),
+        ),
+        (
+            "task-psc-endpoints",
+            json!({
+                "pscServiceId": 40,
+                "endpoints": [{
+                    "id": 7,
+                    "gcpProjectId": "my-gcp-project",
+                    "gcpVpcName": "my-vpc",
+                    "status": "active",
+                    "serviceAttachments": [
+                        {"name": "sa-1", "forwardingRuleName": "redis-psc-fr-1"},
+                        {"name": "sa-2", "forwardingRuleName": "redis-psc-fr-2"}
+                    ]
+                }]
+            }),
+        ),
+        (
+            "task-psc-script",
+            json!({"bash": "gcloud compute forwarding-rules create redis-psc-fr-1"}),
+        ),
+    ];
+    for (task_id, resource) in resources {
+        Mock::given(method("GET"))
+            .and(path(format!("/tasks/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": "processing-completed",
+                "response": {"resource": resource}
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = PscHandler::new(client);
+    let options = TaskWaitOptions::default();
+
+    let endpoints = handler.list_endpoints(123, &options).await.unwrap();
+    assert_eq!(endpoints.len(), 1);
+    assert_eq!(
+        endpoints[0].gcp_project_id.as_deref(),
+        Some("my-gcp-project")
+    );
+    assert_eq!(
+        endpoints[0].forwarding_rules(),
+        vec!["redis-psc-fr-1", "redis-psc-fr-2"]
+    );
+
+    let scripts = handler
+        .get_endpoint_creation_scripts(123, 40, 7, &options)
+        .await
+        .unwrap();
+    assert!(scripts.bash.unwrap().starts_with("gcloud"));
+}
+
+#[tokio::test]
+async fn test_typed_psc_service_and_endpoints() {
+    let mock_server = MockServer::start().await;
+
+    for (route, task_id, resource) in [
+        (
+            "/subscriptions/123/private-service-connect",
+            "task-psc-service",
+            json!({"id": 40, "status": "active"}),
+        ),
+        (
+            "/subscriptions/123/private-service-connect/40",
+            "task-psc-endpoints",
+            json!({
+                "pscServiceId": 40,
+                "endpoints": [
+                    {"id": 7, "gcpProjectId": "team-a", "status": "active"},
+                    {"id": 8, "gcpProjectId": "team-b", "status": "rejected"},
+                    {"id": 9, "gcpProjectId": "team-a", "status": "active"},
+                    {"id": 10, "gcpProjectId": "team-c", "status": "modify-pending"}
+                ]
+            }),
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": "received"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/tasks/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": "processing-completed",
+                "response": {"resource": resource}
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = ConnectivityHandler::new(client);
+    let options = TaskWaitOptions::default();
+
+    let service = handler
+        .get_psc_service_details(123, &options)
+        .await
+        .unwrap();
+    assert_eq!(service.status, Some(PscServiceStatus::Active));
+    assert!(service.status.unwrap().is_ready());
+
+    let endpoints = handler.list_psc_endpoints(123, &options).await.unwrap();
+    let statuses: Vec<_> = endpoints.iter().map(|e| e.status.unwrap()).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            PscEndpointStatus::Active,
+            PscEndpointStatus::Rejected,
+            PscEndpointStatus::Active,
+            PscEndpointStatus::Other
+        ]
+    );
+
+    let list = PrivateServiceConnectEndpoints {
+        psc_service_id: Some(40),
+        endpoints: Some(endpoints),
+    };
+    assert_eq!(list.connected_projects(), vec!["team-a"]);
+}
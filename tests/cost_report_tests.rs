@@ -0,0 +1,128 @@
+use redis_cloud::{CloudClient, CloudError, CostReportHandler};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_download_to_writer() {
+    let mock_server = MockServer::start().await;
+    let report = "BilledCost,ResourceId\n".to_string() + &"12.50,db-1\n".repeat(5000);
+
+    Mock::given(method("GET"))
+        .and(path("/cost-report/report-1"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(report.clone()))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let mut output = Vec::new();
+    let mut updates = Vec::new();
+    let written = CostReportHandler::new(client)
+        .download_to("report-1", &mut output, |progress| updates.push(progress))
+        .await
+        .unwrap();
+
+    assert_eq!(written, report.len() as u64);
+    assert_eq!(output, report.as_bytes());
+    let last = updates.last().unwrap();
+    assert_eq!(last.bytes_written, written);
+    assert_eq!(last.total_bytes, Some(written));
+    assert_eq!(last.fraction(), Some(1.0));
+}
+
+#[tokio::test]
+async fn test_download_to_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/cost-report/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Cost report not found"))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let mut output = Vec::new();
+    let result = CostReportHandler::new(client)
+        .download_to("missing", &mut output, |_| {})
+        .await;
+
+    assert!(matches!(result, Err(CloudError::NotFound { .. })));
+    assert!(output.is_empty());
+}
+
+#[cfg(feature = "cost-parsing")]
+#[test]
+fn test_parse_cost_report() {
+    use redis_cloud::{CostReportFormat, parse_cost_report};
+
+    let csv = "\
+BilledCost,BillingCurrency,RegionId,ChargeDescription,ConsumedQuantity,x_SubscriptionId,x_DatabaseId
+12.50,USD,us-east-1,Shard hours,250,123,456
+0.75,USD,us-east-1,\"Network, cross-AZ\",10.5,123,
+";
+    let items = parse_cost_report(csv.as_bytes(), &CostReportFormat::Csv).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].subscription.as_deref(), Some("123"));
+    assert_eq!(items[0].database.as_deref(), Some("456"));
+    assert_eq!(items[0].region.as_deref(), Some("us-east-1"));
+    assert_eq!(items[0].usage_type.as_deref(), Some("Shard hours"));
+    assert_eq!(items[0].quantity, Some(250.0));
+    assert_eq!(items[0].amount, 12.5);
+    assert_eq!(items[0].currency.as_deref(), Some("USD"));
+    assert_eq!(items[1].usage_type.as_deref(), Some("Network, cross-AZ"));
+    assert_eq!(items[1].database, None);
+
+    let json = r#"{"rows": [{"billedCost": 3, "BillingCurrency": "EUR", "ResourceId": "789"}]}"#;
+    let items = parse_cost_report(json.as_bytes(), &CostReportFormat::Json).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].amount, 3.0);
+    assert_eq!(items[0].database.as_deref(), Some("789"));
+    assert_eq!(items[0].quantity, None);
+
+    let invalid = "BilledCost\nfree\n";
+    assert!(matches!(
+        parse_cost_report(invalid.as_bytes(), &CostReportFormat::Csv),
+        Err(CloudError::UnexpectedResponse { .. })
+    ));
+}
+
+#[cfg(feature = "cost-parsing")]
+#[test]
+fn test_parse_cost_report_rows() {
+    use redis_cloud::{CostReportFormat, parse_cost_report_rows};
+    use serde_json::Value;
+
+    // BOM, CRLF line endings, quoted commas, escaped quotes and an embedded newline
+    let csv = "\u{feff}BilledCost,ChargeDescription,Tags\r\n\
+               1.5,\"Shard \"\"large\"\"\",\"env=prod\r\nteam=cache\"\r\n\
+               \r\n\
+               2,\"Network, cross-AZ\",\r\n";
+    let data = csv.as_bytes();
+    assert_eq!(CostReportFormat::detect(data), CostReportFormat::Csv);
+    let rows = parse_cost_report_rows(data, &CostReportFormat::Csv).unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0][0], ("BilledCost".to_string(), Value::from("1.5")));
+    assert_eq!(rows[0][1].1, Value::from("Shard \"large\""));
+    assert_eq!(rows[0][2].1, Value::from("env=prod\r\nteam=cache"));
+    assert_eq!(rows[1][1].1, Value::from("Network, cross-AZ"));
+    assert_eq!(rows[1][2].1, Value::from(""));
+
+    let json = br#"  [{"BilledCost": 3, "Region": "eu-west-1"}, 42]"#;
+    assert_eq!(CostReportFormat::detect(json), CostReportFormat::Json);
+    let rows = parse_cost_report_rows(json, &CostReportFormat::Json).unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0][0], ("BilledCost".to_string(), Value::from(3)));
+}
@@ -0,0 +1,89 @@
+use redis_cloud::{CloudClient, CloudError};
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_dashboard() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscriptions": [
+                {"id": 123, "name": "production", "status": "active"},
+                {"id": 124, "name": "staging", "status": "active"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "numberOfDatabases": 2,
+                "databases": [
+                    {
+                        "databaseId": 1,
+                        "name": "cache",
+                        "status": "active",
+                        "alerts": [{"name": "dataset-size", "value": 80}]
+                    },
+                    {"databaseId": 2, "name": "sessions", "status": "active"}
+                ]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/124/databases"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("Internal error"))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"taskId": "task-old", "timestamp": "2024-01-01T10:00:00Z"},
+            {"taskId": "task-new", "timestamp": "2024-01-02T10:00:00Z"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    // Spawned, so the future must be Send
+    let dashboard = tokio::spawn(async move { client.dashboard().await })
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(dashboard.subscriptions.len(), 2);
+    assert_eq!(dashboard.database_count(), 2);
+    assert!(matches!(
+        dashboard.subscriptions[1].databases.error(),
+        Some(CloudError::InternalServerError { .. })
+    ));
+    assert!(dashboard.has_failures());
+
+    let tasks = dashboard.recent_tasks.available().unwrap();
+    assert_eq!(tasks[0].task_id.as_deref(), Some("task-new"));
+
+    let alerts: Vec<_> = dashboard.alerts().collect();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].subscription_id, Some(123));
+    assert_eq!(alerts[0].database_name, Some("cache"));
+    assert_eq!(alerts[0].alert.name, "dataset-size");
+}
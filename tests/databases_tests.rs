@@ -1,6 +1,14 @@
-use redis_cloud::{CloudClient, DatabaseHandler};
+use redis_cloud::databases::{
+    BackupAllOptions, CertificateRotationOptions, CloneOptions, DatabaseCreateRequest,
+    DatabaseImportRequest, DatabaseUpdateRequest, SlowLogQuery, TlsRemediationOptions,
+    TlsRemediationOutcome,
+};
+use redis_cloud::tasks::TaskWaitOptions;
+use redis_cloud::{CloudClient, CloudError, DatabaseHandler, RateLimitBackoff};
 use serde_json::json;
-use wiremock::matchers::{body_json, header, method, path, query_param};
+use std::collections::HashMap;
+use std::time::Duration;
+use wiremock::matchers::{body_json, body_partial_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -83,37 +91,9 @@ async fn test_create_database() {
     let request = redis_cloud::databases::DatabaseCreateRequest {
         name: "test-database".to_string(),
         memory_limit_in_gb: Some(1.0),
-        data_eviction_policy: Some("allkeys-lru".to_string()),
+        data_eviction_policy: Some(redis_cloud::types::DataEvictionPolicy::AllKeysLru),
         replication: Some(false),
-        subscription_id: None,
-        dry_run: None,
-        protocol: None,
-        port: None,
-        dataset_size_in_gb: None,
-        redis_version: None,
-        resp_version: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        data_persistence: None,
-        replica_of: None,
-        replica: None,
-        throughput_measurement: None,
-        local_throughput_measurement: None,
-        average_item_size_in_bytes: None,
-        periodic_backup_path: None,
-        remote_backup: None,
-        source_ip: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        sasl_username: None,
-        sasl_password: None,
-        alerts: None,
-        modules: None,
-        sharding_type: None,
-        query_performance_factor: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_database(123, &request).await.unwrap();
@@ -172,11 +152,82 @@ async fn test_get_database_by_id() {
         result.public_endpoint,
         Some("redis-12345-ext.c1.us-east-1.redislabs.com:16379".to_string())
     );
+    assert_eq!(
+        result.private_host_port(),
+        Some(("redis-12345.c1.us-east-1.redislabs.com".to_string(), 16379))
+    );
+    assert_eq!(result.public_host_port().map(|(_, port)| port), Some(16379));
     assert_eq!(result.memory_limit_in_gb, Some(2.5));
-    assert_eq!(result.data_eviction_policy, Some("allkeys-lru".to_string()));
+    assert_eq!(
+        result.data_eviction_policy,
+        Some(redis_cloud::types::DataEvictionPolicy::AllKeysLru)
+    );
     assert_eq!(result.replication, Some(true));
-    assert_eq!(result.data_persistence, Some("aof-every-1-sec".to_string()));
-    assert_eq!(result.protocol, Some("redis".to_string()));
+    assert_eq!(
+        result.data_persistence,
+        Some(redis_cloud::types::DataPersistence::AofEvery1Sec)
+    );
+    assert_eq!(result.protocol, Some(redis_cloud::types::Protocol::Redis));
+}
+
+#[tokio::test]
+async fn test_database_typed_alerts_and_modules() {
+    use redis_cloud::databases::DatabaseModuleSpec;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "search",
+            "alerts": [
+                {"name": "dataset-size", "value": 80},
+                {"name": "throughput-higher-than", "value": 20000}
+            ],
+            "modules": [{
+                "id": 18,
+                "name": "RediSearch",
+                "capabilityName": "Search and query",
+                "version": "2.8.4",
+                "description": "High performance search index",
+                "parameters": {}
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let database = handler
+        .get_subscription_database_by_id(123, 456)
+        .await
+        .unwrap();
+
+    let alerts = database.alerts.unwrap();
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[1].name, "throughput-higher-than");
+    assert_eq!(alerts[1].value, 20000);
+
+    let modules = database.modules.unwrap();
+    assert_eq!(modules[0].name, "RediSearch");
+    assert_eq!(modules[0].version.as_deref(), Some("2.8.4"));
+    assert_eq!(
+        modules[0].capability_name.as_deref(),
+        Some("Search and query")
+    );
+
+    let spec = DatabaseModuleSpec::from(&modules[0]);
+    assert_eq!(spec.name, "RediSearch");
+    assert_eq!(spec.parameters, Some(Default::default()));
 }
 
 #[tokio::test]
@@ -205,35 +256,10 @@ async fn test_update_database() {
 
     let handler = DatabaseHandler::new(client);
     let request = redis_cloud::databases::DatabaseUpdateRequest {
-        subscription_id: None,
-        database_id: None,
-        dry_run: None,
         name: Some("updated-database".to_string()),
         memory_limit_in_gb: Some(4.0),
-        dataset_size_in_gb: None,
-        resp_version: None,
-        throughput_measurement: None,
-        data_persistence: None,
-        data_eviction_policy: Some("volatile-lru".to_string()),
-        replication: None,
-        regex_rules: None,
-        replica_of: None,
-        replica: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        password: None,
-        sasl_username: None,
-        sasl_password: None,
-        source_ip: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        enable_default_user: None,
-        periodic_backup_path: None,
-        remote_backup: None,
-        alerts: None,
-        command_type: None,
-        query_performance_factor: None,
+        data_eviction_policy: Some(redis_cloud::types::DataEvictionPolicy::VolatileLru),
+        ..Default::default()
     };
 
     let result = handler.update_database(123, 456, &request).await.unwrap();
@@ -297,19 +323,34 @@ async fn test_backup_database() {
         .unwrap();
 
     let handler = DatabaseHandler::new(client);
-    let request = redis_cloud::databases::DatabaseBackupRequest {
-        subscription_id: None,
-        database_id: None,
-        region_name: None,
-        adhoc_backup_path: None,
-        command_type: None,
-    };
+    let request = redis_cloud::databases::DatabaseBackupRequest::default();
     let result = handler.backup_database(123, 456, &request).await.unwrap();
 
     assert_eq!(result.task_id, Some("task-backup-db-456".to_string()));
     assert_eq!(result.command_type, Some("BACKUP_DATABASE".to_string()));
 }
 
+#[test]
+fn test_import_request_constructors() {
+    let request = DatabaseImportRequest::from_s3(["s3://bucket/dump.rdb"]).unwrap();
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "sourceType": "aws-s3",
+            "importFromUri": ["s3://bucket/dump.rdb"]
+        })
+    );
+
+    let request = DatabaseImportRequest::from_redis(["redis://:pw@10.0.0.1:6379"]).unwrap();
+    assert_eq!(request.source_type, "redis");
+
+    match DatabaseImportRequest::from_gcs(["https://storage.example.com/dump.rdb"]) {
+        Err(CloudError::BadRequest { message }) => assert!(message.contains("gs://")),
+        other => panic!("Expected BadRequest, got {other:?}"),
+    }
+    assert!(DatabaseImportRequest::from_ftp(Vec::<String>::new()).is_err());
+}
+
 #[tokio::test]
 async fn test_import_into_database() {
     let mock_server = MockServer::start().await;
@@ -342,9 +383,7 @@ async fn test_import_into_database() {
     let request = redis_cloud::databases::DatabaseImportRequest {
         source_type: "ftp".to_string(),
         import_from_uri: vec!["ftp://example.com/backup.rdb".to_string()],
-        subscription_id: None,
-        database_id: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.import_database(123, 456, &request).await.unwrap();
@@ -494,47 +533,18 @@ async fn test_create_database_with_modules() {
     let handler = DatabaseHandler::new(client);
     let request = redis_cloud::databases::DatabaseCreateRequest {
         name: "test-database-with-modules".to_string(),
-        subscription_id: None,
-        dry_run: None,
-        protocol: None,
-        port: None,
         memory_limit_in_gb: Some(2.0),
-        dataset_size_in_gb: None,
-        redis_version: None,
-        resp_version: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        data_persistence: None,
-        data_eviction_policy: None,
-        replication: None,
-        replica_of: None,
-        replica: None,
-        throughput_measurement: None,
-        local_throughput_measurement: None,
-        average_item_size_in_bytes: None,
-        periodic_backup_path: None,
-        remote_backup: None,
-        source_ip: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        sasl_username: None,
-        sasl_password: None,
-        alerts: None,
         modules: Some(vec![
             redis_cloud::databases::DatabaseModuleSpec {
                 name: "RediSearch".to_string(),
-                parameters: None,
+                ..Default::default()
             },
             redis_cloud::databases::DatabaseModuleSpec {
                 name: "RedisJSON".to_string(),
-                parameters: None,
+                ..Default::default()
             },
         ]),
-        sharding_type: None,
-        query_performance_factor: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_database(123, &request).await.unwrap();
@@ -571,41 +581,12 @@ async fn test_create_database_with_alerts() {
     let handler = DatabaseHandler::new(client);
     let request = redis_cloud::databases::DatabaseCreateRequest {
         name: "test-database-with-alerts".to_string(),
-        subscription_id: None,
-        dry_run: None,
-        protocol: None,
-        port: None,
         memory_limit_in_gb: Some(1.0),
-        dataset_size_in_gb: None,
-        redis_version: None,
-        resp_version: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        data_persistence: None,
-        data_eviction_policy: None,
-        replication: None,
-        replica_of: None,
-        replica: None,
-        throughput_measurement: None,
-        local_throughput_measurement: None,
-        average_item_size_in_bytes: None,
-        periodic_backup_path: None,
-        remote_backup: None,
-        source_ip: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        sasl_username: None,
-        sasl_password: None,
         alerts: Some(vec![redis_cloud::databases::DatabaseAlertSpec {
             name: "dataset-size".to_string(),
             value: 80,
         }]),
-        modules: None,
-        sharding_type: None,
-        query_performance_factor: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_database(123, &request).await.unwrap();
@@ -638,38 +619,9 @@ async fn test_database_with_clustering() {
     let handler = DatabaseHandler::new(client);
     let request = redis_cloud::databases::DatabaseCreateRequest {
         name: "clustered-database".to_string(),
-        subscription_id: None,
-        dry_run: None,
-        protocol: None,
-        port: None,
         memory_limit_in_gb: Some(10.0),
-        dataset_size_in_gb: None,
-        redis_version: None,
-        resp_version: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        data_persistence: None,
-        data_eviction_policy: None,
-        replication: None,
-        replica_of: None,
-        replica: None,
-        throughput_measurement: None,
-        local_throughput_measurement: None,
-        average_item_size_in_bytes: None,
-        periodic_backup_path: None,
-        remote_backup: None,
-        source_ip: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        sasl_username: None,
-        sasl_password: None,
-        alerts: None,
-        modules: None,
         sharding_type: Some("standard".to_string()),
-        query_performance_factor: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_database(123, &request).await.unwrap();
@@ -707,38 +659,8 @@ async fn test_task_response_with_error() {
     let handler = DatabaseHandler::new(client);
     let request = redis_cloud::databases::DatabaseCreateRequest {
         name: "test-database".to_string(),
-        subscription_id: None,
-        dry_run: None,
-        protocol: None,
-        port: None,
         memory_limit_in_gb: Some(100.0),
-        dataset_size_in_gb: None,
-        redis_version: None,
-        resp_version: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        data_persistence: None,
-        data_eviction_policy: None,
-        replication: None,
-        replica_of: None,
-        replica: None,
-        throughput_measurement: None,
-        local_throughput_measurement: None,
-        average_item_size_in_bytes: None,
-        periodic_backup_path: None,
-        remote_backup: None,
-        source_ip: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        sasl_username: None,
-        sasl_password: None,
-        alerts: None,
-        modules: None,
-        sharding_type: None,
-        query_performance_factor: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_database(123, &request).await.unwrap();
@@ -865,38 +787,7 @@ async fn test_error_handling_500() {
     let handler = DatabaseHandler::new(client);
     let request = redis_cloud::databases::DatabaseCreateRequest {
         name: "test-database".to_string(),
-        subscription_id: None,
-        dry_run: None,
-        protocol: None,
-        port: None,
-        memory_limit_in_gb: None,
-        dataset_size_in_gb: None,
-        redis_version: None,
-        resp_version: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        data_persistence: None,
-        data_eviction_policy: None,
-        replication: None,
-        replica_of: None,
-        replica: None,
-        throughput_measurement: None,
-        local_throughput_measurement: None,
-        average_item_size_in_bytes: None,
-        periodic_backup_path: None,
-        remote_backup: None,
-        source_ip: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        sasl_username: None,
-        sasl_password: None,
-        alerts: None,
-        modules: None,
-        sharding_type: None,
-        query_performance_factor: None,
-        command_type: None,
+        ..Default::default()
     };
     let result = handler.create_database(123, &request).await;
 
@@ -1107,3 +998,1583 @@ async fn test_stream_databases() {
     }
     assert_eq!(count, 2);
 }
+
+#[tokio::test]
+async fn test_stream_databases_resumes_after_rate_limit() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscription": [{
+                "subscriptionId": 123,
+                "databases": [{ "databaseId": 1, "name": "db-one" }]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(query_param("offset", "1"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(query_param("offset", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscription": [{
+                "subscriptionId": 123,
+                "databases": []
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .retry_on_rate_limit(RateLimitBackoff::default())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+
+    use futures::StreamExt;
+    let databases: Vec<_> = handler
+        .stream_databases_with_page_size(123, 1)
+        .collect()
+        .await;
+
+    assert_eq!(databases.len(), 1);
+    assert_eq!(databases[0].as_ref().unwrap().database_id, 1);
+    // First page, two throttled attempts at offset 1, then the final page
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn test_enforce_tls() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "numberOfDatabases": 2,
+                "databases": [
+                    {"databaseId": 1, "name": "secure", "enableTls": true},
+                    {"databaseId": 2, "name": "plaintext", "enableTls": false}
+                ]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/2"))
+        .and(body_json(json!({"enableTls": true})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "tls-task",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/tls-task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "tls-task",
+            "status": "processing-completed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+
+    // Dry run reports without submitting updates
+    let report = handler
+        .enforce_tls(123, &TlsRemediationOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(report.results.len(), 1);
+    assert_eq!(report.results[0].database_id, 2);
+    assert!(matches!(
+        report.results[0].outcome,
+        TlsRemediationOutcome::WouldEnable
+    ));
+
+    let options = TlsRemediationOptions {
+        confirm: true,
+        wait: Some(TaskWaitOptions::default().poll_interval(Duration::from_millis(10))),
+    };
+    let report = handler.enforce_tls(123, &options).await.unwrap();
+    assert!(!report.is_compliant());
+    assert_eq!(report.failures().count(), 0);
+    match &report.results[0].outcome {
+        TlsRemediationOutcome::Enabled { task_id } => {
+            assert_eq!(task_id.as_deref(), Some("tls-task"));
+        }
+        other => panic!("Expected Enabled, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_enforce_tls_wait_without_task_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscription": [{
+                "subscriptionId": 123,
+                "databases": [{"databaseId": 2, "name": "plaintext"}]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/2"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({"status": "received"})))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let options = TlsRemediationOptions {
+        confirm: true,
+        wait: Some(TaskWaitOptions::default()),
+    };
+    let report = DatabaseHandler::new(client)
+        .enforce_tls(123, &options)
+        .await
+        .unwrap();
+    assert_eq!(report.failures().count(), 1);
+    match &report.results[0].outcome {
+        TlsRemediationOutcome::Failed { task_id, error } => {
+            assert_eq!(*task_id, None);
+            assert!(error.contains("task ID"));
+        }
+        other => panic!("Expected Failed, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_update_database_and_fetch() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/456"))
+        .and(body_json(json!({"name": "renamed"})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "update-task",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/update-task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "update-task",
+            "status": "processing-completed",
+            "response": {"resourceId": 456}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "renamed",
+            "status": "active"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let request = DatabaseUpdateRequest {
+        name: Some("renamed".to_string()),
+        ..Default::default()
+    };
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+
+    let updated = handler
+        .update_database_and_fetch(123, 456, &request, &options)
+        .await
+        .unwrap();
+    assert!(updated.task.is_completed());
+    assert_eq!(updated.resource.database_id, 456);
+    assert_eq!(updated.resource.name, Some("renamed".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_subscription_databases_page() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(query_param("offset", "2"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "numberOfDatabases": 5,
+                "databases": [
+                    {"databaseId": 3, "name": "db-3"},
+                    {"databaseId": 4, "name": "db-4"}
+                ]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(query_param("offset", "4"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "numberOfDatabases": 5,
+                "databases": [{"databaseId": 5, "name": "db-5"}]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+
+    let page = handler
+        .get_subscription_databases_page(123, 2, Some(2))
+        .await
+        .unwrap();
+    assert_eq!(page.offset, 2);
+    assert_eq!(page.limit, Some(2));
+    assert_eq!(page.total, Some(5));
+    assert_eq!(page.databases.len(), 2);
+    assert_eq!(page.next_offset(), 4);
+    assert!(page.has_more());
+
+    let last = handler
+        .get_subscription_databases_page(123, page.next_offset(), Some(2))
+        .await
+        .unwrap();
+    assert_eq!(last.databases[0].database_id, 5);
+    assert!(!last.has_more());
+}
+
+#[tokio::test]
+async fn test_database_remote_backup_status() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "cache",
+            "remoteBackup": {
+                "active": true,
+                "backupInterval": "EVERY_4_HOURS",
+                "timeUTC": "02:00",
+                "databaseBackupTimeUTC": "HOUR_TWO",
+                "backupStorageType": "aws-s3",
+                "storagePath": "s3://backups/cache"
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let database = DatabaseHandler::new(client)
+        .get_subscription_database_by_id(123, 456)
+        .await
+        .unwrap();
+
+    let backup = database.remote_backup.as_ref().unwrap();
+    assert!(backup.is_active());
+    assert_eq!(backup.interval_hours(), Some(4));
+    assert_eq!(backup.time_utc.as_deref(), Some("02:00"));
+    assert_eq!(backup.database_backup_time_utc.as_deref(), Some("HOUR_TWO"));
+    assert_eq!(backup.storage(), Some("aws-s3"));
+
+    let request = DatabaseUpdateRequest::from(&database);
+    assert_eq!(
+        serde_json::to_value(request.remote_backup).unwrap(),
+        json!({
+            "active": true,
+            "interval": "every-4-hours",
+            "timeUTC": "02:00",
+            "storageType": "aws-s3",
+            "storagePath": "s3://backups/cache"
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_update_request_from_database() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "cache",
+            "status": "active",
+            "memoryLimitInGb": 2.0,
+            "datasetSizeInGb": 1.0,
+            "dataPersistence": "none",
+            "dataEvictionPolicy": "allkeys-lru",
+            "replication": true,
+            "password": "********",
+            "sourceIp": ["10.0.0.0/16"],
+            "enableTls": false,
+            "regexRules": [
+                {"ordinal": 1, "pattern": "(?<tag>.*)"},
+                {"ordinal": 0, "pattern": ".*\\{(?<tag>.*)\\}.*"}
+            ],
+            "alerts": [{"name": "dataset-size", "value": 80}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/456"))
+        .and(body_json(json!({
+            "databaseId": 456,
+            "name": "cache",
+            "datasetSizeInGb": 1.0,
+            "dataPersistence": "none",
+            "dataEvictionPolicy": "allkeys-lru",
+            "replication": true,
+            "sourceIp": ["10.0.0.0/16"],
+            "enableTls": true,
+            "regexRules": [".*\\{(?<tag>.*)\\}.*", "(?<tag>.*)"],
+            "alerts": [{"name": "dataset-size", "value": 80}]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-update-db-456",
+            "commandType": "UPDATE_DATABASE",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let database = handler
+        .get_subscription_database_by_id(123, 456)
+        .await
+        .unwrap();
+
+    let mut request = DatabaseUpdateRequest::from(&database);
+    assert_eq!(request.password, None);
+    assert_eq!(request.memory_limit_in_gb, None);
+    request.enable_tls = Some(true);
+
+    let result = handler.update_database(123, 456, &request).await.unwrap();
+    assert_eq!(result.task_id, Some("task-update-db-456".to_string()));
+}
+
+#[tokio::test]
+async fn test_price_database() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases"))
+        .and(body_partial_json(json!({"name": "cache", "dryRun": true})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-dry-run",
+            "commandType": "CREATE_DATABASE",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-dry-run"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-dry-run",
+            "status": "processing-completed",
+            "response": {
+                "resource": {
+                    "pricing": [
+                        {
+                            "databaseName": "cache",
+                            "type": "RedisFlex",
+                            "quantity": 2,
+                            "quantityMeasurement": "shards",
+                            "pricePerUnit": 0.124,
+                            "priceCurrency": "USD",
+                            "pricePeriod": "hour"
+                        },
+                        {
+                            "databaseName": "existing",
+                            "type": "RedisFlex",
+                            "quantity": 1,
+                            "pricePerUnit": 0.124
+                        }
+                    ]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let request = DatabaseCreateRequest {
+        name: "cache".to_string(),
+        dataset_size_in_gb: Some(5.0),
+        ..Default::default()
+    };
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let pricing = DatabaseHandler::new(client)
+        .price_database(123, &request, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(pricing.len(), 1);
+    assert_eq!(pricing[0].quantity, Some(2));
+    assert_eq!(pricing[0].price_per_unit, Some(0.124));
+}
+
+#[tokio::test]
+async fn test_plan_database_update() {
+    use redis_cloud::subscriptions::PlanAction;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "cache"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/456"))
+        .and(body_partial_json(
+            json!({"datasetSizeInGb": 10.0, "dryRun": true}),
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-plan",
+            "commandType": "UPDATE_DATABASE",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-plan"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-plan",
+            "status": "processing-completed",
+            "response": {
+                "resource": {
+                    "pricing": [
+                        {
+                            "databaseName": "cache",
+                            "type": "RedisFlex",
+                            "quantity": 4,
+                            "quantityMeasurement": "shards",
+                            "pricePerUnit": 0.124,
+                            "priceCurrency": "USD"
+                        },
+                        {
+                            "databaseName": "existing",
+                            "type": "RedisFlex",
+                            "quantity": 1,
+                            "pricePerUnit": 0.124,
+                            "priceCurrency": "USD"
+                        },
+                        {
+                            "type": "m6g.large",
+                            "quantity": 2,
+                            "quantityMeasurement": "instances"
+                        }
+                    ]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let request = DatabaseUpdateRequest {
+        dataset_size_in_gb: Some(10.0),
+        ..Default::default()
+    };
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let plan = DatabaseHandler::new(client)
+        .plan_update(123, 456, &request, &options)
+        .await
+        .unwrap();
+
+    assert!(plan.has_changes());
+    assert_eq!(plan.created().count(), 0);
+    let modified: Vec<_> = plan.modified().map(|r| r.kind.as_str()).collect();
+    assert_eq!(modified, vec!["RedisFlex", "m6g.large"]);
+    assert_eq!(plan.resources[0].quantity, Some(4));
+    assert_eq!(plan.resources[0].unit.as_deref(), Some("shards"));
+    assert_eq!(plan.resources[1].action, PlanAction::Unchanged);
+    assert_eq!(plan.pricing.len(), 3);
+    let totals = plan.price_totals();
+    assert_eq!(totals.len(), 1);
+    assert!((totals[0].1 - 0.62).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_price_database_rejected() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-dry-run",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-dry-run"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-dry-run",
+            "status": "processing-error",
+            "response": {"error": "DATABASE_NAME_ALREADY_EXISTS"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let request = DatabaseCreateRequest {
+        name: "cache".to_string(),
+        ..Default::default()
+    };
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let err = DatabaseHandler::new(client)
+        .price_database(123, &request, &options)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, redis_cloud::CloudError::TaskFailed { task_id, error: Some(error), .. } if task_id == "task-dry-run" && error == "DATABASE_NAME_ALREADY_EXISTS")
+    );
+}
+
+#[tokio::test]
+async fn test_all_databases() {
+    use futures::StreamExt;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscriptions": [{"id": 123}, {"id": 124}, {"name": "draft"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    for (subscription_id, database_ids) in [(123, vec![1, 2]), (124, vec![3])] {
+        let databases: Vec<_> = database_ids
+            .iter()
+            .map(|id| json!({"databaseId": id, "name": format!("db-{id}")}))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path(format!("/subscriptions/{subscription_id}/databases")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accountId": 456,
+                "subscription": [{
+                    "subscriptionId": subscription_id,
+                    "databases": databases
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let results: Vec<_> = handler.all_databases().collect().await;
+    let mut pairs: Vec<_> = results
+        .into_iter()
+        .map(|result| {
+            let (subscription_id, database) = result.unwrap();
+            (subscription_id, database.database_id)
+        })
+        .collect();
+    pairs.sort_unstable();
+
+    assert_eq!(pairs, [(123, 1), (123, 2), (124, 3)]);
+}
+
+#[tokio::test]
+async fn test_active_active_region_states() {
+    use redis_cloud::databases::{DatabaseAlertSpec, LocalRegionProperties};
+    use redis_cloud::types::DataPersistence;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "global-cache",
+            "activeActiveRedis": true,
+            "globalDataPersistence": "aof-every-1-second",
+            "globalSourceIp": ["0.0.0.0/0"],
+            "globalAlerts": [{"name": "dataset-size", "value": 80}],
+            "crdbDatabases": [
+                {
+                    "provider": "AWS",
+                    "region": "us-east-1",
+                    "dataPersistence": "snapshot-every-1-hour",
+                    "alerts": [
+                        {"name": "throughput-higher-than", "value": 1000},
+                        {"name": "dataset-size", "value": 90}
+                    ],
+                    "security": {"password": "********", "sourceIps": ["10.0.0.0/16"]}
+                },
+                {
+                    "provider": "AWS",
+                    "region": "eu-west-1",
+                    "security": {"enableDefaultUser": false}
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let database = DatabaseHandler::new(client)
+        .get_active_active_database(123, 456)
+        .await
+        .unwrap();
+
+    let states = database.region_states();
+    assert_eq!(states.len(), 2);
+
+    let east = &states[0];
+    assert_eq!(
+        east.data_persistence,
+        Some(DataPersistence::SnapshotEvery1Hour)
+    );
+    assert_eq!(east.alerts.len(), 2);
+    assert_eq!(east.source_ips, ["10.0.0.0/16"]);
+    assert!(east.password_set);
+
+    // Regions without overrides fall back to the global settings
+    let west = database.region_state("eu-west-1").unwrap();
+    assert_eq!(west.data_persistence, Some(DataPersistence::AofEvery1Sec));
+    assert_eq!(west.source_ips, ["0.0.0.0/0"]);
+    assert!(!west.password_set);
+    assert_eq!(west.enable_default_user, Some(false));
+
+    let desired = LocalRegionProperties {
+        region: Some("us-east-1".to_string()),
+        data_persistence: Some(DataPersistence::SnapshotEvery1Hour),
+        alerts: Some(vec![
+            DatabaseAlertSpec {
+                name: "dataset-size".to_string(),
+                value: 90,
+            },
+            DatabaseAlertSpec {
+                name: "throughput-higher-than".to_string(),
+                value: 1000,
+            },
+        ]),
+        source_ip: Some(vec!["10.0.0.0/16".to_string()]),
+        password: Some("new-secret".to_string()),
+        ..Default::default()
+    };
+    assert!(east.differences(&desired).is_empty());
+
+    let desired = LocalRegionProperties {
+        data_persistence: Some(DataPersistence::None),
+        password: Some("new-secret".to_string()),
+        ..desired
+    };
+    assert_eq!(
+        west.differences(&desired),
+        ["dataPersistence", "alerts", "sourceIp", "password"]
+    );
+}
+
+#[tokio::test]
+async fn test_find_database_by_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "databases": [
+                    {"databaseId": 1, "name": "cache"},
+                    {"databaseId": 2, "name": "cache-replica"},
+                    {"databaseId": 3, "name": "cache"},
+                    {"databaseId": 4, "name": "sessions"}
+                ]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let matches = DatabaseHandler::new(client)
+        .find_by_name(123, "cache")
+        .await
+        .unwrap();
+
+    let exact: Vec<_> = matches.exact.iter().map(|db| db.database_id).collect();
+    assert_eq!(exact, [1, 3]);
+    // Duplicate names are reported rather than silently picking one
+    assert!(matches.unique().is_none());
+    assert_eq!(matches.prefix[0].database_id, 2);
+}
+
+#[tokio::test]
+async fn test_upgrade_database_redis_version_if() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases/456/upgrade"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({ "targetRedisVersion": "7.4" })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-upgrade-456",
+            "commandType": "upgradeDatabaseRedisVersion",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let requirement: redis_cloud::RedisVersionReq = ">=7.2".parse().unwrap();
+
+    // Below the requirement and unparseable targets never reach the API
+    for target in ["6.2", "latest"] {
+        let skipped = handler
+            .upgrade_database_redis_version_if(123, 456, target, &requirement)
+            .await
+            .unwrap();
+        assert!(skipped.is_none());
+    }
+
+    let task = handler
+        .upgrade_database_redis_version_if(123, 456, "7.4", &requirement)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(task.task_id, Some("task-upgrade-456".to_string()));
+}
+
+#[tokio::test]
+async fn test_path_and_query_values_are_encoded() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subscriptions/123/databases/456/tags/cost%2Fcenter"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/slow-log"))
+        .and(query_param("regionName", "us east&1"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "entries": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    handler
+        .delete_tag(123, 456, "cost/center".to_string())
+        .await
+        .unwrap();
+    handler
+        .get_slow_log(123, 456, Some("us east&1".to_string()))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_upgrade_and_wait() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/subscriptions/123/databases/456/available-target-versions",
+        ))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "targetVersions": [{ "version": "7.4" }, { "version": "8.0" }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases/456/upgrade"))
+        .and(body_json(json!({ "targetRedisVersion": "7.4.0" })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-upgrade",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-upgrade"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-upgrade",
+            "status": "processing-completed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/upgrade"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "targetRedisVersion": "7.4",
+            "progress": 40.0,
+            "upgradeStatus": "in-progress"
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/upgrade"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "targetRedisVersion": "7.4",
+            "progress": 100.0,
+            "upgradeStatus": "completed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+
+    let err = handler
+        .upgrade_and_wait(123, 456, "7.2", &options)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, redis_cloud::CloudError::BadRequest { .. }));
+
+    let status = handler
+        .upgrade_and_wait(123, 456, "7.4.0", &options)
+        .await
+        .unwrap();
+    assert!(status.is_completed());
+    assert_eq!(status.progress, Some(100.0));
+}
+
+#[tokio::test]
+async fn test_upgrade_and_wait_without_task_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/subscriptions/123/databases/456/available-target-versions",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "targetVersions": [{ "version": "7.4" }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases/456/upgrade"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/upgrade"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "upgradeStatus": "completed"
+        })))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let err = DatabaseHandler::new(client)
+        .upgrade_and_wait(123, 456, "7.4", &TaskWaitOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        redis_cloud::CloudError::UnexpectedResponse { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_tag_upsert_and_merge() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/tags"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 1,
+            "tags": [
+                { "key": "team", "value": "payments" },
+                { "key": "env", "value": "dev" }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/456/tags/env"))
+        .and(body_json(json!({ "value": "prod" })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "key": "env", "value": "prod" })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases/456/tags"))
+        .and(body_json(json!({ "key": "owner", "value": "alice" })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "key": "owner", "value": "alice" })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/456/tags"))
+        .and(body_json(json!({
+            "tags": [
+                { "key": "team", "value": "payments" },
+                { "key": "env", "value": "prod" },
+                { "key": "owner", "value": "alice" }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "tags": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+
+    let tags = handler.get_tags_map(123, 456).await.unwrap();
+    assert_eq!(tags.get("team").map(String::as_str), Some("payments"));
+    assert_eq!(tags.len(), 2);
+
+    // Unchanged value: no write
+    let tag = handler.set_tag(123, 456, "team", "payments").await.unwrap();
+    assert_eq!(tag.value.as_deref(), Some("payments"));
+
+    let tag = handler.set_tag(123, 456, "env", "prod").await.unwrap();
+    assert_eq!(tag.value.as_deref(), Some("prod"));
+    let tag = handler.set_tag(123, 456, "owner", "alice").await.unwrap();
+    assert_eq!(tag.key.as_deref(), Some("owner"));
+
+    let unchanged = HashMap::from([("team".to_string(), "payments".to_string())]);
+    let current = handler.merge_tags(123, 456, &unchanged).await.unwrap();
+    assert_eq!(current.tags.unwrap().len(), 2);
+
+    let updates = HashMap::from([
+        ("owner".to_string(), "alice".to_string()),
+        ("env".to_string(), "prod".to_string()),
+    ]);
+    handler.merge_tags(123, 456, &updates).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_backup_all() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "numberOfDatabases": 2,
+                "databases": [
+                    {"databaseId": 1, "name": "cache"},
+                    {"databaseId": 2, "name": "global", "activeActiveRedis": true}
+                ]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 2,
+            "name": "global",
+            "activeActiveRedis": true,
+            "crdbDatabases": [
+                {"provider": "AWS", "region": "us-east-1"},
+                {"provider": "AWS", "region": "eu-west-1"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases/1/backup"))
+        .and(body_json(json!({"adhocBackupPath": "s3://bucket/adhoc"})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "backup-1",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    for (region, task_id) in [("us-east-1", "backup-2-us"), ("eu-west-1", "backup-2-eu")] {
+        Mock::given(method("POST"))
+            .and(path("/subscriptions/123/databases/2/backup"))
+            .and(body_json(json!({
+                "regionName": region,
+                "adhocBackupPath": "s3://bucket/adhoc"
+            })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+                "taskId": task_id,
+                "status": "received"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    for task_id in ["backup-1", "backup-2-us"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/tasks/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": "processing-completed"
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/tasks/backup-2-eu"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "backup-2-eu",
+            "status": "processing-error",
+            "response": {"error": "BACKUP_FAILED"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let options = BackupAllOptions {
+        adhoc_backup_path: Some("s3://bucket/adhoc".to_string()),
+        wait: TaskWaitOptions::default().poll_interval(Duration::from_millis(10)),
+        concurrency: 2,
+    };
+    let report = DatabaseHandler::new(client)
+        .backup_all(123, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(report.subscription_id, 123);
+    assert_eq!(report.results.len(), 3);
+    assert!(!report.all_completed());
+    assert!(report.results[0].is_completed());
+    assert_eq!(report.results[0].region, None);
+    assert_eq!(report.results[1].region.as_deref(), Some("us-east-1"));
+    assert!(report.results[1].is_completed());
+
+    let failures: Vec<_> = report.failures().collect();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].database_id, 2);
+    assert_eq!(failures[0].region.as_deref(), Some("eu-west-1"));
+}
+
+#[tokio::test]
+async fn test_backup_status_details() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/backup"))
+        .and(query_param("regionName", "us-east-1"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "backup-status",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/backup-status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "backup-status",
+            "status": "processing-completed",
+            "response": {
+                "resource": {
+                    "status": "failed",
+                    "lastBackupTime": "2026-01-10T04:00:00Z",
+                    "backupSizeInBytes": 1048576,
+                    "failureReason": "Access denied to s3://bucket/backups"
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let status = DatabaseHandler::new(client)
+        .get_backup_status_details(
+            123,
+            456,
+            Some("us-east-1".to_string()),
+            &TaskWaitOptions::default().poll_interval(Duration::from_millis(10)),
+        )
+        .await
+        .unwrap();
+
+    assert!(status.is_failed());
+    assert!(!status.is_completed());
+    assert_eq!(status.size_in_bytes, Some(1_048_576));
+    assert_eq!(
+        status.failure_reason.as_deref(),
+        Some("Access denied to s3://bucket/backups")
+    );
+    assert!(status.last_backup_time.is_some());
+}
+
+#[tokio::test]
+async fn test_clone_database() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases/1/backup"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({"adhocBackupPath": "s3://bucket/clones/"})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "clone-backup",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/1/backup"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "clone-backup-status",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/456/databases/2/import"))
+        .and(body_json(json!({
+            "sourceType": "aws-s3",
+            "importFromUri": ["s3://bucket/clones/db-1.rdb"]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "clone-import",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    for task_id in ["clone-backup", "clone-import"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/tasks/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": "processing-completed"
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/tasks/clone-backup-status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "clone-backup-status",
+            "status": "processing-completed",
+            "response": {
+                "resource": {
+                    "status": "done",
+                    "backupPath": "s3://bucket/clones/db-1.rdb"
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let mut options = CloneOptions::new("s3://bucket/clones/");
+    options.wait = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+
+    let report = handler
+        .clone_database(123, 1, 456, 2, &options)
+        .await
+        .unwrap();
+    assert_eq!(report.backup.task_id.as_deref(), Some("clone-backup"));
+    assert_eq!(report.import_uris, ["s3://bucket/clones/db-1.rdb"]);
+    assert_eq!(report.import.task_id.as_deref(), Some("clone-import"));
+
+    // Rejected before any request is sent
+    assert!(matches!(
+        handler.clone_database(123, 1, 123, 1, &options).await,
+        Err(CloudError::BadRequest { .. })
+    ));
+    let https = CloneOptions::new("https://example.com/dump.rdb");
+    assert!(matches!(
+        handler.clone_database(123, 1, 456, 2, &https).await,
+        Err(CloudError::BadRequest { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_slow_log_queries() {
+    use chrono::{TimeZone, Utc};
+    use futures::StreamExt;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "numberOfDatabases": 2,
+                "databases": [
+                    {"databaseId": 1, "name": "cache"},
+                    {"databaseId": 2, "name": "broken"}
+                ]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/1/slow-log"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "entries": [
+                {"id": 1, "startTime": "2026-01-10T09:00:00Z", "duration": 120, "arguments": "KEYS *"},
+                {"id": 2, "startTime": "2026-01-10T10:00:00Z", "duration": 80, "arguments": "SMEMBERS s"},
+                {"id": 3, "startTime": "2026-01-10T11:00:00Z", "duration": 95, "arguments": "HGETALL h"},
+                {"id": 4, "duration": 60, "arguments": "SORT l"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/2/slow-log"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "error": "forbidden"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let at = |hour: u32| Utc.with_ymd_and_hms(2026, 1, 10, hour, 0, 0).unwrap();
+
+    let all = handler
+        .query_slow_log(123, 1, &SlowLogQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 4);
+    assert_eq!(all[0].timestamp(), Some(at(9)));
+
+    let query = SlowLogQuery::default().between(at(10), at(12)).limit(1);
+    let window = handler.query_slow_log(123, 1, &query).await.unwrap();
+    let ids: Vec<_> = window.iter().map(|entry| entry.id).collect();
+    assert_eq!(ids, [Some(2)]);
+
+    let query = SlowLogQuery::default().since(at(10)).offset(1);
+    let streamed: Vec<_> = handler
+        .stream_slow_log(123, 1, &query)
+        .map(|entry| entry.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(streamed, [Some(3)]);
+
+    let fleet = handler
+        .get_subscription_slow_logs(123, &SlowLogQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(fleet.len(), 2);
+    assert_eq!(fleet[0].entries.as_ref().unwrap().len(), 4);
+    assert_eq!(fleet[1].name.as_deref(), Some("broken"));
+    assert!(fleet[1].entries.is_err());
+}
+
+#[tokio::test]
+async fn test_client_certificate_rotation() {
+    let old = "-----BEGIN CERTIFICATE-----\nOLD\n-----END CERTIFICATE-----\n";
+    let new = "-----BEGIN CERTIFICATE-----\nNEW\n-----END CERTIFICATE-----\n";
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "mtls",
+            "enableTls": true,
+            "clientTlsCertificates": [{"publicCertificatePemString": old}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/456"))
+        .and(body_json(json!({
+            "enableTls": true,
+            "clientTlsCertificates": [
+                {"publicCertificatePemString": old},
+                {"publicCertificatePemString": new}
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "rotate-task",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/rotate-task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "rotate-task",
+            "status": "processing-completed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/certificate"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "publicCertificatePemString": format!("{}\\n{}", old.trim(), new.trim()).replace('\n', "\\n")
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = DatabaseHandler::new(client);
+    let options = CertificateRotationOptions {
+        replace_existing: false,
+        wait: TaskWaitOptions::default().poll_interval(Duration::from_millis(10)),
+    };
+
+    // Already-present certificates are not duplicated
+    let updated = handler
+        .rotate_client_certificates(123, 456, &[new.to_string(), old.to_string()], &options)
+        .await
+        .unwrap();
+    assert_eq!(updated.task.task_id.as_deref(), Some("rotate-task"));
+    assert_eq!(updated.resource.database_id, 456);
+
+    assert!(matches!(
+        handler
+            .rotate_client_certificates(123, 456, &["not a cert".to_string()], &options)
+            .await,
+        Err(CloudError::BadRequest { .. })
+    ));
+
+    let chain = handler.get_server_certificate(123, 456).await.unwrap();
+    assert_eq!(chain, [old, new]);
+}
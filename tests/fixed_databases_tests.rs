@@ -1,6 +1,9 @@
-use redis_cloud::{CloudClient, FixedDatabaseHandler};
+use redis_cloud::fixed::databases::{FixedDatabaseCreateRequest, FixedDatabaseUpgradeRequest};
+use redis_cloud::tasks::TaskWaitOptions;
+use redis_cloud::{CloudClient, CloudError, FixedDatabaseHandler};
 use serde_json::json;
-use wiremock::matchers::{header, method, path};
+use std::time::Duration;
+use wiremock::matchers::{body_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -43,6 +46,51 @@ async fn test_get_fixed_subscription_databases() {
     assert!(result.links.is_some());
 }
 
+#[tokio::test]
+async fn test_list_fixed_databases_array_response() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [
+                {
+                    "subscriptionId": 123,
+                    "numberOfDatabases": 2,
+                    "databases": [
+                        {"databaseId": 1, "name": "cache", "status": "active"},
+                        {"databaseId": 2, "name": "sessions", "status": "pending"}
+                    ]
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let result = FixedDatabaseHandler::new(client)
+        .list(123, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.total_databases(), Some(2));
+    assert_eq!(result.subscription[0].subscription_id, Some(123));
+    let names: Vec<_> = result
+        .databases()
+        .iter()
+        .map(|db| db.name.as_deref())
+        .collect();
+    assert_eq!(names, vec![Some("cache"), Some("sessions")]);
+    assert_eq!(result.into_databases()[1].database_id, Some(2));
+}
+
 #[tokio::test]
 async fn test_create_fixed_database() {
     let mock_server = MockServer::start().await;
@@ -72,33 +120,12 @@ async fn test_create_fixed_database() {
         .unwrap();
 
     let handler = FixedDatabaseHandler::new(client);
-    let request = redis_cloud::fixed_databases::FixedDatabaseCreateRequest {
+    let request = redis_cloud::fixed::databases::FixedDatabaseCreateRequest {
         name: "fixed-test-database".to_string(),
-        subscription_id: None,
-        protocol: None,
         memory_limit_in_gb: Some(1.0),
-        dataset_size_in_gb: None,
-        support_oss_cluster_api: None,
-        redis_version: None,
-        resp_version: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        enable_database_clustering: None,
-        number_of_shards: None,
-        data_persistence: None,
-        data_eviction_policy: Some("noeviction".to_string()),
+        data_eviction_policy: Some(redis_cloud::types::DataEvictionPolicy::NoEviction),
         replication: Some(true),
-        periodic_backup_path: None,
-        source_ips: None,
-        regex_rules: None,
-        replica_of: None,
-        replica: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        alerts: None,
-        modules: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create(123, &request).await.unwrap();
@@ -134,32 +161,11 @@ async fn test_fixed_database_update() {
         .unwrap();
 
     let handler = FixedDatabaseHandler::new(client);
-    let request = redis_cloud::fixed_databases::FixedDatabaseUpdateRequest {
-        subscription_id: None,
-        database_id: None,
+    let request = redis_cloud::fixed::databases::FixedDatabaseUpdateRequest {
         name: Some("updated-fixed-database".to_string()),
         memory_limit_in_gb: Some(2.0),
-        dataset_size_in_gb: None,
-        data_persistence: None,
-        data_eviction_policy: Some("allkeys-lru".to_string()),
-        replication: None,
-        resp_version: None,
-        support_oss_cluster_api: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        password: None,
-        enable_database_clustering: None,
-        number_of_shards: None,
-        periodic_backup_path: None,
-        source_ips: None,
-        regex_rules: None,
-        replica_of: None,
-        replica: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        enable_default_user: None,
-        alerts: None,
-        command_type: None,
+        data_eviction_policy: Some(redis_cloud::types::DataEvictionPolicy::AllKeysLru),
+        ..Default::default()
     };
 
     let result = handler.update(123, 456, &request).await.unwrap();
@@ -224,12 +230,7 @@ async fn test_backup_fixed_database() {
         .unwrap();
 
     let handler = FixedDatabaseHandler::new(client);
-    let request = redis_cloud::fixed_databases::FixedDatabaseBackupRequest {
-        subscription_id: None,
-        database_id: None,
-        adhoc_backup_path: None,
-        command_type: None,
-    };
+    let request = redis_cloud::fixed::databases::FixedDatabaseBackupRequest::default();
     let result = handler.backup(123, 456, &request).await.unwrap();
 
     assert_eq!(result.task_id, Some("task-backup-fixed-db".to_string()));
@@ -260,12 +261,10 @@ async fn test_import_fixed_database() {
         .unwrap();
 
     let handler = FixedDatabaseHandler::new(client);
-    let request = redis_cloud::fixed_databases::FixedDatabaseImportRequest {
+    let request = redis_cloud::fixed::databases::FixedDatabaseImportRequest {
         source_type: "s3".to_string(),
         import_from_uri: vec!["s3://my-bucket/backup.rdb".to_string()],
-        subscription_id: None,
-        database_id: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.import(123, 456, &request).await.unwrap();
@@ -296,12 +295,10 @@ async fn test_tag_operations() {
         .unwrap();
 
     let handler = FixedDatabaseHandler::new(client);
-    let request = redis_cloud::fixed_databases::DatabaseTagCreateRequest {
+    let request = redis_cloud::fixed::databases::DatabaseTagCreateRequest {
         key: "environment".to_string(),
         value: "production".to_string(),
-        subscription_id: None,
-        database_id: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_tag(123, 456, &request).await.unwrap();
@@ -422,33 +419,9 @@ async fn test_error_handling_500() {
         .unwrap();
 
     let handler = FixedDatabaseHandler::new(client);
-    let request = redis_cloud::fixed_databases::FixedDatabaseCreateRequest {
+    let request = redis_cloud::fixed::databases::FixedDatabaseCreateRequest {
         name: "test-database".to_string(),
-        subscription_id: None,
-        protocol: None,
-        memory_limit_in_gb: None,
-        dataset_size_in_gb: None,
-        support_oss_cluster_api: None,
-        redis_version: None,
-        resp_version: None,
-        use_external_endpoint_for_oss_cluster_api: None,
-        enable_database_clustering: None,
-        number_of_shards: None,
-        data_persistence: None,
-        data_eviction_policy: None,
-        replication: None,
-        periodic_backup_path: None,
-        source_ips: None,
-        regex_rules: None,
-        replica_of: None,
-        replica: None,
-        client_ssl_certificate: None,
-        client_tls_certificates: None,
-        enable_tls: None,
-        password: None,
-        alerts: None,
-        modules: None,
-        command_type: None,
+        ..Default::default()
     };
     let result = handler.create(123, &request).await;
 
@@ -458,3 +431,358 @@ async fn test_error_handling_500() {
         _ => panic!("Expected InternalServerError error"),
     }
 }
+
+#[tokio::test]
+async fn test_create_validated_rejects_unsupported_features() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123,
+            "name": "essentials",
+            "planId": 98181
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/plans/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "plans": [{
+                "id": 98181,
+                "name": "Cache 30MB",
+                "supportReplication": false,
+                "supportDataPersistence": false,
+                "supportInstantAndDailyBackups": false,
+                "cidrAllowRules": 1
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/fixed/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({"taskId": "t-1"})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FixedDatabaseHandler::new(client);
+    let request: FixedDatabaseCreateRequest = serde_json::from_value(json!({
+        "name": "cache",
+        "replication": true,
+        "dataPersistence": "aof-every-1-sec",
+        "sourceIps": ["10.0.0.0/24", "10.0.1.0/24"]
+    }))
+    .unwrap();
+
+    match handler.create_validated(123, &request).await.unwrap_err() {
+        CloudError::BadRequest { message } => {
+            assert!(message.contains("98181"));
+            assert!(message.contains("replication"));
+            assert!(message.contains("data persistence"));
+            assert!(message.contains("1 CIDR allow rules but 2 source IPs"));
+        }
+        err => panic!("Expected BadRequest, got {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn test_create_validated_without_plan_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 123})))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let request: FixedDatabaseCreateRequest =
+        serde_json::from_value(json!({"name": "cache"})).unwrap();
+    let err = FixedDatabaseHandler::new(client)
+        .create_validated(123, &request)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CloudError::UnexpectedResponse { .. }));
+}
+
+#[tokio::test]
+async fn test_create_validated_submits_supported_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123,
+            "planId": 98182
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/plans/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"plans": []})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/plans/98182"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 98182,
+            "supportReplication": true,
+            "supportDataPersistence": true,
+            "cidrAllowRules": 8
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/fixed/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({"taskId": "t-2"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FixedDatabaseHandler::new(client);
+    let request: FixedDatabaseCreateRequest = serde_json::from_value(json!({
+        "name": "cache",
+        "replication": true,
+        "sourceIps": ["10.0.0.0/24"]
+    }))
+    .unwrap();
+
+    let task = handler.create_validated(123, &request).await.unwrap();
+    assert_eq!(task.task_id, Some("t-2".to_string()));
+}
+
+#[tokio::test]
+async fn test_stream_tags() {
+    use futures::StreamExt;
+    use std::pin::pin;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": {
+                "subscriptionId": 123,
+                "numberOfDatabases": 2,
+                "databases": [
+                    {"databaseId": 1, "name": "cache"},
+                    {"databaseId": 2, "name": "queue"}
+                ]
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases/1/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "tags": [{"key": "env", "value": "prod", "createdAt": "2024-05-21T20:02:21+02:00"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases/2/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"accountId": 456})))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FixedDatabaseHandler::new(client);
+    let mut stream = pin!(handler.stream_tags(123));
+
+    let first = stream.next().await.unwrap().unwrap();
+    assert_eq!(first.subscription_id, 123);
+    assert_eq!(first.database.name.as_deref(), Some("cache"));
+    assert_eq!(first.tags[0].key.as_deref(), Some("env"));
+    assert_eq!(first.tags[0].value.as_deref(), Some("prod"));
+
+    let second = stream.next().await.unwrap().unwrap();
+    assert_eq!(second.database.database_id, Some(2));
+    assert!(second.tags.is_empty());
+
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_get_all_databases_pages() {
+    let mock_server = MockServer::start().await;
+
+    let first_page: Vec<_> = (1..=100)
+        .map(|id| json!({"databaseId": id, "name": format!("db-{id}")}))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases"))
+        .and(query_param("offset", "0"))
+        .and(query_param("limit", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscription": {"subscriptionId": 123, "databases": first_page}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases"))
+        .and(query_param("offset", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscription": {
+                "subscriptionId": 123,
+                "databases": [{"databaseId": 101, "name": "db-101"}]
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let databases = FixedDatabaseHandler::new(client)
+        .get_all_databases(123)
+        .await
+        .unwrap();
+    assert_eq!(databases.len(), 101);
+    assert_eq!(databases[100].name.as_deref(), Some("db-101"));
+}
+
+#[tokio::test]
+async fn test_import_status_details() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases/456/import"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "import-status",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/import-status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "import-status",
+            "status": "processing-completed",
+            "response": {
+                "resource": {
+                    "status": "importing",
+                    "progress": 42.5,
+                    "sourceType": "aws-s3"
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let status = FixedDatabaseHandler::new(client)
+        .get_import_status_details(
+            123,
+            456,
+            &TaskWaitOptions::default().poll_interval(Duration::from_millis(10)),
+        )
+        .await
+        .unwrap();
+
+    assert!(status.is_in_progress());
+    assert!(!status.is_failed());
+    assert_eq!(status.progress, Some(42.5));
+    assert_eq!(status.source_type.as_deref(), Some("aws-s3"));
+}
+
+#[tokio::test]
+async fn test_upgrade_redis_version() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/fixed/subscriptions/123/databases/456/upgrade"))
+        .and(body_json(json!({"targetRedisVersion": "7.4"})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-upgrade",
+            "commandType": "UPGRADE_DATABASE_REDIS_VERSION",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123/databases/456/upgrade"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "targetRedisVersion": "7.4",
+            "progress": 100.0,
+            "upgradeStatus": "done"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = FixedDatabaseHandler::new(client);
+
+    let task = handler
+        .upgrade_redis_version(123, 456, &FixedDatabaseUpgradeRequest::new("7.4"))
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-upgrade"));
+
+    let status = handler.get_upgrade_status(123, 456).await.unwrap();
+    assert_eq!(status.database_id, Some(456));
+    assert_eq!(status.target_redis_version.as_deref(), Some("7.4"));
+    assert!(status.is_completed());
+    assert!(!status.is_failed());
+}
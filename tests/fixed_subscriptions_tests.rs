@@ -1,6 +1,9 @@
-use redis_cloud::{CloudClient, FixedSubscriptionsHandler};
+use redis_cloud::fixed::subscriptions::FixedSubscriptionCreateRequest;
+use redis_cloud::tasks::TaskWaitOptions;
+use redis_cloud::{CloudClient, CloudError, FixedSubscriptionHandler};
 use serde_json::json;
-use wiremock::matchers::{header, method, path, query_param};
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -14,7 +17,7 @@ async fn test_get_all_fixed_subscriptions_plans() {
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
             "plans": [
                 {
-                    "id": "plan-1",
+                    "id": 1,
                     "name": "Cache 250MB",
                     "size": 250,
                     "sizeMeasurementUnit": "MB",
@@ -22,7 +25,7 @@ async fn test_get_all_fixed_subscriptions_plans() {
                     "region": "us-east-1"
                 },
                 {
-                    "id": "plan-2",
+                    "id": 2,
                     "name": "Cache 1GB",
                     "size": 1,
                     "sizeMeasurementUnit": "GB",
@@ -41,12 +44,103 @@ async fn test_get_all_fixed_subscriptions_plans() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.list_plans(None, None).await.unwrap();
 
-    // Verify the response was successfully parsed
-    // Note: plans data would need typed fields added to FixedSubscriptionsPlans to be accessible
     assert!(result.links.is_none()); // No links in the mock response
+    assert_eq!(result.plans.len(), 2);
+    assert_eq!(result.plans[0].id, Some(1));
+    assert_eq!(result.plans[1].region, Some("us-west-2".to_string()));
+}
+
+#[tokio::test]
+async fn test_list_plans_filtered_by_provider_and_region() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/plans"))
+        .and(query_param("provider", "AWS"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "plans": [
+                {
+                    "id": 1,
+                    "name": "Standard 250MB",
+                    "size": 250,
+                    "sizeMeasurementUnit": "MB",
+                    "provider": "AWS",
+                    "region": "us-east-1",
+                    "price": 5,
+                    "supportReplication": true,
+                    "supportDataPersistence": true
+                },
+                {
+                    "id": 2,
+                    "name": "Standard 250MB",
+                    "size": 250,
+                    "sizeMeasurementUnit": "MB",
+                    "provider": "AWS",
+                    "region": "eu-west-1",
+                    "price": 5
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FixedSubscriptionHandler::new(client);
+    let result = handler
+        .list_plans(Some("AWS".to_string()), Some("US-EAST-1".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(result.plans.len(), 1);
+    assert_eq!(result.plans[0].id, Some(1));
+    assert_eq!(result.plans[0].support_replication, Some(true));
+}
+
+#[tokio::test]
+async fn test_get_plans_for_subscription() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/plans/subscriptions/123"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "plans": [
+                {
+                    "id": 10,
+                    "name": "Standard 1GB",
+                    "size": 1,
+                    "sizeMeasurementUnit": "GB",
+                    "price": 22
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FixedSubscriptionHandler::new(client);
+    let result = handler.get_plans_for_subscription(123).await.unwrap();
+
+    assert_eq!(result.plans.len(), 1);
+    assert_eq!(result.plans[0].price, Some(22));
 }
 
 #[tokio::test]
@@ -81,7 +175,7 @@ async fn test_get_fixed_subscriptions_plans_by_subscription_id() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.get_plans_by_subscription_id(123).await.unwrap();
 
     // Verify the response was successfully parsed
@@ -115,7 +209,7 @@ async fn test_get_fixed_subscriptions_plan_by_id() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.get_plan_by_id(123).await.unwrap();
 
     assert_eq!(result.id, Some(123));
@@ -157,12 +251,19 @@ async fn test_get_redis_versions() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.get_redis_versions(123).await.unwrap();
 
     assert!(result.redis_versions.is_some());
+    assert_eq!(
+        result.default_version().and_then(|v| v.version.as_deref()),
+        Some("7.2")
+    );
     let versions = result.redis_versions.unwrap();
     assert_eq!(versions.len(), 3);
+
+    let default = handler.get_default_redis_version(123).await.unwrap();
+    assert_eq!(default.and_then(|v| v.version), Some("7.2".to_string()));
 }
 
 #[tokio::test]
@@ -200,11 +301,13 @@ async fn test_get_all_subscriptions() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.list().await.unwrap();
 
     assert_eq!(result.account_id, Some(456));
-    // Note: subscriptions data would need a typed field to be accessible
+    let subscriptions = result.subscriptions.unwrap();
+    assert_eq!(subscriptions.len(), 2);
+    assert_eq!(subscriptions[0].name.as_deref(), Some("Production Fixed"));
 }
 
 #[tokio::test]
@@ -235,13 +338,13 @@ async fn test_create_subscription() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
-    let request = redis_cloud::fixed_subscriptions::FixedSubscriptionCreateRequest {
+    let handler = FixedSubscriptionHandler::new(client);
+    let request = redis_cloud::fixed::subscriptions::FixedSubscriptionCreateRequest {
         name: "New Fixed Subscription".to_string(),
         plan_id: 123,
         payment_method: Some("credit-card".to_string()),
         payment_method_id: Some(1001),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create(&request).await.unwrap();
@@ -272,7 +375,7 @@ async fn test_delete_subscription_by_id() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.delete_by_id(123).await.unwrap();
 
     assert_eq!(result.task_id, Some("task-delete-fixed-sub".to_string()));
@@ -305,7 +408,7 @@ async fn test_get_subscription_by_id() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.get_by_id(123).await.unwrap();
 
     assert_eq!(result.id, Some(123));
@@ -336,14 +439,13 @@ async fn test_update_subscription() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
-    let request = redis_cloud::fixed_subscriptions::FixedSubscriptionUpdateRequest {
+    let handler = FixedSubscriptionHandler::new(client);
+    let request = redis_cloud::fixed::subscriptions::FixedSubscriptionUpdateRequest {
         name: Some("Updated Fixed Subscription".to_string()),
         plan_id: Some(124),
         payment_method: Some("credit-card".to_string()),
         payment_method_id: Some(1002),
-        subscription_id: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.update(123, &request).await.unwrap();
@@ -369,7 +471,7 @@ async fn test_error_handling_401() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.list().await;
 
     assert!(result.is_err());
@@ -400,7 +502,7 @@ async fn test_error_handling_404() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
+    let handler = FixedSubscriptionHandler::new(client);
     let result = handler.get_by_id(999).await;
 
     assert!(result.is_err());
@@ -432,13 +534,12 @@ async fn test_error_handling_500() {
         .build()
         .unwrap();
 
-    let handler = FixedSubscriptionsHandler::new(client);
-    let request = redis_cloud::fixed_subscriptions::FixedSubscriptionCreateRequest {
+    let handler = FixedSubscriptionHandler::new(client);
+    let request = redis_cloud::fixed::subscriptions::FixedSubscriptionCreateRequest {
         name: "Test Subscription".to_string(),
         plan_id: 100,
         payment_method: Some("credit-card".to_string()),
-        payment_method_id: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create(&request).await;
@@ -449,3 +550,184 @@ async fn test_error_handling_500() {
         _ => panic!("Expected InternalServerError error"),
     }
 }
+
+#[tokio::test]
+async fn test_list_paginated() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 40131,
+            "subscriptions": [
+                {
+                    "id": 151367,
+                    "name": "fixed-1",
+                    "planId": 98276,
+                    "planName": "Standard 1GB",
+                    "provider": "AWS",
+                    "region": "us-west-1",
+                    "price": 22,
+                    "connections": "1024"
+                },
+                {
+                    "id": 120416,
+                    "name": "subscription-name",
+                    "planId": 123,
+                    "planName": "Standard 30MB",
+                    "provider": "AWS",
+                    "region": "us-east-1",
+                    "price": 0,
+                    "connections": 30
+                },
+                {"id": 120417, "name": "third"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FixedSubscriptionHandler::new(client);
+
+    let page = handler.list_paginated(Some(1), Some(1)).await.unwrap();
+    let subscriptions = page.subscriptions.unwrap();
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(subscriptions[0].id, Some(120416));
+    assert_eq!(subscriptions[0].plan_name.as_deref(), Some("Standard 30MB"));
+    assert_eq!(subscriptions[0].region.as_deref(), Some("us-east-1"));
+    assert_eq!(subscriptions[0].price, Some(0));
+    assert_eq!(subscriptions[0].connections.as_deref(), Some("30"));
+
+    let all = handler.list_paginated(None, None).await.unwrap();
+    assert_eq!(all.subscriptions.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_create_and_wait() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/fixed/subscriptions"))
+        .and(body_partial_json(json!({"name": "cache", "planId": 42})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-create",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-create"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-create",
+            "status": "processing-completed",
+            "response": {"resourceId": 777}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/777"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 777,
+            "name": "cache",
+            "status": "active",
+            "planId": 42
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let request = FixedSubscriptionCreateRequest {
+        name: "cache".to_string(),
+        plan_id: 42,
+        ..Default::default()
+    };
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let created = FixedSubscriptionHandler::new(client)
+        .create_and_wait(&request, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(created.task.task_id.as_deref(), Some("task-create"));
+    assert_eq!(created.resource.id, Some(777));
+    assert_eq!(created.resource.name.as_deref(), Some("cache"));
+}
+
+#[tokio::test]
+async fn test_change_plan() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/plans/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "plans": [
+                {"id": 42, "name": "Standard 250MB"},
+                {"id": 43, "name": "Standard 1GB"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/fixed/subscriptions/123"))
+        .and(body_partial_json(json!({"planId": 43})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-resize",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-resize"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-resize",
+            "status": "processing-completed",
+            "response": {"resourceId": 123}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fixed/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123,
+            "name": "cache",
+            "planId": 43
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FixedSubscriptionHandler::new(client);
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let updated = handler.change_plan(123, 43, &options).await.unwrap();
+    assert_eq!(updated.task.task_id.as_deref(), Some("task-resize"));
+    assert_eq!(updated.resource.plan_id, Some(43));
+
+    let err = handler.change_plan(123, 99, &options).await.unwrap_err();
+    assert!(matches!(err, CloudError::BadRequest { message } if message.contains("Plan 99")));
+}
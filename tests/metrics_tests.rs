@@ -0,0 +1,64 @@
+use redis_cloud::metrics::prometheus_scrape_url;
+use redis_cloud::{CloudClient, MetricsHandler};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn test_client(uri: String) -> CloudClient {
+    CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(uri)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_prometheus_url() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "cache",
+            "privateEndpoint": "redis-12345.internal.c1.us-east-1-1.ec2.cloud.redislabs.com:12345"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/789"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 789,
+            "name": "public-only",
+            "publicEndpoint": "redis-12346.c1.us-east-1-1.ec2.cloud.redislabs.com:12346"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let handler = MetricsHandler::new(test_client(mock_server.uri()));
+
+    assert_eq!(
+        handler.prometheus_url(123, 456).await.unwrap().as_deref(),
+        Some("https://internal.c1.us-east-1-1.ec2.cloud.redislabs.com:8070")
+    );
+    assert_eq!(handler.prometheus_url(123, 789).await.unwrap(), None);
+}
+
+#[test]
+fn test_prometheus_scrape_url() {
+    assert_eq!(
+        prometheus_scrape_url("internal.c7.eu-west-1-2.ec2.cloud.redislabs.com").as_deref(),
+        Some("https://internal.c7.eu-west-1-2.ec2.cloud.redislabs.com:8070")
+    );
+    assert_eq!(
+        prometheus_scrape_url("redis://redis-1.internal.c7.gcp.cloud.rlrcp.com:1/").as_deref(),
+        Some("https://internal.c7.gcp.cloud.rlrcp.com:8070")
+    );
+    assert_eq!(
+        prometheus_scrape_url("redis-12346.c1.us-east-1-1.ec2.cloud.redislabs.com:12346"),
+        None
+    );
+    assert_eq!(prometheus_scrape_url(""), None);
+}
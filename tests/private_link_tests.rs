@@ -2,8 +2,10 @@ use redis_cloud::connectivity::{
     PrincipalType, PrivateLinkAddPrincipalRequest, PrivateLinkCreateRequest,
     PrivateLinkRemovePrincipalRequest,
 };
+use redis_cloud::tasks::TaskWaitOptions;
 use redis_cloud::{CloudClient, PrivateLinkHandler};
 use serde_json::json;
+use wiremock::matchers::body_json;
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -266,7 +268,7 @@ async fn test_create_active_active_private_link() {
         share_name: "my-crdb-share".to_string(),
         principal: "111222333444".to_string(),
         principal_type: PrincipalType::AwsAccount,
-        alias: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -314,7 +316,7 @@ async fn test_add_principals_active_active() {
     let request = PrivateLinkAddPrincipalRequest {
         principal: "555666777888".to_string(),
         principal_type: Some(PrincipalType::AwsAccount),
-        alias: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -354,7 +356,7 @@ async fn test_remove_principals_active_active() {
     let request = PrivateLinkRemovePrincipalRequest {
         principal: "555666777888".to_string(),
         principal_type: Some(PrincipalType::AwsAccount),
-        alias: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -471,9 +473,202 @@ async fn test_error_handling_500() {
         share_name: "test".to_string(),
         principal: "123".to_string(),
         principal_type: PrincipalType::AwsAccount,
-        alias: None,
+        ..Default::default()
     };
     let result = handler.create(123, &request).await;
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_typed_private_link_share_principals_and_connections() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/private-link"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "active",
+            "shareName": "my-redis-share",
+            "shareArn": "arn:aws:ram:us-east-1:111111111111:resource-share/abc",
+            "resourceConfigurationId": "rcfg-0abc",
+            "principals": [
+                {"principal": "123456789012", "type": "aws_account", "status": "associated"},
+                {"principal": "arn:aws:iam::123456789012:role/app", "type": "iam_role"},
+                {"principal": "o-example", "type": "future_kind"}
+            ],
+            "connections": [{
+                "associationId": "rcfg-assoc-1",
+                "connectionId": "vpce-0abc",
+                "type": "resource-endpoint",
+                "ownerId": "123456789012"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/private-link/principals"))
+        .and(body_json(
+            json!({"principal": "210987654321", "type": "aws_account"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "principals": [{"principal": "210987654321", "type": "aws_account"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subscriptions/123/private-link/principals"))
+        .and(body_json(json!({"principal": "210987654321"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"status": "active"})))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key")
+        .api_secret("test-secret")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = PrivateLinkHandler::new(client);
+
+    let share = handler.get_share(123).await.unwrap();
+    assert_eq!(share.share_name.as_deref(), Some("my-redis-share"));
+    assert_eq!(
+        share.resource_configuration_id.as_deref(),
+        Some("rcfg-0abc")
+    );
+
+    let principals = handler.get_principals(123).await.unwrap();
+    let types: Vec<_> = principals
+        .iter()
+        .map(|p| p.principal_type.clone())
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            Some(PrincipalType::AwsAccount),
+            Some(PrincipalType::IamRole),
+            Some(PrincipalType::Unknown)
+        ]
+    );
+
+    let connections = handler.get_connections(123).await.unwrap();
+    assert_eq!(connections[0].connection_id.as_deref(), Some("vpce-0abc"));
+
+    let request = PrivateLinkAddPrincipalRequest {
+        principal: "210987654321".to_string(),
+        principal_type: Some(PrincipalType::AwsAccount),
+        alias: None,
+    };
+    let updated = handler.add_principal(123, &request).await.unwrap();
+    assert_eq!(updated.principals.unwrap().len(), 1);
+
+    let request = PrivateLinkRemovePrincipalRequest {
+        principal: "210987654321".to_string(),
+        ..Default::default()
+    };
+    let updated = handler.remove_principal(123, &request).await.unwrap();
+    assert_eq!(updated.status.as_deref(), Some("active"));
+}
+
+#[tokio::test]
+async fn test_rotate_principal_with_task_waits() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/private-link/principals"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({
+            "principal": "arn:aws:iam::123456789012:role/new",
+            "type": "iam_role",
+            "alias": "new role"
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-add",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subscriptions/123/private-link/principals"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({
+            "principal": "arn:aws:iam::123456789012:role/old",
+            "type": "iam_role",
+            "alias": "old role"
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-remove",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    for task_id in ["task-add", "task-remove"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/tasks/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": "processing-completed"
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/private-link"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "principals": [
+                {
+                    "principal": "arn:aws:iam::123456789012:role/old",
+                    "type": "iam_role",
+                    "alias": "old role",
+                    "status": "associated"
+                },
+                {
+                    "principal": "arn:aws:iam::123456789012:role/new",
+                    "type": "iam_role",
+                    "alias": "new role",
+                    "status": "associated"
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key")
+        .api_secret("test-secret")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = PrivateLinkHandler::new(client);
+    let options = TaskWaitOptions::default();
+
+    let request = PrivateLinkAddPrincipalRequest::new(
+        "arn:aws:iam::123456789012:role/new",
+        PrincipalType::IamRole,
+    )
+    .alias("new role");
+    let added = handler
+        .add_principal_and_fetch(123, &request, &options)
+        .await
+        .unwrap();
+    assert_eq!(added.task.task_id.as_deref(), Some("task-add"));
+    assert_eq!(added.resource.len(), 2);
+
+    let old = PrivateLinkRemovePrincipalRequest::from(&added.resource[0]);
+    let removed = handler
+        .remove_principal_and_fetch(123, &old, &options)
+        .await
+        .unwrap();
+    assert_eq!(removed.task.task_id.as_deref(), Some("task-remove"));
+}
@@ -1,6 +1,11 @@
-use redis_cloud::{CloudClient, SubscriptionsHandler};
+use redis_cloud::flexible::subscriptions::{
+    ActiveActiveRegionDeleteRequest, CustomerManagedKey, DeletionGracePeriod,
+    PersistentStorageEncryptionType, SubscriptionCreateRequest, SubscriptionRegionSpec,
+    SubscriptionSpec, SubscriptionUpdateCMKRequest,
+};
+use redis_cloud::{CloudClient, CloudError, SubscriptionHandler};
 use serde_json::json;
-use wiremock::matchers::{header, method, path, query_param};
+use wiremock::matchers::{body_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -32,7 +37,7 @@ async fn test_get_all_subscriptions() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.get_all_subscriptions().await.unwrap();
 
     assert_eq!(result.account_id, Some(456));
@@ -72,36 +77,29 @@ async fn test_create_subscription() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     // Using the SubscriptionCreateRequest with required fields
     let request = redis_cloud::subscriptions::SubscriptionCreateRequest {
         name: Some("New Subscription".to_string()),
         payment_method_id: Some(1001),
-        payment_method: None,
         memory_storage: Some("ram".to_string()),
-        persistent_storage_encryption_type: None,
         deployment_type: Some("single-region".to_string()),
-        dry_run: None,
         cloud_providers: vec![redis_cloud::subscriptions::SubscriptionSpec {
-            provider: Some("AWS".to_string()),
+            provider: Some(redis_cloud::types::CloudProvider::Aws),
             cloud_account_id: Some(1001),
             regions: vec![redis_cloud::subscriptions::SubscriptionRegionSpec {
                 region: "us-west-2".to_string(),
-                multiple_availability_zones: None,
-                preferred_availability_zones: None,
                 networking: Some(
                     redis_cloud::subscriptions::SubscriptionRegionNetworkingSpec {
                         deployment_cidr: Some("10.0.0.0/20".to_string()),
-                        vpc_id: None,
-                        subnet_ids: None,
-                        security_group_id: None,
+                        ..Default::default()
                     },
                 ),
+                ..Default::default()
             }],
         }],
         databases: vec![],
-        redis_version: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_subscription(&request).await.unwrap();
@@ -139,10 +137,17 @@ async fn test_get_redis_versions() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.get_redis_versions(Some(123)).await.unwrap();
 
     assert!(result.redis_versions.is_some());
+
+    let requirement = ">=7.2".parse().unwrap();
+    let eligible: Vec<_> = result
+        .matching(&requirement)
+        .filter_map(|v| v.version.as_deref())
+        .collect();
+    assert_eq!(eligible, ["7.2"]);
 }
 
 #[tokio::test]
@@ -168,7 +173,7 @@ async fn test_delete_subscription_by_id() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.delete_subscription_by_id(123).await.unwrap();
 
     assert_eq!(result.task_id, Some("task-delete-sub".to_string()));
@@ -186,7 +191,40 @@ async fn test_get_subscription_by_id() {
             "id": 123,
             "name": "Production",
             "status": "active",
-            "paymentMethodType": "credit-card"
+            "paymentMethodType": "credit-card",
+            "cloudDetails": [{
+                "provider": "AWS",
+                "cloudAccountId": 1,
+                "totalSizeInGb": 0.5,
+                "regions": [{
+                    "region": "us-east-1",
+                    "networking": [{
+                        "deploymentCIDR": "10.0.0.0/24",
+                        "vpcId": "vpc-123",
+                        "subnetId": "subnet-456"
+                    }],
+                    "preferredAvailabilityZones": ["use1-az1"],
+                    "multipleAvailabilityZones": false
+                }]
+            }],
+            "pricing": [
+                {
+                    "type": "Shards",
+                    "quantity": 2,
+                    "quantityMeasurement": "shards",
+                    "pricePerUnit": 0.25,
+                    "priceCurrency": "USD",
+                    "pricePeriod": "hour"
+                },
+                {
+                    "type": "EBS Volume",
+                    "quantity": 4,
+                    "pricePerUnit": 0.5,
+                    "priceCurrency": "USD",
+                    "pricePeriod": "hour"
+                },
+                { "type": "Minimum", "typeDetails": "no unit price" }
+            ]
         })))
         .mount(&mock_server)
         .await;
@@ -198,11 +236,101 @@ async fn test_get_subscription_by_id() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.get_subscription_by_id(123).await.unwrap();
 
     assert_eq!(result.id, Some(123));
     assert_eq!(result.name, Some("Production".to_string()));
+
+    let cloud = &result.cloud_details.as_ref().unwrap()[0];
+    assert_eq!(cloud.provider, Some(redis_cloud::types::CloudProvider::Aws));
+    let region = result.regions().next().unwrap();
+    assert_eq!(region.region.as_deref(), Some("us-east-1"));
+    assert_eq!(
+        region.networking.as_ref().unwrap()[0].vpc_id.as_deref(),
+        Some("vpc-123")
+    );
+
+    let pricing = result.pricing.as_ref().unwrap();
+    assert_eq!(pricing[0].line_total(), Some(0.5));
+    assert_eq!(pricing[2].line_total(), None);
+    assert_eq!(result.price_totals(), [("USD".to_string(), 2.5)]);
+}
+
+#[tokio::test]
+async fn test_get_subscription_with_customer_managed_key() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/124"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 124,
+            "name": "Encrypted",
+            "status": "active",
+            "persistentStorageEncryptionType": "customer-managed-key",
+            "deletionGracePeriod": "1-hour",
+            "customerManagedKeyAccessDetails": {
+                "redisServiceAccount": "redis@example.iam.gserviceaccount.com",
+                "deletionGracePeriodOptions": ["alerts-only", "immediate", "1-hour", "48-hours"]
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SubscriptionHandler::new(client);
+    let result = handler.get_subscription_by_id(124).await.unwrap();
+
+    assert!(result.uses_customer_managed_key());
+    assert_eq!(result.grace_period(), Some(DeletionGracePeriod::OneHour));
+    assert_eq!(
+        result.grace_period().unwrap().duration(),
+        Some(std::time::Duration::from_secs(3600))
+    );
+
+    let details = result.customer_managed_key_access_details.unwrap();
+    assert_eq!(
+        details.grace_period_options(),
+        vec![
+            DeletionGracePeriod::AlertsOnly,
+            DeletionGracePeriod::Immediate,
+            DeletionGracePeriod::OneHour,
+        ]
+    );
+    assert!(details.supports_grace_period(DeletionGracePeriod::Immediate));
+    assert!(!details.supports_grace_period(DeletionGracePeriod::FourHours));
+}
+
+#[test]
+fn test_persistent_storage_encryption_type_serialization() {
+    let value = serde_json::to_value(PersistentStorageEncryptionType::CustomerManagedKey).unwrap();
+    assert_eq!(value, json!("customer-managed-key"));
+    assert_eq!(
+        PersistentStorageEncryptionType::default().to_string(),
+        "cloud-provider-managed-key"
+    );
+    let other: PersistentStorageEncryptionType =
+        serde_json::from_value(json!("hsm-managed-key")).unwrap();
+    assert_eq!(
+        other,
+        PersistentStorageEncryptionType::Other("hsm-managed-key".to_string())
+    );
+    assert_eq!(
+        serde_json::to_value(&other).unwrap(),
+        json!("hsm-managed-key")
+    );
+    assert_eq!(
+        serde_json::to_value(DeletionGracePeriod::TwentyFourHours).unwrap(),
+        json!("24-hours")
+    );
 }
 
 #[tokio::test]
@@ -228,11 +356,8 @@ async fn test_update_subscription() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
-    let request = redis_cloud::subscriptions::BaseSubscriptionUpdateRequest {
-        subscription_id: None,
-        command_type: None,
-    };
+    let handler = SubscriptionHandler::new(client);
+    let request = redis_cloud::subscriptions::BaseSubscriptionUpdateRequest::default();
 
     let result = handler.update_subscription(123, &request).await.unwrap();
     assert_eq!(result.task_id, Some("task-update-sub".to_string()));
@@ -264,7 +389,7 @@ async fn test_get_cidr_allowlist() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.get_cidr_allowlist(123).await.unwrap();
 
     assert!(result.response.is_some());
@@ -293,12 +418,10 @@ async fn test_update_subscription_cidr_allowlist() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let request = redis_cloud::subscriptions::CidrAllowlistUpdateRequest {
-        subscription_id: None,
         cidr_ips: Some(vec!["192.168.0.0/16".to_string()]),
-        security_group_ids: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -336,7 +459,7 @@ async fn test_get_subscription_maintenance_windows() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler
         .get_subscription_maintenance_windows(123)
         .await
@@ -369,10 +492,10 @@ async fn test_update_subscription_maintenance_windows() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let request = redis_cloud::subscriptions::SubscriptionMaintenanceWindowsSpec {
         mode: "automatic".to_string(),
-        windows: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -382,6 +505,42 @@ async fn test_update_subscription_maintenance_windows() {
     assert_eq!(result.task_id, Some("task-update-maintenance".to_string()));
 }
 
+#[tokio::test]
+async fn test_skip_and_unskip_maintenance_window() {
+    let mock_server = MockServer::start().await;
+
+    for (action, task_id) in [("skip", "task-skip"), ("unskip", "task-unskip")] {
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/subscriptions/123/maintenance-windows/{action}"
+            )))
+            .and(header("x-api-key", "test-key"))
+            .and(header("x-api-secret-key", "test-secret"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+                "taskId": task_id,
+                "commandType": "SKIP_MAINTENANCE_WINDOW",
+                "status": "received"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SubscriptionHandler::new(client);
+    let skipped = handler.skip_maintenance_window(123).await.unwrap();
+    assert_eq!(skipped.task_id.as_deref(), Some("task-skip"));
+
+    let unskipped = handler.unskip_maintenance_window(123).await.unwrap();
+    assert_eq!(unskipped.task_id.as_deref(), Some("task-unskip"));
+}
+
 #[tokio::test]
 async fn test_get_subscription_pricing() {
     let mock_server = MockServer::start().await;
@@ -414,7 +573,7 @@ async fn test_get_subscription_pricing() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.get_subscription_pricing(123).await.unwrap();
 
     // Check that pricing data was parsed
@@ -424,8 +583,46 @@ async fn test_get_subscription_pricing() {
     assert_eq!(pricing[0].r#type, Some("Shards".to_string()));
 }
 
-// Skipping test_delete_regions_from_active_active_subscription
-// as the client doesn't yet support DELETE with body
+#[tokio::test]
+async fn test_delete_regions_from_active_active_subscription() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subscriptions/123/regions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({
+            "regions": [{"region": "us-west-2"}, {"region": "eu-west-1"}],
+            "dryRun": true
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-delete-regions",
+            "commandType": "activeActiveRegionDeleteRequest",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SubscriptionHandler::new(client);
+    let request = ActiveActiveRegionDeleteRequest {
+        dry_run: Some(true),
+        ..ActiveActiveRegionDeleteRequest::new(["us-west-2", "eu-west-1"])
+    };
+
+    let result = handler
+        .delete_regions_from_active_active_subscription(123, &request)
+        .await
+        .unwrap();
+    assert_eq!(result.task_id.as_deref(), Some("task-delete-regions"));
+}
 
 #[tokio::test]
 async fn test_get_regions_from_active_active_subscription() {
@@ -459,7 +656,7 @@ async fn test_get_regions_from_active_active_subscription() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler
         .get_regions_from_active_active_subscription(123)
         .await
@@ -493,17 +690,11 @@ async fn test_add_new_region_to_active_active_subscription() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let request = redis_cloud::subscriptions::ActiveActiveRegionCreateRequest {
-        subscription_id: None,
         region: Some("ap-southeast-1".to_string()),
-        vpc_id: None,
         deployment_cidr: "10.1.0.0/20".to_string(),
-        dry_run: None,
-        databases: None,
-        resp_version: None,
-        customer_managed_key_resource_name: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler
@@ -532,7 +723,7 @@ async fn test_error_handling_401() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.get_all_subscriptions().await;
 
     assert!(result.is_err());
@@ -563,7 +754,7 @@ async fn test_error_handling_404() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let result = handler.get_subscription_by_id(999).await;
 
     assert!(result.is_err());
@@ -595,19 +786,13 @@ async fn test_error_handling_500() {
         .build()
         .unwrap();
 
-    let handler = SubscriptionsHandler::new(client);
+    let handler = SubscriptionHandler::new(client);
     let request = redis_cloud::subscriptions::SubscriptionCreateRequest {
         name: Some("Test Subscription".to_string()),
-        payment_method_id: None,
-        payment_method: None,
-        memory_storage: None,
-        persistent_storage_encryption_type: None,
         deployment_type: Some("single-region".to_string()),
-        dry_run: None,
         cloud_providers: vec![],
         databases: vec![],
-        redis_version: None,
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.create_subscription(&request).await;
@@ -618,3 +803,376 @@ async fn test_error_handling_500() {
         _ => panic!("Expected InternalServerError error"),
     }
 }
+
+fn region_request(provider: &str, regions: &[&str]) -> SubscriptionCreateRequest {
+    SubscriptionCreateRequest {
+        name: Some("Regional".to_string()),
+        cloud_providers: vec![SubscriptionSpec {
+            provider: Some(provider.into()),
+            regions: regions
+                .iter()
+                .map(|region| SubscriptionRegionSpec {
+                    region: (*region).to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }],
+        databases: vec![],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_create_subscription_validated() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/regions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "regions": [
+                {"name": "us-east-1", "provider": "AWS"},
+                {"name": "europe-west1", "provider": "GCP"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-validated",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = SubscriptionHandler::new(client);
+
+    // Region names are only valid for their own provider
+    let err = handler
+        .create_subscription_validated(&region_request("aws", &["us-east-1", "europe-west1"]))
+        .await
+        .unwrap_err();
+    match err {
+        CloudError::BadRequest { message } => {
+            assert!(message.contains("'europe-west1'"));
+            assert!(!message.contains("'us-east-1'"));
+        }
+        other => panic!("expected BadRequest, got {other:?}"),
+    }
+
+    let task = handler
+        .create_subscription_validated(&region_request("GCP", &["europe-west1"]))
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-validated"));
+}
+
+#[tokio::test]
+async fn test_update_customer_managed_keys() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/cmks"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({
+            "deletionGracePeriod": "alerts-only",
+            "customerManagedKeys": [
+                {"resourceName": "arn:aws:kms:us-east-1:123456789012:key/new-key"}
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-cmk",
+            "commandType": "UPDATE_SUBSCRIPTION_CMK",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123,
+            "name": "encrypted",
+            "persistentStorageEncryptionType": "customer-managed-key",
+            "customerManagedKeyAccessDetails": {
+                "redisIamRole": "arn:aws:iam::111111111111:role/redis-cmk",
+                "deletionGracePeriodOptions": ["immediate", "alerts-only"]
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = SubscriptionHandler::new(client);
+
+    let request = SubscriptionUpdateCMKRequest {
+        deletion_grace_period: Some(DeletionGracePeriod::AlertsOnly),
+        customer_managed_keys: vec![CustomerManagedKey {
+            resource_name: "arn:aws:kms:us-east-1:123456789012:key/new-key".to_string(),
+            region: None,
+        }],
+        ..Default::default()
+    };
+    let task = handler
+        .update_customer_managed_keys(123, &request)
+        .await
+        .unwrap();
+    assert_eq!(task.task_id.as_deref(), Some("task-cmk"));
+
+    let details = handler
+        .get_customer_managed_key_access_details(123)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        details.redis_iam_role.as_deref(),
+        Some("arn:aws:iam::111111111111:role/redis-cmk")
+    );
+    assert!(details.supports_grace_period(DeletionGracePeriod::AlertsOnly));
+}
+
+#[tokio::test]
+async fn test_find_subscription_by_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscriptions": [
+                {"id": 1, "name": "production"},
+                {"id": 2, "name": "production-eu"},
+                {"id": 3, "name": "staging"},
+                {"id": 4}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = SubscriptionHandler::new(client);
+
+    let matches = handler.find_by_name("production").await.unwrap();
+    assert_eq!(matches.unique().and_then(|s| s.id), Some(1));
+    assert_eq!(matches.prefix.len(), 1);
+    assert_eq!(matches.prefix[0].id, Some(2));
+
+    let matches = handler.find_by_name("prod").await.unwrap();
+    assert!(matches.unique().is_none());
+    assert_eq!(matches.prefix.len(), 2);
+
+    assert!(handler.find_by_name("dev").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_simple() {
+    use redis_cloud::subscriptions::SubscriptionDatabaseSpec;
+    use redis_cloud::tasks::TaskWaitOptions;
+    use std::time::Duration;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(body_json(json!({
+            "name": "cache",
+            "deploymentType": "single-region",
+            "cloudProviders": [{
+                "provider": "GCP",
+                "regions": [{"region": "us-central1"}]
+            }],
+            "databases": [{
+                "name": "cache",
+                "protocol": "redis",
+                "datasetSizeInGb": 1.0,
+                "throughputMeasurement": {"by": "operations-per-second", "value": 25000}
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-create-sub",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-create-sub"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-create-sub",
+            "status": "processing-completed",
+            "response": {"resourceId": 789}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/789/databases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 789,
+                "databases": [{"databaseId": 42, "name": "cache"}]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let database = SubscriptionDatabaseSpec {
+        name: "cache".to_string(),
+        dataset_size_in_gb: Some(1.0),
+        ..Default::default()
+    };
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let created = SubscriptionHandler::new(client)
+        .create_simple("GCP", "us-central1", database, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(created.subscription_id, 789);
+    assert_eq!(created.database_id, 42);
+    assert!(created.task.is_completed());
+}
+
+#[tokio::test]
+async fn test_create_subscription_from_value() {
+    let mock_server = MockServer::start().await;
+
+    let databases: Vec<_> = (1..=50)
+        .map(|i| json!({"name": format!("db-{i}"), "protocol": "redis", "datasetSizeInGb": 1}))
+        .collect();
+    let body = json!({
+        "name": "many-databases",
+        "cloudProviders": [{"provider": "AWS", "regions": [{"region": "us-east-1"}]}],
+        "databases": databases
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .and(header("content-type", "application/json"))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-create-many",
+            "commandType": "subscriptionCreateRequest",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let bytes = redis_cloud::Bytes::from(body.to_string());
+    let result = SubscriptionHandler::new(client)
+        .create_subscription_from_value(bytes)
+        .await
+        .unwrap();
+    assert_eq!(result.task_id.as_deref(), Some("task-create-many"));
+}
+
+#[tokio::test]
+async fn test_redis_version_lifecycle() {
+    use chrono::{Duration, NaiveDate};
+    use redis_cloud::databases::Database;
+    use redis_cloud::flexible::subscriptions::VersionLifecycle;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/redis-versions"))
+        .and(query_param("subscriptionId", "123"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "redisVersions": [
+                { "version": "6.2", "eolDate": "2025-02-28" },
+                { "version": "7.2", "eolDate": "2026-11-30", "isDefault": true },
+                { "version": "7.4", "isPreview": false },
+                { "version": "8.0", "isPreview": true }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SubscriptionHandler::new(client);
+    let default = handler.get_default_redis_version(Some(123)).await.unwrap();
+    assert_eq!(default.unwrap().version.as_deref(), Some("7.2"));
+
+    let databases: Vec<Database> = serde_json::from_value(json!([
+        { "databaseId": 1, "name": "legacy", "redisVersion": "6.2.14" },
+        { "databaseId": 2, "name": "orders", "redisVersion": "7.2.4" },
+        { "databaseId": 3, "name": "cache", "redisVersion": "7.4.0" },
+        { "databaseId": 4, "name": "odd", "redisVersion": "5.0" }
+    ]))
+    .unwrap();
+
+    let versions = handler.get_redis_versions(Some(123)).await.unwrap();
+    let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+    let report = versions.database_lifecycle(123, &databases, today, Duration::days(90));
+
+    let lifecycles: Vec<_> = report.iter().map(|status| status.lifecycle).collect();
+    assert_eq!(
+        lifecycles,
+        [
+            VersionLifecycle::Eol,
+            VersionLifecycle::NearEol,
+            VersionLifecycle::Supported,
+            VersionLifecycle::Unknown
+        ]
+    );
+    assert_eq!(report[1].eol, NaiveDate::from_ymd_opt(2026, 11, 30));
+    let to_upgrade: Vec<_> = report
+        .iter()
+        .filter(|status| status.needs_upgrade())
+        .map(|status| status.database_id)
+        .collect();
+    assert_eq!(to_upgrade, [1, 2]);
+}
@@ -0,0 +1,128 @@
+use redis_cloud::CloudClient;
+use redis_cloud::tag_inventory::DatabaseKind;
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_get(server: &MockServer, route: &str, body: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(route))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_tag_inventory() {
+    let mock_server = MockServer::start().await;
+
+    mount_get(
+        &mock_server,
+        "/subscriptions",
+        json!({"accountId": 456, "subscriptions": [{"id": 123, "name": "production"}]}),
+    )
+    .await;
+    mount_get(
+        &mock_server,
+        "/subscriptions/123/databases",
+        json!({
+            "accountId": 456,
+            "subscription": [{
+                "subscriptionId": 123,
+                "databases": [
+                    {"databaseId": 1, "name": "cache"},
+                    {"databaseId": 2, "name": "sessions"}
+                ]
+            }]
+        }),
+    )
+    .await;
+    mount_get(
+        &mock_server,
+        "/subscriptions/123/databases/1/tags",
+        json!({"accountId": 456, "tags": [
+            {"key": "team", "value": "payments"},
+            {"key": "env", "value": "prod"}
+        ]}),
+    )
+    .await;
+    mount_get(
+        &mock_server,
+        "/subscriptions/123/databases/2/tags",
+        json!({"accountId": 456, "tags": [{"key": "team", "value": "identity"}]}),
+    )
+    .await;
+
+    mount_get(
+        &mock_server,
+        "/fixed/subscriptions",
+        json!({"accountId": 456, "subscriptions": [{"id": 777, "name": "essentials"}]}),
+    )
+    .await;
+    mount_get(
+        &mock_server,
+        "/fixed/subscriptions/777/databases",
+        json!({
+            "accountId": 456,
+            "subscription": {
+                "subscriptionId": 777,
+                "numberOfDatabases": 1,
+                "databases": [{"databaseId": 51, "name": "queue"}]
+            }
+        }),
+    )
+    .await;
+    mount_get(
+        &mock_server,
+        "/fixed/subscriptions/777/databases/51/tags",
+        json!({"accountId": 456, "tags": [{"key": "team", "value": "payments"}]}),
+    )
+    .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let inventory = client.tag_inventory().await.unwrap();
+
+    assert_eq!(inventory.keys().collect::<Vec<_>>(), ["env", "team"]);
+    let teams: Vec<_> = inventory
+        .values("team")
+        .map(|(value, resources)| (value, resources.len()))
+        .collect();
+    assert_eq!(teams, [("identity", 1), ("payments", 2)]);
+
+    let payments = inventory.resources("team", "payments");
+    assert_eq!(payments[0].kind, DatabaseKind::Pro);
+    assert_eq!(payments[0].database_id, 1);
+    assert_eq!(payments[1].kind, DatabaseKind::Essentials);
+    assert_eq!(payments[1].subscription_id, 777);
+    assert_eq!(payments[1].database_name.as_deref(), Some("queue"));
+
+    assert!(inventory.resources("team", "unknown").is_empty());
+    assert_eq!(inventory.values("owner").count(), 0);
+
+    let by_resource = inventory.by_resource();
+    assert_eq!(by_resource.len(), 3);
+    let cache = by_resource.get(&payments[0]).unwrap();
+    assert_eq!(cache.get("env"), Some(&"prod"));
+    assert_eq!(cache.get("team"), Some(&"payments"));
+
+    let found = client
+        .find_databases_by_tag("team", "payments")
+        .await
+        .unwrap();
+    assert_eq!(found, payments);
+    assert!(
+        client
+            .find_databases_by_tag("env", "staging")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -1,5 +1,7 @@
-use redis_cloud::{CloudClient, tasks::TasksHandler};
+use redis_cloud::tasks::TaskWaitOptions;
+use redis_cloud::{CloudClient, CloudError, tasks::TasksHandler};
 use serde_json::json;
+use std::time::Duration;
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -275,3 +277,362 @@ async fn test_error_handling_500() {
         _ => panic!("Expected InternalServerError error"),
     }
 }
+
+#[tokio::test]
+async fn test_wait_for_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-wait"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-wait",
+            "status": "processing-in-progress"
+        })))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-wait"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-wait",
+            "status": "processing-completed",
+            "response": {"resourceId": 42}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = TasksHandler::new(client);
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let task = handler.wait_for_task("task-wait", &options).await.unwrap();
+
+    assert!(task.is_completed());
+    assert_eq!(task.response.unwrap().resource_id, Some(42));
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_wait_for_task_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-slow"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-slow",
+            "status": "processing-in-progress"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = TasksHandler::new(client);
+    let options = TaskWaitOptions::default()
+        .poll_interval(Duration::from_millis(10))
+        .timeout(Duration::from_millis(50));
+    let result = handler.wait_for_task("task-slow", &options).await;
+
+    assert!(matches!(result, Err(CloudError::TaskTimeout { .. })));
+}
+
+#[tokio::test]
+async fn test_watch_task() {
+    use futures::StreamExt;
+    use std::pin::pin;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-watch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-watch",
+            "status": "received"
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-watch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-watch",
+            "status": "processing-in-progress",
+            "progress": 50.0
+        })))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-watch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-watch",
+            "status": "processing-completed",
+            "response": {"resourceId": 42}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = TasksHandler::new(client);
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let mut updates = pin!(handler.watch("task-watch", &options));
+
+    let mut statuses = Vec::new();
+    while let Some(task) = updates.next().await {
+        statuses.push(task.unwrap().status.unwrap());
+    }
+
+    // The repeated in-progress poll is not reported twice
+    assert_eq!(
+        statuses,
+        ["received", "processing-in-progress", "processing-completed"]
+    );
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn test_watch_task_timeout() {
+    use futures::StreamExt;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-slow"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-slow",
+            "status": "processing-in-progress"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = TasksHandler::new(client);
+    let options = TaskWaitOptions::default()
+        .poll_interval(Duration::from_millis(10))
+        .timeout(Duration::from_millis(50));
+    let updates: Vec<_> = handler.watch("task-slow", &options).collect().await;
+
+    assert_eq!(updates.len(), 2);
+    assert!(updates[0].is_ok());
+    assert!(matches!(updates[1], Err(CloudError::TaskTimeout { .. })));
+}
+
+#[tokio::test]
+async fn test_wait_and_fetch_failed_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task-broken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "taskId": "task-broken",
+            "status": "processing-error",
+            "response": {
+                "error": "SUBSCRIPTION_NOT_ACTIVE",
+                "additionalInfo": "Subscription 123 is pending"
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = TasksHandler::new(client);
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+
+    // The primitive still hands back the failed task
+    let task = handler
+        .wait_for_task("task-broken", &options)
+        .await
+        .unwrap();
+    assert!(task.is_failed());
+
+    let err = handler
+        .wait_and_fetch(Some("task-broken".to_string()), &options, || async {
+            Err::<(), _>(CloudError::NotFound {
+                message: "resource must not be fetched after a failed task".to_string(),
+            })
+        })
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Task task-broken failed: SUBSCRIPTION_NOT_ACTIVE"
+    );
+    match err {
+        CloudError::TaskFailed {
+            task_id,
+            error,
+            additional_info,
+        } => {
+            assert_eq!(task_id, "task-broken");
+            assert_eq!(error.as_deref(), Some("SUBSCRIPTION_NOT_ACTIVE"));
+            assert_eq!(
+                additional_info.as_deref(),
+                Some("Subscription 123 is pending")
+            );
+        }
+        other => panic!("expected TaskFailed, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_task_result_ext() {
+    use redis_cloud::tasks::TaskResultExt;
+
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Peering {
+        vpc_peering_id: i32,
+        status: String,
+    }
+
+    let body = json!({
+        "taskId": "task-create",
+        "status": "processing-completed",
+        "response": {
+            "resourceId": 123,
+            "additionalResourceId": 456,
+            "resource": {"vpcPeeringId": 7, "status": "pending-acceptance"}
+        }
+    });
+
+    let flexible: redis_cloud::databases::TaskStateUpdate =
+        serde_json::from_value(body.clone()).unwrap();
+    assert_eq!(flexible.resource_id(), Some(123));
+    assert_eq!(flexible.additional_resource_id(), Some(456));
+    assert_eq!(flexible.error(), None);
+    let peering: Peering = flexible.resource().unwrap();
+    assert_eq!(peering.vpc_peering_id, 7);
+    assert_eq!(peering.status, "pending-acceptance");
+
+    let fixed: redis_cloud::fixed::databases::TaskStateUpdate =
+        serde_json::from_value(body).unwrap();
+    assert_eq!(fixed.resource_id(), Some(123));
+
+    // The resource ID falls back to the returned resource's id
+    let task: redis_cloud::tasks::TaskStateUpdate = serde_json::from_value(json!({
+        "taskId": "task-acl",
+        "status": "processing-completed",
+        "response": {"resource": {"id": 42, "name": "readers"}}
+    }))
+    .unwrap();
+    assert_eq!(task.resource_id(), Some(42));
+    assert_eq!(task.additional_resource_id(), None);
+
+    let failed: redis_cloud::subscriptions::TaskStateUpdate = serde_json::from_value(json!({
+        "taskId": "task-failed",
+        "status": "processing-error",
+        "response": {"error": "SUBSCRIPTION_PI_NOT_FOUND"}
+    }))
+    .unwrap();
+    assert_eq!(failed.error(), Some("SUBSCRIPTION_PI_NOT_FOUND"));
+    assert_eq!(failed.resource_id(), None);
+}
+
+#[tokio::test]
+async fn test_wait_for_all() {
+    let mock_server = MockServer::start().await;
+
+    for (task_id, status, response) in [
+        ("task-a", "processing-completed", json!({"resourceId": 1})),
+        (
+            "task-b",
+            "processing-error",
+            json!({"error": "INVALID_PLAN"}),
+        ),
+        ("task-c", "processing-completed", json!({"resourceId": 3})),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!("/tasks/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "taskId": task_id,
+                "status": status,
+                "response": response
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = TasksHandler::new(client);
+    let options = TaskWaitOptions::default().poll_interval(Duration::from_millis(10));
+    let batch = handler
+        .wait_for_all_with_concurrency(&["task-a", "task-b", "task-c", "task-missing"], &options, 2)
+        .await;
+
+    let ids: Vec<_> = batch.outcomes.iter().map(|o| o.task_id.as_str()).collect();
+    assert_eq!(ids, ["task-a", "task-b", "task-c", "task-missing"]);
+    assert!(!batch.all_completed());
+    assert_eq!(batch.completed().count(), 2);
+
+    let failed: Vec<_> = batch.failed().collect();
+    assert_eq!(failed.len(), 2);
+    assert_eq!(failed[0].0, "task-b");
+    assert!(
+        matches!(failed[0].1, CloudError::TaskFailed { error: Some(e), .. } if e == "INVALID_PLAN")
+    );
+    assert_eq!(failed[1].0, "task-missing");
+    assert!(matches!(failed[1].1, CloudError::NotFound { .. }));
+}
+
+#[tokio::test]
+async fn test_wait_without_task_id() {
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url("http://127.0.0.1:1".to_string())
+        .build()
+        .unwrap();
+
+    let handler = TasksHandler::new(client);
+    let options = TaskWaitOptions::default();
+
+    let err = handler
+        .wait_for_resource::<serde_json::Value>(None, &options)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CloudError::UnexpectedResponse { .. }));
+
+    let err = handler
+        .wait_and_fetch(None, &options, || async { Ok(()) })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CloudError::UnexpectedResponse { .. }));
+}
@@ -1,6 +1,7 @@
+use redis_cloud::types::UserRole;
 use redis_cloud::{CloudClient, UserHandler};
 use serde_json::json;
-use wiremock::matchers::{header, method, path};
+use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -99,10 +100,9 @@ async fn test_update_user() {
 
     let handler = UserHandler::new(client);
     let request = redis_cloud::users::AccountUserUpdateRequest {
-        user_id: None,
         name: "Jane Doe".to_string(),
         role: Some("admin".to_string()),
-        command_type: None,
+        ..Default::default()
     };
 
     let result = handler.update_user(456, &request).await.unwrap();
@@ -172,3 +172,160 @@ async fn test_error_handling_404() {
         panic!("Expected NotFound error");
     }
 }
+
+#[tokio::test]
+async fn test_get_security_summary() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("x-api-secret-key", "test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "account": 1,
+            "users": [
+                {
+                    "id": 1,
+                    "email": "owner@example.com",
+                    "userType": "SAML",
+                    "options": {"mfaEnabled": true}
+                },
+                {
+                    "id": 2,
+                    "email": "local@example.com",
+                    "userType": "Local",
+                    "options": {"mfaEnabled": false}
+                },
+                {
+                    "id": 3,
+                    "email": "social@example.com",
+                    "userType": "Google"
+                },
+                {
+                    "id": 4,
+                    "email": "unknown@example.com",
+                    "options": {"mfaEnabled": true}
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let summary = UserHandler::new(client)
+        .get_security_summary()
+        .await
+        .unwrap();
+
+    assert_eq!(summary.total_users, 4);
+    assert!(!summary.all_users_have_mfa());
+    assert!(!summary.all_users_use_sso());
+    let without_mfa: Vec<_> = summary.without_mfa.iter().map(|u| u.id).collect();
+    assert_eq!(without_mfa, vec![Some(2), Some(3)]);
+    let without_sso: Vec<_> = summary.without_sso.iter().map(|u| u.id).collect();
+    assert_eq!(without_sso, vec![Some(2), Some(3), Some(4)]);
+    assert_eq!(summary.local_users.len(), 1);
+    assert_eq!(summary.local_users[0].id, Some(2));
+    assert_eq!(summary.unknown_user_type.len(), 1);
+    assert_eq!(summary.unknown_user_type[0].id, Some(4));
+}
+
+#[tokio::test]
+async fn test_find_by_email_and_change_role() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "account": 1001,
+            "users": [
+                {"id": 1, "name": "Ada", "email": "ada@example.com", "role": "Owner"},
+                {"id": 2, "name": "Bob", "email": "Bob@Example.com", "role": "Viewer"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 2,
+            "name": "Bob",
+            "email": "Bob@Example.com",
+            "role": "Viewer"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/users/2"))
+        .and(body_json(json!({"name": "Bob", "role": "Billing Admin"})))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-role",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CloudClient::builder()
+        .api_key("test-key".to_string())
+        .api_secret("test-secret".to_string())
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let handler = UserHandler::new(client);
+
+    let bob = handler
+        .find_by_email("bob@example.com")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(bob.id, Some(2));
+    assert_eq!(bob.user_role(), Some(UserRole::Viewer));
+    assert!(
+        handler
+            .find_by_email("eve@example.com")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(
+        handler
+            .find_by_name("Ada")
+            .await
+            .unwrap()
+            .unique()
+            .and_then(|u| u.id),
+        Some(1)
+    );
+
+    let task = handler
+        .change_role(2, UserRole::BillingAdmin)
+        .await
+        .unwrap();
+    assert_eq!(task.unwrap().task_id.as_deref(), Some("task-role"));
+    // Already a viewer: nothing to submit
+    assert!(
+        handler
+            .change_role(2, UserRole::Viewer)
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    assert_eq!(
+        UserRole::from("Logs Viewer (API use only)"),
+        UserRole::LogsViewer
+    );
+    assert_eq!(
+        UserRole::from("Custom"),
+        UserRole::Other("Custom".to_string())
+    );
+}